@@ -0,0 +1,676 @@
+//! Local stand-in for the Bybit v5 API, so clients can be tested without network access.
+//!
+//! [`MockServer`] serves the public (`/v5/public/{category}`) and private (`/v5/private`)
+//! websocket streams and any REST path registered with [`MockServer::mock_rest`].
+
+use axum::{
+    body::Bytes,
+    extract::{
+        ws::{CloseFrame, Message, WebSocket, WebSocketUpgrade},
+        State,
+    },
+    http::{HeaderMap, HeaderValue, Method, StatusCode, Uri},
+    response::{IntoResponse, Response},
+    routing::get,
+    Router,
+};
+use futures_util::{SinkExt, StreamExt};
+use hmac::{Hmac, Mac};
+use serde_json::{json, Value};
+use sha2::Sha256;
+use std::{
+    collections::{HashMap, VecDeque},
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tokio::{
+    net::TcpListener,
+    sync::{broadcast, Notify},
+    task::JoinHandle,
+    time::timeout,
+};
+use tracing::Instrument;
+
+pub const PATH_PUBLIC_SPOT: &str = "/v5/public/spot";
+pub const PATH_PUBLIC_LINEAR: &str = "/v5/public/linear";
+pub const PATH_PUBLIC_INVERSE: &str = "/v5/public/inverse";
+pub const PATH_PUBLIC_OPTION: &str = "/v5/public/option";
+pub const PATH_PRIVATE: &str = "/v5/private";
+
+/// `retCode` and `retMsg` returned by Bybit for unknown REST paths.
+const NOT_FOUND_CODE: i32 = 10404;
+/// `ret_msg` of a rejected `auth` or of a private `subscribe` before `auth`.
+const UNAUTHORIZED: &str = "Request not authorized";
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// How the private stream answers `auth` requests.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuthMode {
+    /// Every request succeeds.
+    Accept,
+    /// Every request fails.
+    Reject,
+    /// Succeeds only for this key pair, with a valid signature and a future `expires`.
+    Verify { api_key: String, api_secret: String },
+}
+
+/// Values of the `X-Bapi-Limit*` headers of a REST endpoint.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    pub limit: u32,
+    pub remaining: u32,
+    /// UTC timestamp in milliseconds.
+    pub reset_timestamp: u64,
+}
+
+/// REST request received by the server.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RestRequest {
+    pub method: Method,
+    pub path: String,
+    pub query: Option<String>,
+    pub body: String,
+    /// Value of the `X-BAPI-API-KEY` header, set on signed requests.
+    pub api_key: Option<String>,
+}
+
+#[derive(Clone)]
+struct RestMock {
+    ret_code: i32,
+    ret_msg: String,
+    result: Value,
+    ret_ext_info: Value,
+}
+
+#[derive(Clone, Debug)]
+enum Control {
+    Push(String),
+    Disconnect,
+}
+
+struct Shared {
+    /// Responses of a path, or of `{path}?{name}={value}`, the last one is repeated.
+    rest: Mutex<HashMap<String, VecDeque<RestMock>>>,
+    rate_limits: Mutex<HashMap<String, RateLimit>>,
+    rest_requests: Mutex<Vec<RestRequest>>,
+    scripts: Mutex<HashMap<String, Vec<String>>>,
+    auth: Mutex<AuthMode>,
+    received: Mutex<Vec<String>>,
+    received_notify: Notify,
+    connections: AtomicU64,
+    control: broadcast::Sender<Control>,
+}
+
+/// Bybit v5 server listening on a random local port. Stops when dropped.
+pub struct MockServer {
+    addr: SocketAddr,
+    shared: Arc<Shared>,
+    handle: JoinHandle<()>,
+}
+
+impl MockServer {
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind mock server");
+        let addr = listener.local_addr().expect("mock server address");
+        let (control, _) = broadcast::channel(256);
+        let shared = Arc::new(Shared {
+            rest: Mutex::default(),
+            rate_limits: Mutex::default(),
+            rest_requests: Mutex::default(),
+            scripts: Mutex::default(),
+            auth: Mutex::new(AuthMode::Accept),
+            received: Mutex::default(),
+            received_notify: Notify::new(),
+            connections: AtomicU64::new(0),
+            control,
+        });
+
+        let app = Router::new()
+            .route("/v5/public/{category}", get(websocket_handler))
+            .route(PATH_PRIVATE, get(websocket_handler))
+            .fallback(rest_handler)
+            .with_state(shared.clone());
+
+        let span = tracing::info_span!("bybit.mock", %addr);
+        let handle = tokio::spawn(
+            async move {
+                if let Err(e) = axum::serve(listener, app).await {
+                    tracing::error!("Serve failed: {e}");
+                }
+            }
+            .instrument(span),
+        );
+
+        Self {
+            addr,
+            shared,
+            handle,
+        }
+    }
+
+    /// Base URL for `Client::new`.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Websocket URL of a stream, e.g. `ws_url(PATH_PUBLIC_LINEAR)`.
+    pub fn ws_url(&self, path: &str) -> String {
+        format!("ws://{}{}", self.addr, path)
+    }
+
+    /// Answers `path` with `retCode` 0 and `result`.
+    pub fn mock_rest(&self, path: &str, result: Value) {
+        self.insert_rest(path, 0, "OK", result, json!({}));
+    }
+
+    /// Answers `path` with `retCode` 0, `result` and `retExtInfo`, e.g. for batch endpoints.
+    pub fn mock_rest_with_ext_info(&self, path: &str, result: Value, ret_ext_info: Value) {
+        self.insert_rest(path, 0, "OK", result, ret_ext_info);
+    }
+
+    /// Answers successive requests of `path` with the next of `results`, then repeats the
+    /// last one, e.g. for the pages of a paginated endpoint.
+    pub fn mock_rest_pages(&self, path: &str, results: Vec<Value>) {
+        let mocks = results
+            .into_iter()
+            .map(|result| RestMock {
+                ret_code: 0,
+                ret_msg: String::from("OK"),
+                result,
+                ret_ext_info: json!({}),
+            })
+            .collect();
+        lock(&self.shared.rest).insert(path.to_owned(), mocks);
+    }
+
+    /// Answers requests of `path` with the query parameter `name=value` with `result`,
+    /// instead of the response of `path`, e.g. for a filter like `status=PreLaunch`.
+    pub fn mock_rest_query(&self, path: &str, name: &str, value: &str, result: Value) {
+        self.insert_rest(
+            &format!("{path}?{name}={value}"),
+            0,
+            "OK",
+            result,
+            json!({}),
+        );
+    }
+
+    /// Answers `path` with an API error.
+    pub fn mock_rest_error(&self, path: &str, ret_code: i32, ret_msg: &str) {
+        self.insert_rest(path, ret_code, ret_msg, json!({}), json!({}));
+    }
+
+    fn insert_rest(
+        &self,
+        path: &str,
+        ret_code: i32,
+        ret_msg: &str,
+        result: Value,
+        ret_ext_info: Value,
+    ) {
+        let mock = RestMock {
+            ret_code,
+            ret_msg: ret_msg.to_owned(),
+            result,
+            ret_ext_info,
+        };
+        lock(&self.shared.rest).insert(path.to_owned(), VecDeque::from([mock]));
+    }
+
+    /// Sends `X-Bapi-Limit*` headers with every response of `path`.
+    pub fn set_rate_limit(&self, path: &str, rate_limit: RateLimit) {
+        lock(&self.shared.rate_limits).insert(path.to_owned(), rate_limit);
+    }
+
+    /// REST requests received so far, oldest first.
+    pub fn rest_requests(&self) -> Vec<RestRequest> {
+        lock(&self.shared.rest_requests).clone()
+    }
+
+    /// Frames sent to every connection right after it subscribes to `topic`.
+    pub fn on_subscribe(&self, topic: &str, frames: Vec<String>) {
+        lock(&self.shared.scripts).insert(topic.to_owned(), frames);
+    }
+
+    pub fn set_auth(&self, mode: AuthMode) {
+        *lock(&self.shared.auth) = mode;
+    }
+
+    /// Sends a frame to every open websocket connection.
+    pub fn push(&self, frame: impl Into<String>) {
+        // No receivers just means no connection is open.
+        let _ = self.shared.control.send(Control::Push(frame.into()));
+    }
+
+    /// Closes every open websocket connection, like a Bybit maintenance disconnect.
+    pub fn disconnect_all(&self) {
+        let _ = self.shared.control.send(Control::Disconnect);
+    }
+
+    /// Number of websocket connections accepted so far.
+    pub fn connections(&self) -> u64 {
+        self.shared.connections.load(Ordering::SeqCst)
+    }
+
+    /// Text frames received from clients so far, oldest first.
+    pub fn received(&self) -> Vec<String> {
+        lock(&self.shared.received).clone()
+    }
+
+    /// Waits until `count` frames have been received. Returns them, or `None` on timeout.
+    pub async fn wait_received(&self, count: usize, wait: Duration) -> Option<Vec<String>> {
+        let frames = async {
+            loop {
+                let notified = self.shared.received_notify.notified();
+                let received = self.received();
+                if received.len() >= count {
+                    return received;
+                }
+                notified.await;
+            }
+        };
+        timeout(wait, frames).await.ok()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.disconnect_all();
+        self.handle.abort();
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    // A test that panicked while holding the lock must not hide the original failure.
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time went backwards")
+        .as_millis() as u64
+}
+
+async fn rest_handler(
+    State(shared): State<Arc<Shared>>,
+    method: Method,
+    uri: Uri,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let path = uri.path().to_owned();
+    lock(&shared.rest_requests).push(RestRequest {
+        method,
+        path: path.clone(),
+        query: uri.query().map(str::to_owned),
+        body: String::from_utf8_lossy(&body).into_owned(),
+        api_key: headers
+            .get("X-BAPI-API-KEY")
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_owned),
+    });
+
+    let mock = {
+        let mut rest = lock(&shared.rest);
+        let query_key = uri
+            .query()
+            .unwrap_or_default()
+            .split('&')
+            .map(|pair| format!("{path}?{pair}"))
+            .find(|key| rest.contains_key(key));
+        rest.get_mut(query_key.as_ref().unwrap_or(&path))
+            .and_then(|mocks| {
+                if mocks.len() > 1 {
+                    mocks.pop_front()
+                } else {
+                    mocks.front().cloned()
+                }
+            })
+    };
+    let mock = mock.unwrap_or_else(|| {
+        tracing::warn!(path, "REST path is not mocked");
+        RestMock {
+            ret_code: NOT_FOUND_CODE,
+            ret_msg: format!("{path} is not mocked"),
+            result: json!({}),
+            ret_ext_info: json!({}),
+        }
+    });
+    let envelope = json!({
+        "retCode": mock.ret_code,
+        "retMsg": mock.ret_msg,
+        "result": mock.result,
+        "retExtInfo": mock.ret_ext_info,
+        "time": timestamp(),
+    });
+
+    let mut response = (StatusCode::OK, axum::Json(envelope)).into_response();
+    if let Some(rate_limit) = lock(&shared.rate_limits).get(&path).copied() {
+        let headers = response.headers_mut();
+        headers.insert("X-Bapi-Limit", HeaderValue::from(rate_limit.limit));
+        headers.insert(
+            "X-Bapi-Limit-Status",
+            HeaderValue::from(rate_limit.remaining),
+        );
+        headers.insert(
+            "X-Bapi-Limit-Reset-Timestamp",
+            HeaderValue::from(rate_limit.reset_timestamp),
+        );
+    }
+    response
+}
+
+async fn websocket_handler(
+    ws: WebSocketUpgrade,
+    State(shared): State<Arc<Shared>>,
+    uri: Uri,
+) -> impl IntoResponse {
+    let private = uri.path() == PATH_PRIVATE;
+    ws.on_upgrade(move |socket| websocket(socket, shared, private))
+}
+
+/// Per connection state of the stream protocol.
+struct Session {
+    conn_id: String,
+    private: bool,
+    authorized: bool,
+}
+
+async fn websocket(socket: WebSocket, shared: Arc<Shared>, private: bool) {
+    let id = shared.connections.fetch_add(1, Ordering::SeqCst) + 1;
+    let mut session = Session {
+        conn_id: format!("mock-conn-{id}"),
+        private,
+        authorized: false,
+    };
+    let mut control = shared.control.subscribe();
+    let (mut sender, mut receiver) = socket.split();
+    tracing::debug!(conn_id = session.conn_id, "Connect");
+
+    loop {
+        tokio::select! {
+            message = receiver.next() => {
+                let frame = match message {
+                    Some(Ok(Message::Text(frame))) => frame.to_string(),
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                    Some(Ok(_)) => continue,
+                };
+                lock(&shared.received).push(frame.clone());
+                shared.received_notify.notify_waiters();
+
+                for reply in handle_frame(&shared, &mut session, &frame) {
+                    if sender.send(Message::text(reply)).await.is_err() {
+                        return;
+                    }
+                }
+            }
+            control = control.recv() => match control {
+                Ok(Control::Push(frame)) => {
+                    if sender.send(Message::text(frame)).await.is_err() {
+                        break;
+                    }
+                }
+                Ok(Control::Disconnect) | Err(broadcast::error::RecvError::Closed) => {
+                    let close = CloseFrame {
+                        code: axum::extract::ws::close_code::AWAY,
+                        reason: "mock disconnect".into(),
+                    };
+                    let _ = sender.send(Message::Close(Some(close))).await;
+                    break;
+                }
+                Err(broadcast::error::RecvError::Lagged(n)) => {
+                    tracing::warn!("Control lagged by {n} messages");
+                }
+            },
+        }
+    }
+    tracing::debug!(conn_id = session.conn_id, "Disconnect");
+}
+
+/// Replies to one client frame, in the order Bybit sends them.
+fn handle_frame(shared: &Shared, session: &mut Session, frame: &str) -> Vec<String> {
+    let Ok(request) = serde_json::from_str::<Value>(frame) else {
+        tracing::warn!(payload = frame, "Frame is not JSON");
+        return vec![];
+    };
+    let op = request["op"].as_str().unwrap_or_default();
+    let req_id = request.get("req_id").cloned().unwrap_or(Value::Null);
+    let conn_id = &session.conn_id;
+
+    match op {
+        "ping" if session.private => vec![json!({
+            "req_id": req_id,
+            "op": "pong",
+            "args": [timestamp().to_string()],
+            "conn_id": conn_id,
+            "success": true,
+        })
+        .to_string()],
+        "ping" => vec![json!({
+            "success": true,
+            "ret_msg": "pong",
+            "conn_id": conn_id,
+            "req_id": req_id,
+            "op": "ping",
+        })
+        .to_string()],
+        "auth" => {
+            let success = verify_auth(&lock(&shared.auth), &request["args"]);
+            session.authorized = success;
+            vec![json!({
+                "success": success,
+                "ret_msg": if success { "" } else { UNAUTHORIZED },
+                "op": "auth",
+                "conn_id": conn_id,
+                "req_id": req_id,
+            })
+            .to_string()]
+        }
+        "subscribe" | "unsubscribe" => {
+            let success = !session.private || session.authorized;
+            let mut replies = vec![json!({
+                "success": success,
+                "ret_msg": if success { "" } else { UNAUTHORIZED },
+                "conn_id": conn_id,
+                "req_id": req_id,
+                "op": op,
+            })
+            .to_string()];
+            if success && op == "subscribe" {
+                let scripts = lock(&shared.scripts);
+                let topics = request["args"].as_array().into_iter().flatten();
+                for topic in topics.filter_map(Value::as_str) {
+                    replies.extend(scripts.get(topic).into_iter().flatten().cloned());
+                }
+            }
+            replies
+        }
+        _ => {
+            tracing::warn!(payload = frame, "Unknown op");
+            vec![]
+        }
+    }
+}
+
+/// `args` of an `auth` request: `[api_key, expires, signature]`.
+fn verify_auth(mode: &AuthMode, args: &Value) -> bool {
+    match mode {
+        AuthMode::Accept => true,
+        AuthMode::Reject => false,
+        AuthMode::Verify {
+            api_key,
+            api_secret,
+        } => {
+            let (Some(key), Some(expires), Some(signature)) =
+                (args[0].as_str(), args[1].as_u64(), args[2].as_str())
+            else {
+                return false;
+            };
+            let mut mac = HmacSha256::new_from_slice(api_secret.as_bytes())
+                .expect("HMAC can take key of any size");
+            mac.update(format!("GET/realtime{expires}").as_bytes());
+            key == api_key
+                && expires > timestamp()
+                && hex::encode(mac.finalize().into_bytes()) == signature
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio_tungstenite::{connect_async, tungstenite::Message as Frame};
+
+    use super::*;
+
+    async fn next_json<S>(ws: &mut S) -> Value
+    where
+        S: StreamExt<Item = Result<Frame, tokio_tungstenite::tungstenite::Error>> + Unpin,
+    {
+        match ws.next().await.unwrap().unwrap() {
+            Frame::Text(text) => serde_json::from_str(&text).unwrap(),
+            frame => panic!("unexpected frame {frame:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_public_subscribe_ping_and_push() {
+        let server = MockServer::start().await;
+        let trade = r#"{"topic":"publicTrade.BTCUSDT","type":"snapshot","ts":1,"data":[]}"#;
+        server.on_subscribe("publicTrade.BTCUSDT", vec![trade.to_owned()]);
+
+        let (mut ws, _) = connect_async(server.ws_url(PATH_PUBLIC_LINEAR))
+            .await
+            .unwrap();
+        let subscribe = r#"{"op":"subscribe","req_id":"1","args":["publicTrade.BTCUSDT"]}"#;
+        ws.send(Frame::text(subscribe)).await.unwrap();
+        let ack = next_json(&mut ws).await;
+        assert_eq!(ack["op"], "subscribe");
+        assert_eq!(ack["success"], true);
+        assert_eq!(ack["req_id"], "1");
+        assert_eq!(next_json(&mut ws).await["topic"], "publicTrade.BTCUSDT");
+
+        ws.send(Frame::text(r#"{"op":"ping","req_id":"p"}"#))
+            .await
+            .unwrap();
+        let pong = next_json(&mut ws).await;
+        assert_eq!(pong["ret_msg"], "pong");
+
+        server.push(r#"{"topic":"pushed"}"#);
+        assert_eq!(next_json(&mut ws).await["topic"], "pushed");
+        assert_eq!(server.received().len(), 2);
+        assert_eq!(server.connections(), 1);
+
+        server.disconnect_all();
+        assert!(matches!(ws.next().await, Some(Ok(Frame::Close(_)))));
+    }
+
+    #[tokio::test]
+    async fn test_private_auth() {
+        let server = MockServer::start().await;
+        server.set_auth(AuthMode::Verify {
+            api_key: String::from("key"),
+            api_secret: String::from("secret"),
+        });
+        let (mut ws, _) = connect_async(server.ws_url(PATH_PRIVATE)).await.unwrap();
+
+        ws.send(Frame::text(r#"{"op":"subscribe","args":["order"]}"#))
+            .await
+            .unwrap();
+        assert_eq!(next_json(&mut ws).await["success"], false);
+
+        let bad = r#"{"op":"auth","args":["key",99999999999999,"bad"]}"#;
+        ws.send(Frame::text(bad)).await.unwrap();
+        let reply = next_json(&mut ws).await;
+        assert_eq!(reply["success"], false);
+        assert_eq!(reply["ret_msg"], UNAUTHORIZED);
+
+        let expires = timestamp() + 10_000;
+        let mut mac = HmacSha256::new_from_slice(b"secret").unwrap();
+        mac.update(format!("GET/realtime{expires}").as_bytes());
+        let signature = hex::encode(mac.finalize().into_bytes());
+        let auth = json!({"op": "auth", "args": ["key", expires, signature]});
+        ws.send(Frame::text(auth.to_string())).await.unwrap();
+        assert_eq!(next_json(&mut ws).await["success"], true);
+
+        ws.send(Frame::text(r#"{"op":"ping"}"#)).await.unwrap();
+        assert_eq!(next_json(&mut ws).await["op"], "pong");
+    }
+
+    #[tokio::test]
+    async fn test_rest_envelope_and_rate_limit() {
+        let server = MockServer::start().await;
+        server.mock_rest("/v5/market/time", json!({"timeSecond": "1"}));
+        server.set_rate_limit(
+            "/v5/market/time",
+            RateLimit {
+                limit: 10,
+                remaining: 9,
+                reset_timestamp: 1,
+            },
+        );
+
+        let response = reqwest::get(format!("{}/v5/market/time?a=1", server.url()))
+            .await
+            .unwrap();
+        assert_eq!(response.headers()["X-Bapi-Limit-Status"], "9");
+        let body: Value = response.json().await.unwrap();
+        assert_eq!(body["retCode"], 0);
+        assert_eq!(body["result"]["timeSecond"], "1");
+
+        let body: Value = reqwest::get(format!("{}/v5/unknown", server.url()))
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(body["retCode"], NOT_FOUND_CODE);
+
+        let requests = server.rest_requests();
+        assert_eq!(requests[0].path, "/v5/market/time");
+        assert_eq!(requests[0].query.as_deref(), Some("a=1"));
+        assert_eq!(requests.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_rest_pages_and_query() {
+        let server = MockServer::start().await;
+        server.mock_rest_pages(
+            "/v5/position/closed-pnl",
+            vec![json!({"page": 1}), json!({"page": 2})],
+        );
+        let url = format!("{}/v5/position/closed-pnl", server.url());
+        for page in [1, 2, 2] {
+            let body: Value = reqwest::get(&url).await.unwrap().json().await.unwrap();
+            assert_eq!(body["result"]["page"], page);
+        }
+
+        server.mock_rest_query(
+            "/v5/position/closed-pnl",
+            "status",
+            "Closed",
+            json!({"page": 0}),
+        );
+        let body: Value = reqwest::get(format!("{url}?limit=1&status=Closed"))
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(body["result"]["page"], 0);
+        let body: Value = reqwest::get(format!("{url}?status=Trading"))
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(body["result"]["page"], 2);
+    }
+}
@@ -2,9 +2,8 @@ use std::time::Duration;
 use tokio::{self, time::sleep};
 
 use bybit_sdk::{
-    stream_async, topic_all_liquidation, topic_kline, topic_ticker, topic_trade, AllLiquidationMsg,
-    CommandMsg, IncomingMessage, Interval, KLineMsg, OutgoingMessage, TickerMsg, TradeMsg,
-    PATH_PUBLIC_LINEAR, URL_BASE_STREAM_MAINNET_1,
+    stream_async, AllLiquidationMsg, CommandMsg, IncomingMessage, Interval, KLineMsg,
+    OutgoingMessage, TickerMsg, Topic, TradeMsg, PATH_PUBLIC_LINEAR, URL_BASE_STREAM_MAINNET_1,
 };
 
 const WEBSOCKET_PING_INTERVAL: u64 = 10; // Sec.
@@ -14,36 +13,36 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let url = format!("{URL_BASE_STREAM_MAINNET_1}{PATH_PUBLIC_LINEAR}");
     let message_0001 = OutgoingMessage::Subscribe {
         req_id: Some(String::from("req-0001")),
-        args: vec![topic_ticker("BTCUSDT")],
+        args: vec![Topic::ticker("BTCUSDT")],
     };
     let message_0002 = OutgoingMessage::Unsubscribe {
         req_id: Some(String::from("req-0002")),
-        args: vec![topic_ticker("BTCUSDT")],
+        args: vec![Topic::ticker("BTCUSDT")],
     };
     let message_0003 = OutgoingMessage::Subscribe {
         req_id: Some(String::from("req-0003")),
-        args: vec![topic_trade("BTCUSDT")],
+        args: vec![Topic::trade("BTCUSDT")],
     };
     let message_0004 = OutgoingMessage::Unsubscribe {
         req_id: Some(String::from("req-0004")),
-        args: vec![topic_trade("BTCUSDT")],
+        args: vec![Topic::trade("BTCUSDT")],
     };
     let message_0005 = OutgoingMessage::Subscribe {
         req_id: Some(String::from("req-0005")),
-        args: vec![topic_kline("BTCUSDT", Interval::Minute1)],
+        args: vec![Topic::kline("BTCUSDT", Interval::Minute1)],
     };
     let message_0006 = OutgoingMessage::Unsubscribe {
         req_id: Some(String::from("req-0006")),
-        args: vec![topic_kline("BTCUSDT", Interval::Minute1)],
+        args: vec![Topic::kline("BTCUSDT", Interval::Minute1)],
     };
 
     let message_0007 = OutgoingMessage::Subscribe {
         req_id: Some(String::from("req-0007")),
-        args: vec![topic_all_liquidation("BTCUSDT")],
+        args: vec![Topic::all_liquidation("BTCUSDT")],
     };
     let message_0008 = OutgoingMessage::Unsubscribe {
         req_id: Some(String::from("req-0008")),
-        args: vec![topic_all_liquidation("BTCUSDT")],
+        args: vec![Topic::all_liquidation("BTCUSDT")],
     };
 
     let (tx, mut rx) = stream_async(&url, WEBSOCKET_PING_INTERVAL).await?;
@@ -136,6 +135,8 @@ fn print(message: IncomingMessage) {
                 data,
             } => println!("{data:?}"),
         },
+        IncomingMessage::OptionTicker(message) => println!("{message:?}"),
+        IncomingMessage::OrderBook(message) => println!("{message:?}"),
         IncomingMessage::Trade(message) => match message {
             TradeMsg::Snapshot {
                 id: _,
@@ -160,5 +161,9 @@ fn print(message: IncomingMessage) {
         },
         IncomingMessage::Order(message) => println!("{message:?}"),
         IncomingMessage::Position(message) => println!("{message:?}"),
+        IncomingMessage::Execution(message) => println!("{message:?}"),
+        IncomingMessage::Wallet(message) => println!("{message:?}"),
+        IncomingMessage::Greeks(message) => println!("{message:?}"),
+        IncomingMessage::Error(error) => println!("{error}"),
     }
 }
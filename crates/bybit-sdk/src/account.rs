@@ -0,0 +1,215 @@
+use crate::{
+    url::{
+        PATH_ACCOUNT_BORROW_HISTORY, PATH_ACCOUNT_COLLATERAL_INFO, PATH_ACCOUNT_INFO,
+        PATH_ACCOUNT_SET_MARGIN_MODE, PATH_ACCOUNT_SET_MARGIN_MODE_DEMO_APPLY_MONEY,
+        PATH_ACCOUNT_TRANSACTION_LOG, PATH_ACCOUNT_WALLET_BALANCE,
+    },
+    AccountInfo, BorrowHistory, Client, CollateralInfo, DemoApplyMoneyParams,
+    GetBorrowHistoryParams, GetCollateralInfoParams, GetTransactionLogParams,
+    GetWalletBalanceParams, Result, SetMarginMode, SetMarginModeParams, TransactionLog,
+    WalletBalance,
+};
+
+impl Client {
+    pub async fn get_wallet_balance(
+        &self,
+        params: GetWalletBalanceParams,
+    ) -> Result<WalletBalance> {
+        self.get_signed(PATH_ACCOUNT_WALLET_BALANCE, &params).await
+    }
+
+    pub async fn get_account_info(&self) -> Result<AccountInfo> {
+        self.get_signed(PATH_ACCOUNT_INFO, &()).await
+    }
+
+    /// A single page; [`Client::transaction_log`] streams all of them.
+    pub async fn get_transaction_log(
+        &self,
+        params: GetTransactionLogParams,
+    ) -> Result<TransactionLog> {
+        self.get_signed(PATH_ACCOUNT_TRANSACTION_LOG, &params).await
+    }
+
+    pub async fn get_collateral_info(
+        &self,
+        params: GetCollateralInfoParams,
+    ) -> Result<CollateralInfo> {
+        self.get_signed(PATH_ACCOUNT_COLLATERAL_INFO, &params).await
+    }
+
+    /// A single page; [`Client::borrow_history`] streams all of them.
+    pub async fn get_borrow_history(
+        &self,
+        params: GetBorrowHistoryParams,
+    ) -> Result<BorrowHistory> {
+        self.get_signed(PATH_ACCOUNT_BORROW_HISTORY, &params).await
+    }
+
+    /// Bybit answers retCode 0 with the reasons when the mode cannot be switched, so check
+    /// [`SetMarginMode::reasons`].
+    pub async fn set_margin_mode(&self, params: SetMarginModeParams) -> Result<SetMarginMode> {
+        self.post_signed(PATH_ACCOUNT_SET_MARGIN_MODE, &params)
+            .await
+    }
+
+    /// Adds or removes funds of a demo trading account. Only the demo trading endpoint
+    /// ([`Region::Demo`](crate::Region::Demo)) accepts it.
+    pub async fn demo_apply_money(&self, params: DemoApplyMoneyParams) -> Result<()> {
+        self.post_empty(PATH_ACCOUNT_SET_MARGIN_MODE_DEMO_APPLY_MONEY, &params)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bybit_mock::MockServer;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        client::tests::signed_client, number::n, AccountType, DemoApplyCoin, MarginMode,
+        UnifiedMarginStatus,
+    };
+
+    #[tokio::test]
+    async fn test_get_wallet_balance() {
+        let server = MockServer::start().await;
+        server.mock_rest(
+            PATH_ACCOUNT_WALLET_BALANCE,
+            json!({
+                "list": [{
+                    "totalEquity": "3.31216591",
+                    "accountIMRate": "0",
+                    "totalMarginBalance": "3.00326056",
+                    "totalInitialMargin": "0",
+                    "accountType": "UNIFIED",
+                    "totalAvailableBalance": "3.00326056",
+                    "accountMMRate": "0",
+                    "totalPerpUPL": "0",
+                    "totalWalletBalance": "3.00326056",
+                    "accountLTV": "0",
+                    "totalMaintenanceMargin": "0",
+                    "coin": [{
+                        "availableToBorrow": "",
+                        "bonus": "0",
+                        "accruedInterest": "0",
+                        "availableToWithdraw": "",
+                        "totalOrderIM": "0",
+                        "equity": "0.17",
+                        "totalPositionMM": "0",
+                        "usdValue": "9.79307888",
+                        "spotHedgingQty": "0.01592413",
+                        "unrealisedPnl": "0",
+                        "collateralSwitch": true,
+                        "borrowAmount": "0.0",
+                        "totalPositionIM": "0",
+                        "walletBalance": "0.17",
+                        "cumRealisedPnl": "-0.00004832",
+                        "locked": "0",
+                        "marginCollateral": true,
+                        "coin": "ETH"
+                    }]
+                }]
+            }),
+        );
+        let balance = signed_client(&server.url())
+            .get_wallet_balance(GetWalletBalanceParams {
+                account_type: AccountType::UNIFIED,
+                coin: Some(String::from("ETH")),
+            })
+            .await
+            .unwrap();
+
+        let wallet = &balance.list[0];
+        assert_eq!(wallet.account_type, AccountType::UNIFIED);
+        assert_eq!(wallet.total_equity, Some(n!(3.31216591)));
+        assert_eq!(wallet.account_mm_rate, Some(n!(0.0)));
+        let coin = &wallet.coin[0];
+        assert_eq!(coin.coin, "ETH");
+        assert_eq!(coin.equity, n!(0.17));
+        assert_eq!(coin.available_to_withdraw, None);
+        assert_eq!(coin.total_position_im, Some(n!(0.0)));
+        assert_eq!(coin.cum_realised_pnl, Some(n!(-0.00004832)));
+
+        let requests = server.rest_requests();
+        assert_eq!(
+            requests[0].query.as_deref(),
+            Some("accountType=UNIFIED&coin=ETH")
+        );
+        assert_eq!(requests[0].api_key.as_deref(), Some("api_key"));
+    }
+
+    #[tokio::test]
+    async fn test_get_account_info() {
+        let server = MockServer::start().await;
+        server.mock_rest(
+            PATH_ACCOUNT_INFO,
+            json!({
+                "marginMode": "REGULAR_MARGIN",
+                "updatedTime": "1697078946000",
+                "unifiedMarginStatus": 5,
+                "dcpStatus": "OFF",
+                "timeWindow": 10,
+                "smpGroup": 0,
+                "isMasterTrader": false,
+                "spotHedgingStatus": "OFF"
+            }),
+        );
+        let info = signed_client(&server.url())
+            .get_account_info()
+            .await
+            .unwrap();
+        assert_eq!(
+            info.unified_margin_status,
+            UnifiedMarginStatus::UnifiedTradingAccount2
+        );
+        assert_eq!(info.margin_mode, MarginMode::RegularMargin);
+        assert_eq!(info.updated_time, 1697078946000);
+        assert_eq!(info.time_window, 10);
+    }
+
+    #[tokio::test]
+    async fn test_set_margin_mode() {
+        let server = MockServer::start().await;
+        server.mock_rest(
+            PATH_ACCOUNT_SET_MARGIN_MODE,
+            json!({
+                "reasons": [{
+                    "reasonCode": "3400045",
+                    "reasonMsg": "Set margin mode failed"
+                }]
+            }),
+        );
+        let result = signed_client(&server.url())
+            .set_margin_mode(SetMarginModeParams {
+                set_margin_mode: MarginMode::PortfolioMargin,
+            })
+            .await
+            .unwrap();
+        assert_eq!(result.reasons[0].reason_code, "3400045");
+        assert_eq!(
+            server.rest_requests()[0].body,
+            r#"{"setMarginMode":"PORTFOLIO_MARGIN"}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_demo_apply_money() {
+        let server = MockServer::start().await;
+        server.mock_rest(PATH_ACCOUNT_SET_MARGIN_MODE_DEMO_APPLY_MONEY, json!({}));
+        signed_client(&server.url())
+            .demo_apply_money(DemoApplyMoneyParams {
+                adjust_type: Some(0),
+                uta_demo_apply_money: vec![DemoApplyCoin {
+                    coin: String::from("USDT"),
+                    amount_str: n!(10000.0),
+                }],
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            server.rest_requests()[0].body,
+            r#"{"adjustType":0,"utaDemoApplyMoney":[{"coin":"USDT","amountStr":"10000"}]}"#
+        );
+    }
+}
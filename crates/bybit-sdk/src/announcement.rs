@@ -0,0 +1,307 @@
+use futures_util::{stream, Stream};
+use std::{collections::VecDeque, time::Duration};
+
+use crate::{
+    paginate::PageState, url::PATH_ANNOUNCEMENTS_INDEX, Announcement, Announcements, Client,
+    GetAnnouncementsParams, Pagination, Result,
+};
+
+/// Pages a poll looks through at most before giving up on finding the last seen announcement.
+const MAX_POLL_PAGES: u32 = 5;
+
+impl Client {
+    /// A single page, newest first; [`Client::announcements`] streams all of them.
+    pub async fn get_announcements(
+        &self,
+        params: &GetAnnouncementsParams,
+    ) -> Result<Announcements> {
+        self.get(PATH_ANNOUNCEMENTS_INDEX, params).await
+    }
+
+    /// Announcements of all pages, newest first. The time window of `pagination` is ignored.
+    /// The stream ends after the first error.
+    pub fn announcements(
+        &self,
+        mut params: GetAnnouncementsParams,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<Announcement>> + '_ {
+        params.page = Some(params.page.unwrap_or(1));
+        let state = PageState::new(params, &pagination);
+
+        stream::unfold(state, move |mut state| async move {
+            loop {
+                if state.remaining == Some(0) {
+                    return None;
+                }
+                if let Some(item) = state.next_item() {
+                    return Some((Ok(item), state));
+                }
+                if state.last_page {
+                    return None;
+                }
+
+                let page = match self.get_announcements(&state.params).await {
+                    Ok(page) => page,
+                    Err(e) => {
+                        state.last_page = true;
+                        return Some((Err(e), state));
+                    }
+                };
+                let page_number = state.params.page.unwrap_or(1);
+                let limit = state.params.limit.unwrap_or(page.list.len() as u32).max(1);
+                let fetched = u64::from(page_number) * u64::from(limit);
+                state.last_page = page.list.is_empty() || fetched >= page.total;
+                state.params.page = Some(page_number + 1);
+                state.items.extend(page.list);
+            }
+        })
+    }
+
+    /// Announcements published after the previous poll, oldest first, checked every
+    /// `interval`. See [`AnnouncementPoller`] for what counts as new. A failed poll yields
+    /// the error and the stream keeps polling.
+    pub fn watch_announcements(
+        &self,
+        poller: AnnouncementPoller,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<Announcement>> + '_ {
+        let state = (poller, VecDeque::new(), true);
+
+        stream::unfold(
+            state,
+            move |(mut poller, mut items, mut first)| async move {
+                loop {
+                    if let Some(item) = items.pop_front() {
+                        return Some((Ok(item), (poller, items, first)));
+                    }
+                    if !first {
+                        tokio::time::sleep(interval).await;
+                    }
+                    first = false;
+                    match poller.poll(self).await {
+                        Ok(new) => items.extend(new),
+                        Err(e) => return Some((Err(e), (poller, items, first))),
+                    }
+                }
+            },
+        )
+    }
+}
+
+/// Tracks the newest announcement seen, to return only the ones published after it.
+///
+/// The first poll only records the newest announcement unless a starting point was given
+/// with [`AnnouncementPoller::with_last_seen`], so a restarted bot does not replay the feed.
+#[derive(Debug, Clone)]
+pub struct AnnouncementPoller {
+    params: GetAnnouncementsParams,
+    /// `dateTimestamp` of the newest announcement seen.
+    last_seen: Option<u64>,
+    /// URLs of the announcements seen with `last_seen`, which can be shared.
+    seen_urls: Vec<String>,
+}
+
+impl AnnouncementPoller {
+    /// `params.page` is ignored, polls always start from the newest page.
+    pub fn new(params: GetAnnouncementsParams) -> Self {
+        Self {
+            params,
+            last_seen: None,
+            seen_urls: Vec::new(),
+        }
+    }
+
+    /// Returns announcements with a `dateTimestamp` (ms) after `last_seen` from the first poll.
+    pub fn with_last_seen(mut self, last_seen: u64) -> Self {
+        self.last_seen = Some(last_seen);
+        self
+    }
+
+    /// `dateTimestamp` of the newest announcement seen.
+    pub fn last_seen(&self) -> Option<u64> {
+        self.last_seen
+    }
+
+    /// Announcements published since the previous poll, oldest first.
+    pub async fn poll(&mut self, client: &Client) -> Result<Vec<Announcement>> {
+        let mut params = self.params.clone();
+        let mut new = Vec::new();
+        for page in 1..=MAX_POLL_PAGES {
+            params.page = Some(page);
+            let announcements = client.get_announcements(&params).await?;
+            let count = announcements.list.len();
+            let mut reached_last_seen = self.last_seen.is_none();
+            for announcement in announcements.list {
+                if self.is_new(&announcement) {
+                    new.push(announcement);
+                } else {
+                    reached_last_seen = true;
+                }
+            }
+            let fetched = u64::from(page) * u64::from(params.limit.unwrap_or(count as u32));
+            if reached_last_seen || count == 0 || fetched >= announcements.total {
+                break;
+            }
+        }
+
+        let first_poll = self.last_seen.is_none();
+        for announcement in &new {
+            self.mark_seen(announcement);
+        }
+        if first_poll {
+            tracing::debug!(
+                last_seen = self.last_seen,
+                "Announcements baseline recorded"
+            );
+            return Ok(Vec::new());
+        }
+        new.sort_by_key(|announcement| announcement.date_timestamp);
+        Ok(new)
+    }
+
+    fn is_new(&self, announcement: &Announcement) -> bool {
+        match self.last_seen {
+            None => true,
+            Some(last_seen) if announcement.date_timestamp == last_seen => {
+                // Without the URLs, as set by `with_last_seen`, the whole millisecond is seen.
+                !self.seen_urls.is_empty() && !self.seen_urls.contains(&announcement.url)
+            }
+            Some(last_seen) => announcement.date_timestamp > last_seen,
+        }
+    }
+
+    fn mark_seen(&mut self, announcement: &Announcement) {
+        match self.last_seen {
+            Some(last_seen) if announcement.date_timestamp < last_seen => {}
+            Some(last_seen) if announcement.date_timestamp == last_seen => {
+                self.seen_urls.push(announcement.url.clone());
+            }
+            _ => {
+                self.last_seen = Some(announcement.date_timestamp);
+                self.seen_urls = vec![announcement.url.clone()];
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bybit_mock::MockServer;
+    use futures_util::{StreamExt, TryStreamExt};
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::{AnnouncementType, Locale};
+
+    fn announcement(title: &str, date_timestamp: u64) -> Value {
+        json!({
+            "title": title,
+            "description": "",
+            "type": { "title": "New Listings", "key": "new_crypto" },
+            "tags": ["Spot", "Spot Listings"],
+            "url": format!("https://announcements.bybit.com/en-US/article/{title}"),
+            "dateTimestamp": date_timestamp,
+            "startDateTimestamp": date_timestamp,
+            "endDateTimestamp": 1735545600000u64
+        })
+    }
+
+    fn params() -> GetAnnouncementsParams {
+        GetAnnouncementsParams {
+            type_: Some(AnnouncementType::NewCrypto),
+            ..GetAnnouncementsParams::new(Locale::EnUs)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_announcements() {
+        let server = MockServer::start().await;
+        server.mock_rest(
+            PATH_ANNOUNCEMENTS_INDEX,
+            json!({
+                "total": 2,
+                "list": [announcement("b", 1702449475000), announcement("a", 1702449474000)]
+            }),
+        );
+        let client = Client::new(&server.url());
+        let announcements: Vec<Announcement> = client
+            .announcements(params(), Pagination::default())
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(announcements.len(), 2);
+        assert_eq!(announcements[0].title, "b");
+        assert_eq!(announcements[0].type_.key, AnnouncementType::NewCrypto);
+        assert_eq!(announcements[1].date_timestamp, 1702449474000);
+        let requests = server.rest_requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(
+            requests[0].query.as_deref(),
+            Some("locale=en-US&type=new_crypto&page=1")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_announcements_large_page_number() {
+        let server = MockServer::start().await;
+        server.mock_rest(
+            PATH_ANNOUNCEMENTS_INDEX,
+            json!({ "total": 1, "list": [announcement("a", 1702449474000)] }),
+        );
+        let client = Client::new(&server.url());
+        let params = GetAnnouncementsParams {
+            page: Some(500_000_000),
+            limit: Some(10),
+            ..params()
+        };
+        let announcements: Vec<Announcement> = client
+            .announcements(params, Pagination::default())
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(announcements.len(), 1);
+        assert_eq!(server.rest_requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_poller() {
+        let server = MockServer::start().await;
+        let client = Client::new(&server.url());
+        server.mock_rest(
+            PATH_ANNOUNCEMENTS_INDEX,
+            json!({ "total": 1, "list": [announcement("a", 1000)] }),
+        );
+        let mut poller = AnnouncementPoller::new(params());
+        assert!(poller.poll(&client).await.unwrap().is_empty());
+        assert_eq!(poller.last_seen(), Some(1000));
+
+        // A second announcement with the same timestamp is still new.
+        server.mock_rest(
+            PATH_ANNOUNCEMENTS_INDEX,
+            json!({
+                "total": 4,
+                "list": [
+                    announcement("d", 2000),
+                    announcement("c", 1500),
+                    announcement("b", 1000),
+                    announcement("a", 1000)
+                ]
+            }),
+        );
+        let new = poller.poll(&client).await.unwrap();
+        let titles: Vec<&str> = new.iter().map(|a| a.title.as_str()).collect();
+        assert_eq!(titles, ["b", "c", "d"]);
+        assert!(poller.poll(&client).await.unwrap().is_empty());
+
+        let poller = AnnouncementPoller::new(params()).with_last_seen(1500);
+        let new: Vec<Announcement> = client
+            .watch_announcements(poller, Duration::from_secs(60))
+            .take(1)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(new[0].title, "d");
+    }
+}
@@ -3,16 +3,22 @@ use serde_aux::prelude::{
     deserialize_number_from_string as number,
     deserialize_option_number_from_string as option_number,
 };
+use uuid::Uuid;
 
 use crate::{
-    AutoAddMargin, CancelType, Category, ContractType, CopyTrading, CreateType, CurAuctionPhase,
-    Innovation, Interval, OcoTriggerBy, OrderStatus, OrderType, Pair, PlaceType, PositionIdx,
-    PositionStatus, RejectReason, Side, SmpType, Status, StopOrderType, TimeInForce, TpslMode,
-    TradeMode, TriggerBy, TriggerDirection,
+    common::{serialize_as_string as as_string, serialize_option_as_string as option_as_string},
+    number::ZERO,
+    AccountType, AnnouncementType, AutoAddMargin, CancelType, Category, ContractType, CopyTrading,
+    CreateType, CurAuctionPhase, DepositStatus, ExecType, Innovation, Interval, IntervalTime,
+    Locale, LtOrderStatus, LtOrderType, LtStatus, MarginMode, Number, OcoTriggerBy, OptionPeriod,
+    OptionsType, OrderBookLevelMsg, OrderStatus, OrderType, Pair, PlaceType, PositionIdx,
+    PositionMode, PositionStatus, RejectReason, Side, SmpType, SpotMarginMode, Status,
+    StopOrderType, TimeInForce, TpslMode, TradeMode, TransferStatus, TriggerBy, TriggerDirection,
+    Type, UnifiedMarginStatus, WithdrawStatus,
 };
 
 #[derive(Debug, Deserialize, PartialEq)]
-pub struct Response<T> {
+pub struct Response<T, E = RetExtInfo> {
     #[serde(rename = "retCode")]
     pub ret_code: i32,
     #[serde(rename = "retMsg")]
@@ -20,12 +26,24 @@ pub struct Response<T> {
     pub result: T,
     pub time: i64,
     #[serde(rename = "retExtInfo")]
-    pub ret_ext_info: RetExtInfo,
+    pub ret_ext_info: E,
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
 pub struct RetExtInfo {}
 
+/// Result of `/v5/market/time`.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerTime {
+    /// Server timestamp (s)
+    #[serde(deserialize_with = "number")]
+    pub time_second: u64,
+    /// Server timestamp (ns)
+    #[serde(deserialize_with = "number")]
+    pub time_nano: u64,
+}
+
 #[derive(Serialize)]
 pub struct GetKLinesParams {
     pub category: Category,
@@ -54,20 +72,21 @@ pub struct KLineRow {
     #[serde(rename = "startTime", deserialize_with = "number")]
     pub start_time: u64, // Start time of the candle (ms)
     #[serde(rename = "openPrice", deserialize_with = "number")]
-    pub open_price: f64, // Open price
+    pub open_price: Number, // Open price
     #[serde(rename = "highPrice", deserialize_with = "number")]
-    pub high_price: f64, // Highest price
+    pub high_price: Number, // Highest price
     #[serde(rename = "lowPrice", deserialize_with = "number")]
-    pub low_price: f64, // Lowest price
+    pub low_price: Number, // Lowest price
     #[serde(rename = "closePrice", deserialize_with = "number")]
-    pub close_price: f64, // Close price. Is the last traded price when the candle is not closed
+    pub close_price: Number, // Close price. Is the last traded price when the candle is not closed
     #[serde(rename = "volume", deserialize_with = "number")]
-    pub volume: f64, // Trade volume. Unit of contract: pieces of contract. Unit of spot: quantity of coins
+    pub volume: Number, // Trade volume. Unit of contract: pieces of contract. Unit of spot: quantity of coins
     #[serde(rename = "turnover", deserialize_with = "number")]
-    pub turnover: f64, // Turnover. Unit of figure: quantity of quota coin
+    pub turnover: Number, // Turnover. Unit of figure: quantity of quota coin
 }
 
 #[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct GetInstrumentsInfoParams {
     pub category: Category,
     pub symbol: Option<String>,
@@ -93,7 +112,7 @@ pub enum InstrumentsInfo {
     #[serde(rename = "option", rename_all = "camelCase")]
     Option {
         next_page_cursor: String,
-        list: Vec<AllCategoriesInstrumentsInfo>, // TODO: Rewrite this.
+        list: Vec<OptionInstrumentsInfo>,
     },
     #[serde(rename = "spot", rename_all = "camelCase")]
     Spot {
@@ -115,7 +134,7 @@ pub struct AllCategoriesInstrumentsInfo {
     #[serde(deserialize_with = "number")]
     pub delivery_time: i64,
     #[serde(deserialize_with = "option_number")]
-    pub delivery_fee_rate: Option<f64>,
+    pub delivery_fee_rate: Option<Number>,
     #[serde(deserialize_with = "number")]
     pub price_scale: i64,
     pub leverage_filter: LeverageFilter,
@@ -126,14 +145,35 @@ pub struct AllCategoriesInstrumentsInfo {
     pub settle_coin: String,
     pub copy_trading: CopyTrading,
     #[serde(deserialize_with = "number")]
-    pub upper_funding_rate: f64,
+    pub upper_funding_rate: Number,
     #[serde(deserialize_with = "number")]
-    pub lower_funding_rate: f64,
+    pub lower_funding_rate: Number,
     pub risk_parameters: RiskParameters,
     pub is_pre_listing: bool,
     pub pre_listing_info: Option<PreListingInfo>,
 }
 
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionInstrumentsInfo {
+    pub symbol: String,
+    pub options_type: OptionsType,
+    pub status: Status,
+    pub base_coin: String,
+    pub quote_coin: String,
+    pub settle_coin: String,
+    #[serde(deserialize_with = "number")]
+    pub launch_time: i64,
+    #[serde(deserialize_with = "number")]
+    pub delivery_time: i64,
+    #[serde(deserialize_with = "option_number")]
+    pub delivery_fee_rate: Option<Number>,
+    pub price_filter: PriceFilter,
+    pub lot_size_filter: OptionLotSizeFilter,
+    /// Symbol shown on the website, e.g. `BTCUSDT-27DEC24-100000-C`.
+    pub display_name: String,
+}
+
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct SpotInstrumentsInfo {
@@ -155,72 +195,83 @@ pub struct SpotInstrumentsInfo {
 #[serde(rename_all = "camelCase")]
 pub struct LeverageFilter {
     #[serde(deserialize_with = "number")]
-    pub min_leverage: f64,
+    pub min_leverage: Number,
     #[serde(deserialize_with = "number")]
-    pub max_leverage: f64,
+    pub max_leverage: Number,
     #[serde(deserialize_with = "number")]
-    pub leverage_step: f64,
+    pub leverage_step: Number,
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct PriceFilter {
     #[serde(deserialize_with = "number")]
-    pub min_price: f64,
+    pub min_price: Number,
     #[serde(deserialize_with = "number")]
-    pub max_price: f64,
+    pub max_price: Number,
     #[serde(deserialize_with = "number")]
-    pub tick_size: f64,
+    pub tick_size: Number,
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct SpotPriceFilter {
     #[serde(deserialize_with = "number")]
-    pub tick_size: f64, // The step to increase/reduce order price
+    pub tick_size: Number, // The step to increase/reduce order price
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct LotSizeFilter {
     #[serde(deserialize_with = "number")]
-    pub min_notional_value: f64,
+    pub min_notional_value: Number,
+    #[serde(deserialize_with = "number")]
+    pub max_order_qty: Number,
+    #[serde(deserialize_with = "number")]
+    pub max_mkt_order_qty: Number,
     #[serde(deserialize_with = "number")]
-    pub max_order_qty: f64,
+    pub min_order_qty: Number,
     #[serde(deserialize_with = "number")]
-    pub max_mkt_order_qty: f64,
+    pub qty_step: Number,
     #[serde(deserialize_with = "number")]
-    pub min_order_qty: f64,
+    pub post_only_max_order_qty: Number,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionLotSizeFilter {
     #[serde(deserialize_with = "number")]
-    pub qty_step: f64,
+    pub max_order_qty: Number,
     #[serde(deserialize_with = "number")]
-    pub post_only_max_order_qty: f64,
+    pub min_order_qty: Number,
+    #[serde(deserialize_with = "number")]
+    pub qty_step: Number,
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct SpotLotSizeFilter {
     #[serde(deserialize_with = "number")]
-    pub base_precision: f64, // The precision of base coin
+    pub base_precision: Number, // The precision of base coin
     #[serde(deserialize_with = "number")]
-    pub quote_precision: f64, // The precision of quote coin
+    pub quote_precision: Number, // The precision of quote coin
     #[serde(deserialize_with = "number")]
-    pub min_order_qty: f64, // Minimum order quantity
+    pub min_order_qty: Number, // Minimum order quantity
     #[serde(deserialize_with = "number")]
-    pub max_order_qty: f64, // Maximum order quantity
+    pub max_order_qty: Number, // Maximum order quantity
     #[serde(deserialize_with = "number")]
-    pub min_order_amt: f64, // Minimum order amount
+    pub min_order_amt: Number, // Minimum order amount
     #[serde(deserialize_with = "number")]
-    pub max_order_amt: f64, // Maximum order amount
+    pub max_order_amt: Number, // Maximum order amount
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct RiskParameters {
     #[serde(deserialize_with = "number")]
-    pub price_limit_ratio_x: f64,
+    pub price_limit_ratio_x: Number,
     #[serde(deserialize_with = "number")]
-    pub price_limit_ratio_y: f64,
+    pub price_limit_ratio_y: Number,
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
@@ -245,14 +296,15 @@ pub struct Phase {
 #[serde(rename_all = "camelCase")]
 pub struct AuctionFeeInfo {
     #[serde(deserialize_with = "number")]
-    pub auction_fee_rate: f64,
+    pub auction_fee_rate: Number,
     #[serde(deserialize_with = "number")]
-    pub taker_fee_rate: f64,
+    pub taker_fee_rate: Number,
     #[serde(deserialize_with = "number")]
-    pub maker_fee_rate: f64,
+    pub maker_fee_rate: Number,
 }
 
 #[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct GetTickersParams {
     pub category: Category,
     pub symbol: Option<String>,
@@ -278,31 +330,31 @@ pub enum Ticker {
 pub struct LinearInverseTicker {
     pub symbol: String, // Symbol name
     #[serde(deserialize_with = "number")]
-    pub last_price: f64, // Last price
+    pub last_price: Number, // Last price
     #[serde(deserialize_with = "number")]
-    pub mark_price: f64, // Mark price
+    pub mark_price: Number, // Mark price
     #[serde(deserialize_with = "number")]
-    pub index_price: f64, // Index price
+    pub index_price: Number, // Index price
     #[serde(deserialize_with = "number")]
-    pub prev_price24h: f64, // Market price 24 hours ago
+    pub prev_price24h: Number, // Market price 24 hours ago
     #[serde(deserialize_with = "number")]
-    pub price24h_pcnt: f64, // Percentage change of market price in the last 24 hours
+    pub price24h_pcnt: Number, // Percentage change of market price in the last 24 hours
     #[serde(deserialize_with = "number")]
-    pub high_price24h: f64, // The highest price in the last 24 hours
+    pub high_price24h: Number, // The highest price in the last 24 hours
     #[serde(deserialize_with = "number")]
-    pub low_price24h: f64, // The lowest price in the last 24 hours
+    pub low_price24h: Number, // The lowest price in the last 24 hours
     #[serde(deserialize_with = "number")]
-    pub prev_price1h: f64, // Market price an hour ago
+    pub prev_price1h: Number, // Market price an hour ago
     #[serde(deserialize_with = "number")]
-    pub open_interest: f64, // Open interest size
+    pub open_interest: Number, // Open interest size
     #[serde(deserialize_with = "number")]
-    pub open_interest_value: f64, // Open interest value
+    pub open_interest_value: Number, // Open interest value
     #[serde(deserialize_with = "number")]
-    pub turnover24h: f64, // Turnover for 24h
+    pub turnover24h: Number, // Turnover for 24h
     #[serde(deserialize_with = "number")]
-    pub volume24h: f64, // Volume for 24h
+    pub volume24h: Number, // Volume for 24h
     #[serde(deserialize_with = "number")]
-    pub funding_rate: f64, // Funding rate
+    pub funding_rate: Number, // Funding rate
     #[serde(deserialize_with = "number")]
     pub next_funding_time: u64, // Next funding timestamp (ms)
     pub predicted_delivery_price: String, // Predicated delivery price. It has value when 30 min before delivery
@@ -311,13 +363,13 @@ pub struct LinearInverseTicker {
     pub delivery_fee_rate: String, // Delivery fee rate. Unique field for inverse futures & USDC futures
     pub delivery_time: String, // Delivery date time (UTC+0). Unique field for inverse futures & USDC futures
     #[serde(deserialize_with = "number")]
-    pub bid1_price: f64, // Best bid price
+    pub bid1_price: Number, // Best bid price
     #[serde(deserialize_with = "number")]
-    pub bid1_size: f64, // Best bid size
+    pub bid1_size: Number, // Best bid size
     #[serde(deserialize_with = "number")]
-    pub ask1_price: f64, // Best ask price
+    pub ask1_price: Number, // Best ask price
     #[serde(deserialize_with = "number")]
-    pub ask1_size: f64, // Best ask size
+    pub ask1_size: Number, // Best ask size
     pub pre_open_price: String, // Estimated pre-market contract open price. The value is meaningless when entering continuous trading phase.
     pub pre_qty: String, // Estimated pre-market contract open qty. The value is meaningless when entering continuous trading phase.
     pub cur_pre_listing_phase: String, // Enum: NotStarted, Finished, CallAuction, CallAuctionNoCancel, CrossMatching, ContinuousTrading.
@@ -328,53 +380,53 @@ pub struct LinearInverseTicker {
 pub struct OptionTicker {
     pub symbol: String, // Symbol name
     #[serde(deserialize_with = "number")]
-    pub bid1_price: f64, // Best bid price
+    pub bid1_price: Number, // Best bid price
     #[serde(deserialize_with = "number")]
-    pub bid1_size: f64, // Best bid size
+    pub bid1_size: Number, // Best bid size
     #[serde(deserialize_with = "number")]
-    pub bid1_iv: f64, // Best bid iv
+    pub bid1_iv: Number, // Best bid iv
     #[serde(deserialize_with = "number")]
-    pub ask1_price: f64, // Best ask price
+    pub ask1_price: Number, // Best ask price
     #[serde(deserialize_with = "number")]
-    pub ask1_size: f64, // Best ask size
+    pub ask1_size: Number, // Best ask size
     #[serde(deserialize_with = "number")]
-    pub ask1_iv: f64, // Best ask iv
+    pub ask1_iv: Number, // Best ask iv
     #[serde(deserialize_with = "number")]
-    pub last_price: f64, // Last price
+    pub last_price: Number, // Last price
     #[serde(deserialize_with = "number")]
-    pub high_price24h: f64, // The highest price in the last 24 hours
+    pub high_price24h: Number, // The highest price in the last 24 hours
     #[serde(deserialize_with = "number")]
-    pub low_price24h: f64, // The lowest price in the last 24 hours
+    pub low_price24h: Number, // The lowest price in the last 24 hours
     #[serde(deserialize_with = "number")]
-    pub mark_price: f64, // Mark price
+    pub mark_price: Number, // Mark price
     #[serde(deserialize_with = "number")]
-    pub index_price: f64, // Index price
+    pub index_price: Number, // Index price
     #[serde(deserialize_with = "number")]
-    pub mark_iv: f64, // Mark price iv
+    pub mark_iv: Number, // Mark price iv
     #[serde(deserialize_with = "number")]
-    pub underlying_price: f64, // Underlying price
+    pub underlying_price: Number, // Underlying price
     #[serde(deserialize_with = "number")]
-    pub open_interest: f64, // Open interest size
+    pub open_interest: Number, // Open interest size
     #[serde(deserialize_with = "number")]
-    pub turnover24h: f64, // Turnover for 24h
+    pub turnover24h: Number, // Turnover for 24h
     #[serde(deserialize_with = "number")]
-    pub volume24h: f64, // Volume for 24h
+    pub volume24h: Number, // Volume for 24h
     #[serde(deserialize_with = "number")]
-    pub total_volume: f64, // Total volume
+    pub total_volume: Number, // Total volume
     #[serde(deserialize_with = "number")]
-    pub total_turnover: f64, // Total turnover
+    pub total_turnover: Number, // Total turnover
     #[serde(deserialize_with = "number")]
-    pub delta: f64, // Delta
+    pub delta: Number, // Delta
     #[serde(deserialize_with = "number")]
-    pub gamma: f64, // Gamma
+    pub gamma: Number, // Gamma
     #[serde(deserialize_with = "number")]
-    pub vega: f64, // Vega
+    pub vega: Number, // Vega
     #[serde(deserialize_with = "number")]
-    pub theta: f64, // Theta
+    pub theta: Number, // Theta
     #[serde(deserialize_with = "number")]
-    pub predicted_delivery_price: f64, // Predicated delivery price. It has value when 30 min before delivery
+    pub predicted_delivery_price: Number, // Predicated delivery price. It has value when 30 min before delivery
     #[serde(deserialize_with = "number")]
-    pub change24h: f64, // The change in the last 24 hous
+    pub change24h: Number, // The change in the last 24 hous
 }
 
 #[derive(Debug, Deserialize)]
@@ -382,33 +434,33 @@ pub struct OptionTicker {
 pub struct SpotTicker {
     pub symbol: String, // Symbol name
     #[serde(deserialize_with = "number")]
-    pub bid1_price: f64, // Best bid price
+    pub bid1_price: Number, // Best bid price
     #[serde(deserialize_with = "number")]
-    pub bid1_size: f64, // Best bid size
+    pub bid1_size: Number, // Best bid size
     #[serde(deserialize_with = "number")]
-    pub ask1_price: f64, // Best ask price
+    pub ask1_price: Number, // Best ask price
     #[serde(deserialize_with = "number")]
-    pub ask1_size: f64, // Best ask size
+    pub ask1_size: Number, // Best ask size
     #[serde(deserialize_with = "number")]
-    pub last_price: f64, // Last price
+    pub last_price: Number, // Last price
     #[serde(deserialize_with = "number")]
-    pub prev_price24h: f64, // Market price 24 hours ago
+    pub prev_price24h: Number, // Market price 24 hours ago
     #[serde(deserialize_with = "number")]
-    pub price24h_pcnt: f64, // Percentage change of market price in the last 24 hours
+    pub price24h_pcnt: Number, // Percentage change of market price in the last 24 hours
     #[serde(deserialize_with = "number")]
-    pub high_price24h: f64, // The highest price in the last 24 hours
+    pub high_price24h: Number, // The highest price in the last 24 hours
     #[serde(deserialize_with = "number")]
-    pub low_price24h: f64, // The lowest price in the last 24 hours
+    pub low_price24h: Number, // The lowest price in the last 24 hours
     #[serde(deserialize_with = "number")]
-    pub turnover24h: f64, // Turnover for 24h
+    pub turnover24h: Number, // Turnover for 24h
     #[serde(deserialize_with = "number")]
-    pub volume24h: f64, // Volume for 24h
+    pub volume24h: Number, // Volume for 24h
     // USD index price
     // - used to calculate USD value of the assets in Unified account
     // - non-collateral margin coin returns ""
     // - Only those trading pairs like "XXX/USDT" or "XXX/USDC" have the value
     #[serde(deserialize_with = "number")]
-    pub usd_index_price: f64,
+    pub usd_index_price: Number,
 }
 
 #[derive(Serialize)]
@@ -447,9 +499,9 @@ pub struct InverseLinearSpotTrade {
     pub exec_id: String,
     pub symbol: String,
     #[serde(deserialize_with = "number")]
-    pub price: f64,
+    pub price: Number,
     #[serde(deserialize_with = "number")]
-    pub size: f64,
+    pub size: Number,
     pub side: Side,
     #[serde(deserialize_with = "number")]
     pub time: u64,
@@ -462,21 +514,317 @@ pub struct OptionTrade {
     pub exec_id: String,
     pub symbol: String,
     #[serde(deserialize_with = "number")]
-    pub price: f64,
+    pub price: Number,
     #[serde(deserialize_with = "number")]
-    pub size: f64,
+    pub size: Number,
     pub side: Side,
     #[serde(deserialize_with = "number")]
     pub time: u64,
     pub is_block_trade: bool,
     #[serde(rename = "mP", deserialize_with = "number")]
-    pub mark_price: f64,
+    pub mark_price: Number,
     #[serde(rename = "iP", deserialize_with = "number")]
-    pub index_price: f64,
+    pub index_price: Number,
     #[serde(rename = "mIv", deserialize_with = "number")]
-    pub mark_iv: f64,
+    pub mark_iv: Number,
     #[serde(rename = "iv", deserialize_with = "number")]
-    pub iv: f64,
+    pub iv: Number,
+}
+
+/// Result of `/v5/market/mark-price-kline`, `/v5/market/index-price-kline` and
+/// `/v5/market/premium-index-price-kline` (linear only). Requested with [`GetKLinesParams`].
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(tag = "category")]
+pub enum PriceKLine {
+    #[serde(rename = "inverse")]
+    Inverse {
+        symbol: String,
+        list: Vec<PriceKLineRow>,
+    },
+    #[serde(rename = "linear")]
+    Linear {
+        symbol: String,
+        list: Vec<PriceKLineRow>,
+    },
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct PriceKLineRow {
+    #[serde(rename = "startTime", deserialize_with = "number")]
+    pub start_time: u64, // Start time of the candle (ms)
+    #[serde(rename = "openPrice", deserialize_with = "number")]
+    pub open_price: Number, // Open price
+    #[serde(rename = "highPrice", deserialize_with = "number")]
+    pub high_price: Number, // Highest price
+    #[serde(rename = "lowPrice", deserialize_with = "number")]
+    pub low_price: Number, // Lowest price
+    #[serde(rename = "closePrice", deserialize_with = "number")]
+    pub close_price: Number, // Close price. Is the last price when the candle is not closed
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetOrderBookParams {
+    pub category: Category,
+    pub symbol: String,
+    /// spot: [1, 200], default: 1
+    /// linear & inverse: [1, 500], default: 25
+    /// option: [1, 25], default: 1
+    pub limit: Option<u64>,
+}
+
+/// Result of `/v5/market/orderbook`.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct OrderBookSnapshot {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// Bids, sorted by price in descending order
+    #[serde(rename = "b")]
+    pub bids: Vec<OrderBookLevelMsg>,
+    /// Asks, sorted by price in ascending order
+    #[serde(rename = "a")]
+    pub asks: Vec<OrderBookLevelMsg>,
+    /// Timestamp (ms) the system generates the data
+    pub ts: u64,
+    /// Update ID, is always in sequence
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    /// Cross sequence
+    pub seq: u64,
+    /// Timestamp (ms) from the matching engine when the data is produced
+    pub cts: u64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetFundingHistoryParams {
+    /// linear, inverse
+    pub category: Category,
+    pub symbol: String,
+    /// The start timestamp (ms)
+    pub start_time: Option<u64>,
+    /// The end timestamp (ms)
+    pub end_time: Option<u64>,
+    /// [1, 200], default: 200
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(tag = "category")]
+pub enum FundingHistory {
+    #[serde(rename = "inverse")]
+    Inverse { list: Vec<FundingRate> },
+    #[serde(rename = "linear")]
+    Linear { list: Vec<FundingRate> },
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRate {
+    pub symbol: String,
+    #[serde(deserialize_with = "number")]
+    pub funding_rate: Number,
+    /// Funding rate settlement time (ms)
+    #[serde(deserialize_with = "number")]
+    pub funding_rate_timestamp: u64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetOpenInterestParams {
+    /// linear, inverse
+    pub category: Category,
+    pub symbol: String,
+    pub interval_time: IntervalTime,
+    /// The start timestamp (ms)
+    pub start_time: Option<u64>,
+    /// The end timestamp (ms)
+    pub end_time: Option<u64>,
+    /// [1, 200], default: 50
+    pub limit: Option<u64>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(tag = "category")]
+pub enum OpenInterest {
+    #[serde(rename = "inverse", rename_all = "camelCase")]
+    Inverse {
+        symbol: String,
+        list: Vec<OpenInterestRow>,
+        next_page_cursor: String,
+    },
+    #[serde(rename = "linear", rename_all = "camelCase")]
+    Linear {
+        symbol: String,
+        list: Vec<OpenInterestRow>,
+        next_page_cursor: String,
+    },
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestRow {
+    /// Open interest. Unit of linear: base coin, unit of inverse: USD
+    #[serde(deserialize_with = "number")]
+    pub open_interest: Number,
+    /// The timestamp (ms)
+    #[serde(deserialize_with = "number")]
+    pub timestamp: u64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetHistoricalVolatilityParams {
+    /// option
+    pub category: Category,
+    /// Base coin. Default: BTC
+    pub base_coin: Option<String>,
+    /// Quote coin, USD or USDT. Default: USD
+    pub quote_coin: Option<String>,
+    /// Default: 7
+    pub period: Option<OptionPeriod>,
+    /// The start timestamp (ms). The window to endTime is at most 30 days
+    pub start_time: Option<u64>,
+    /// The end timestamp (ms)
+    pub end_time: Option<u64>,
+}
+
+/// Item of the `/v5/market/historical-volatility` result, which is a list without a category tag.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct HistoricalVolatility {
+    /// Period (days)
+    pub period: u64,
+    #[serde(deserialize_with = "number")]
+    pub value: Number,
+    /// Timestamp (ms)
+    #[serde(deserialize_with = "number")]
+    pub time: u64,
+}
+
+#[derive(Serialize)]
+pub struct GetInsuranceParams {
+    /// Coin. Default: all
+    pub coin: Option<String>,
+}
+
+/// Result of `/v5/market/insurance`.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Insurance {
+    /// Data updated time (ms)
+    #[serde(deserialize_with = "number")]
+    pub updated_time: u64,
+    pub list: Vec<InsuranceFund>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct InsuranceFund {
+    pub coin: String,
+    /// Symbols sharing the fund, comma separated. Empty for the shared fund of a coin
+    pub symbols: String,
+    #[serde(deserialize_with = "number")]
+    pub balance: Number,
+    /// USD value
+    #[serde(deserialize_with = "number")]
+    pub value: Number,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRiskLimitParams {
+    /// linear, inverse
+    pub category: Category,
+    pub symbol: Option<String>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(tag = "category")]
+pub enum RiskLimit {
+    #[serde(rename = "inverse", rename_all = "camelCase")]
+    Inverse {
+        list: Vec<RiskLimitTier>,
+        #[serde(default)]
+        next_page_cursor: String,
+    },
+    #[serde(rename = "linear", rename_all = "camelCase")]
+    Linear {
+        list: Vec<RiskLimitTier>,
+        #[serde(default)]
+        next_page_cursor: String,
+    },
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RiskLimitTier {
+    /// Risk ID
+    pub id: u64,
+    pub symbol: String,
+    /// Position limit
+    #[serde(deserialize_with = "number")]
+    pub risk_limit_value: Number,
+    /// Maintain margin rate
+    #[serde(deserialize_with = "number")]
+    pub maintenance_margin: Number,
+    /// Initial margin rate
+    #[serde(deserialize_with = "number")]
+    pub initial_margin: Number,
+    /// 1: the lowest risk tier, 0: otherwise
+    pub is_lowest_risk: u8,
+    /// Allowed max leverage
+    #[serde(deserialize_with = "number")]
+    pub max_leverage: Number,
+    /// Maintenance margin deduction value when the risk limit tier changed
+    #[serde(deserialize_with = "option_number")]
+    pub mm_deduction: Option<Number>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDeliveryPriceParams {
+    /// linear, inverse, option
+    pub category: Category,
+    pub symbol: Option<String>,
+    /// Apply to option only. Default: BTC
+    pub base_coin: Option<String>,
+    /// Settle coin, USDT or USDC. Default: USDC
+    pub settle_coin: Option<String>,
+    /// [1, 200], default: 50
+    pub limit: Option<u64>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(tag = "category")]
+pub enum DeliveryPrice {
+    #[serde(rename = "inverse", rename_all = "camelCase")]
+    Inverse {
+        list: Vec<DeliveryPriceRow>,
+        next_page_cursor: String,
+    },
+    #[serde(rename = "linear", rename_all = "camelCase")]
+    Linear {
+        list: Vec<DeliveryPriceRow>,
+        next_page_cursor: String,
+    },
+    #[serde(rename = "option", rename_all = "camelCase")]
+    Option {
+        list: Vec<DeliveryPriceRow>,
+        next_page_cursor: String,
+    },
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryPriceRow {
+    pub symbol: String,
+    #[serde(deserialize_with = "number")]
+    pub delivery_price: Number,
+    /// Delivery time (ms)
+    #[serde(deserialize_with = "number")]
+    pub delivery_time: u64,
 }
 
 #[derive(Serialize)]
@@ -485,22 +833,22 @@ pub struct GetOpenClosedOrdersParams {
     /// Product type
     /// UTA2.0, UTA1.0: linear, inverse, spot, option
     /// classic account: linear, inverse, spot
-    category: String,
+    pub category: String,
     /// Symbol name, like BTCUSDT, uppercase only. For linear, either symbol, baseCoin, settleCoin is required
-    symbol: Option<String>,
+    pub symbol: Option<String>,
     /// Base coin, uppercase only
     /// Supports linear, inverse & option
     /// option: it returns all option open orders by default
-    base_coin: Option<String>,
+    pub base_coin: Option<String>,
     /// Settle coin, uppercase only
     /// linear: either symbol, baseCoin or settleCoin is required
     /// spot: not supported
     /// option: USDT or USDC
-    settle_coin: Option<String>,
+    pub settle_coin: Option<String>,
     /// Order ID
-    order_id: Option<String>,
+    pub order_id: Option<String>,
     /// User customised order ID
-    order_link_id: Option<String>,
+    pub order_link_id: Option<String>,
     /// 0(default): UTA2.0, UTA1.0, classic account query open status orders (e.g., New, PartiallyFilled) only
     /// 1: UTA2.0, UTA1.0(except inverse)
     /// 2: UTA1.0(inverse), classic account
@@ -508,15 +856,30 @@ pub struct GetOpenClosedOrdersParams {
     /// If the Bybit service is restarted due to an update, this part of the data will be cleared and accumulated again, but the order records will still be queried in order history
     /// openOnly param will be ignored when query by orderId or orderLinkId
     /// Classic spot: not supported
-    open_only: Option<i64>,
+    pub open_only: Option<i64>,
     /// Order: active order, StopOrder: conditional order for Futures and Spot, tpslOrder: spot TP/SL order, OcoOrder: Spot oco order, BidirectionalTpslOrder: Spot bidirectional TPSL order
     /// classic account spot: return Order active order by default
     /// Others: all kinds of orders by default
-    order_filter: Option<String>,
+    pub order_filter: Option<String>,
+    /// The start timestamp (ms). Order history only
+    /// startTime and endTime must be passed together or both are not passed, the window is at most 7 days
+    pub start_time: Option<u64>,
+    /// The end timestamp (ms). Order history only
+    pub end_time: Option<u64>,
     /// Limit for data size per page. [1, 50]. Default: 20
-    limit: Option<i64>,
+    pub limit: Option<i64>,
     /// Cursor. Use the nextPageCursor token from the response to retrieve the next page of the result set
-    cursor: Option<String>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenClosedOrders {
+    /// Product type
+    pub category: Category,
+    /// Refer to the cursor request parameter
+    pub next_page_cursor: String,
+    pub list: Vec<Order>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -532,10 +895,10 @@ pub struct Order {
     pub symbol: String,
     /// Order price
     #[serde(deserialize_with = "number")]
-    pub price: f64,
+    pub price: Number,
     /// Order qty
     #[serde(deserialize_with = "number")]
-    pub qty: f64,
+    pub qty: Number,
     /// Side. Buy,Sell
     pub side: Side,
     /// Whether to borrow. Unified spot only. 0: false, 1: true. Classic spot is not supported, always 0
@@ -558,19 +921,19 @@ pub struct Order {
     pub avg_price: String,
     /// The remaining qty not executed. Classic spot is not supported
     #[serde(deserialize_with = "number")]
-    pub leaves_qty: f64,
+    pub leaves_qty: Number,
     /// The estimated value not executed. Classic spot is not supported
     #[serde(deserialize_with = "number")]
-    pub leaves_value: f64,
+    pub leaves_value: Number,
     /// Cumulative executed order qty
     #[serde(deserialize_with = "number")]
-    pub cum_exec_qty: f64,
+    pub cum_exec_qty: Number,
     /// Cumulative executed order value. Classic spot is not supported
     #[serde(deserialize_with = "number")]
-    pub cum_exec_value: f64,
+    pub cum_exec_value: Number,
     /// Cumulative executed trading fee. Classic spot is not supported
     #[serde(deserialize_with = "number")]
-    pub cum_exec_fee: f64,
+    pub cum_exec_fee: Number,
     /// Time in force
     pub time_in_force: TimeInForce,
     /// Order type. Market,Limit. For TP/SL order, it means the order type after triggered
@@ -583,23 +946,23 @@ pub struct Order {
     pub market_unit: String,
     /// Trigger price. If stopOrderType=TrailingStop, it is activate price. Otherwise, it is trigger price
     #[serde(deserialize_with = "number")]
-    pub trigger_price: f64,
+    pub trigger_price: Number,
     /// Take profit price
     #[serde(deserialize_with = "number")]
-    pub take_profit: f64,
+    pub take_profit: Number,
     /// Stop loss price
     #[serde(deserialize_with = "number")]
-    pub stop_loss: f64,
+    pub stop_loss: Number,
     /// TP/SL mode, Full: entire position for TP/SL. Partial: partial position tp/sl. Spot does not have this field, and Option returns always ""
     pub tpsl_mode: Option<TpslMode>,
     /// The trigger type of Spot OCO order.OcoTriggerByUnknown, OcoTriggerByTp, OcoTriggerByBySl. Classic spot is not supported
     pub oco_trigger_by: OcoTriggerBy,
     /// The limit order price when take profit price is triggered
     #[serde(deserialize_with = "number")]
-    pub tp_limit_price: f64,
+    pub tp_limit_price: Number,
     /// The limit order price when stop loss price is triggered
     #[serde(deserialize_with = "number")]
-    pub sl_limit_price: f64,
+    pub sl_limit_price: Number,
     /// The price type to trigger take profit
     pub tp_trigger_by: TriggerBy,
     /// The price type to trigger stop loss
@@ -610,10 +973,10 @@ pub struct Order {
     pub trigger_by: TriggerBy,
     /// Last price when place the order, Spot is not applicable
     #[serde(deserialize_with = "number")]
-    pub last_price_on_created: f64,
+    pub last_price_on_created: Number,
     /// Last price when place the order, Spot has this field only
     #[serde(deserialize_with = "number")]
-    pub base_price: f64,
+    pub base_price: Number,
     /// Reduce only. true means reduce position size
     pub reduce_only: bool,
     /// Close on trigger.
@@ -642,71 +1005,319 @@ impl Order {
     }
 }
 
-pub fn spot_fee_currency(side: Side, is_maker_order: bool, maker_fee_rate: f64) -> Pair {
-    if maker_fee_rate >= 0.0 {
-        match side {
-            Side::Buy => Pair::Base,
-            Side::Sell => Pair::Quote,
-        }
-    } else if is_maker_order {
-        match side {
-            Side::Buy => Pair::Quote,
-            Side::Sell => Pair::Base,
-        }
+/// `None` for an unrecognized side.
+pub fn spot_fee_currency(side: Side, is_maker_order: bool, maker_fee_rate: Number) -> Option<Pair> {
+    // Negative maker fees are paid in the other coin of the pair.
+    let (buy, sell) = if maker_fee_rate < ZERO && is_maker_order {
+        (Pair::Quote, Pair::Base)
     } else {
-        match side {
-            Side::Buy => Pair::Base,
-            Side::Sell => Pair::Quote,
-        }
+        (Pair::Base, Pair::Quote)
+    };
+    match side {
+        Side::Buy => Some(buy),
+        Side::Sell => Some(sell),
+        Side::Unrecognized(_) => None,
     }
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
-pub struct GetPositionInfo {
+pub struct CreateOrderParams {
     /// Product type
-    /// UTA2.0, UTA1.0: linear, inverse, option
-    /// Classic account: linear, inverse
+    /// UTA2.0, UTA1.0: linear, inverse, spot, option
+    /// classic account: linear, inverse, spot
     pub category: Category,
     /// Symbol name, like BTCUSDT, uppercase only
-    /// If symbol passed, it returns data regardless of having position or not.
-    /// If symbol=null and settleCoin specified, it returns position size greater than zero.
-    pub symbol: Option<String>,
-    /// Base coin, uppercase only. option only. Return all option positions if not passed
-    pub base_coin: Option<String>,
-    /// Settle coin
-    /// linear: either symbol or settleCoin is required. symbol has a higher priority
-    pub settle_coin: Option<String>,
-    /// Limit for data size per page. [1, 200]. Default: 20
-    pub limit: Option<i64>,
-    /// Cursor. Use the nextPageCursor token from the response to retrieve the next page of the result set
-    pub cursor: Option<String>,
+    pub symbol: String,
+    /// Whether to borrow. Valid for Unified spot only. 0(default): false then spot trading, 1: true then margin trading
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_leverage: Option<u8>,
+    /// Buy, Sell
+    pub side: Side,
+    /// Market, Limit
+    pub order_type: OrderType,
+    /// Order quantity
+    #[serde(serialize_with = "as_string")]
+    pub qty: Number,
+    /// Select the unit for qty when create Spot market orders for UTA account. baseCoin, quoteCoin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub market_unit: Option<String>,
+    /// Order price. Market order will ignore this field
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_as_string"
+    )]
+    pub price: Option<Number>,
+    /// Conditional order param. Used to identify the expected direction of the conditional order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_direction: Option<TriggerDirection>,
+    /// Spot only. Order, tpslOrder, StopOrder. If not passed, Order by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_filter: Option<String>,
+    /// For Perps & Futures, it is the conditional order trigger price
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_as_string"
+    )]
+    pub trigger_price: Option<Number>,
+    /// Trigger price type, Conditional order param for Perps & Futures
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_by: Option<TriggerBy>,
+    /// Implied volatility. option only. Pass the real value, e.g for 10%, 0.1 should be passed
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_as_string"
+    )]
+    pub order_iv: Option<Number>,
+    /// Time in force. Market order will always use IOC
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    /// Used to identify positions in different position modes. Under hedge-mode, this param is required
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_idx: Option<PositionIdx>,
+    /// User customised order ID. A max of 36 characters. Combinations of numbers, letters (upper and lower cases), dashes, and underscores are supported
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_link_id: Option<String>,
+    /// Take profit price
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_as_string"
+    )]
+    pub take_profit: Option<Number>,
+    /// Stop loss price
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_as_string"
+    )]
+    pub stop_loss: Option<Number>,
+    /// The price type to trigger take profit. MarkPrice, IndexPrice, default: LastPrice
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tp_trigger_by: Option<TriggerBy>,
+    /// The price type to trigger stop loss. MarkPrice, IndexPrice, default: LastPrice
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sl_trigger_by: Option<TriggerBy>,
+    /// What is a reduce-only order? true means your position can only reduce in size if this order is triggered
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+    /// What is a close on trigger order? For a closing order. It can only reduce your position, not increase it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close_on_trigger: Option<bool>,
+    /// Smp execution type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smp_type: Option<SmpType>,
+    /// Market maker protection. option only. true means set the order as a market maker protection order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mmp: Option<bool>,
+    /// TP/SL mode. Full: entire position for TP/SL, Partial: partial position tp/sl
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tpsl_mode: Option<TpslMode>,
+    /// The limit order price when take profit price is triggered
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_as_string"
+    )]
+    pub tp_limit_price: Option<Number>,
+    /// The limit order price when stop loss price is triggered
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_as_string"
+    )]
+    pub sl_limit_price: Option<Number>,
+    /// The order type when take profit is triggered. Market(default), Limit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tp_order_type: Option<OrderType>,
+    /// The order type when stop loss is triggered. Market(default), Limit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sl_order_type: Option<OrderType>,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
-pub struct PositionInfo {
+pub struct AmendOrderParams {
     /// Product type
     pub category: Category,
-    /// Refer to the cursor request parameter
-    pub next_page_cursor: String,
-    pub list: Vec<Position>,
+    /// Symbol name, like BTCUSDT, uppercase only
+    pub symbol: String,
+    /// Order ID. Either orderId or orderLinkId is required
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>,
+    /// User customised order ID. Either orderId or orderLinkId is required
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_link_id: Option<String>,
+    /// Implied volatility. option only
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_as_string"
+    )]
+    pub order_iv: Option<Number>,
+    /// Trigger price. If you expect to amend conditional order, this param is required
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_as_string"
+    )]
+    pub trigger_price: Option<Number>,
+    /// Order quantity after modification. Do not pass it if not modify the qty
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_as_string"
+    )]
+    pub qty: Option<Number>,
+    /// Order price after modification. Do not pass it if not modify the price
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_as_string"
+    )]
+    pub price: Option<Number>,
+    /// TP/SL mode. Full, Partial
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tpsl_mode: Option<TpslMode>,
+    /// Take profit price after modification. "0" cancels the take profit
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_as_string"
+    )]
+    pub take_profit: Option<Number>,
+    /// Stop loss price after modification. "0" cancels the stop loss
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_as_string"
+    )]
+    pub stop_loss: Option<Number>,
+    /// The price type to trigger take profit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tp_trigger_by: Option<TriggerBy>,
+    /// The price type to trigger stop loss
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sl_trigger_by: Option<TriggerBy>,
+    /// Trigger price type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_by: Option<TriggerBy>,
+    /// Limit order price when take profit is triggered. Only working when tpslMode=Partial and tpOrderType=Limit
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_as_string"
+    )]
+    pub tp_limit_price: Option<Number>,
+    /// Limit order price when stop loss is triggered. Only working when tpslMode=Partial and slOrderType=Limit
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_as_string"
+    )]
+    pub sl_limit_price: Option<Number>,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
-pub struct Position {
-    /// Position idx, used to identify positions in different position modes
-    /// 0: One-Way Mode
-    /// 1: Buy side of both side mode
-    /// 2: Sell side of both side mode
-    /// riskId:Snteger, /// Risk tier ID
-    /// for portfolio margin mode, this field returns 0, which means risk limit rules are invalid
-    pub position_idx: PositionIdx,
-    /// Risk limit value
-    /// for portfolio margin mode, this field returns 0, which means risk limit rules are invalid
+pub struct CancelOrderParams {
+    /// Product type
+    pub category: Category,
+    /// Symbol name, like BTCUSDT, uppercase only
+    pub symbol: String,
+    /// Order ID. Either orderId or orderLinkId is required
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>,
+    /// User customised order ID. Either orderId or orderLinkId is required
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_link_id: Option<String>,
+    /// Spot trading only. Order, tpslOrder, StopOrder
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_filter: Option<String>,
+}
+
+/// Body of the `*-batch` order endpoints.
+/// The `category` of every request must match the batch one.
+#[derive(Serialize, Debug)]
+pub struct BatchOrderParams<T> {
+    pub category: Category,
+    pub request: Vec<T>,
+}
+
+/// Result of create, amend and cancel order requests.
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderId {
+    /// Order ID
+    pub order_id: String,
+    /// User customised order ID
+    pub order_link_id: String,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct BatchOrderIds {
+    pub list: Vec<BatchOrderId>,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchOrderId {
+    /// Product type
+    pub category: Category,
+    /// Symbol name
+    pub symbol: String,
+    /// Order ID. Empty when the request was rejected
+    pub order_id: String,
+    /// User customised order ID
+    pub order_link_id: String,
+}
+
+/// Per request result of a batch order, in the order of the batch.
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct BatchRetExtInfo {
+    pub list: Vec<BatchRetCode>,
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct BatchRetCode {
+    pub code: i32,
+    pub msg: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPositionInfo {
+    /// Product type
+    /// UTA2.0, UTA1.0: linear, inverse, option
+    /// Classic account: linear, inverse
+    pub category: Category,
+    /// Symbol name, like BTCUSDT, uppercase only
+    /// If symbol passed, it returns data regardless of having position or not.
+    /// If symbol=null and settleCoin specified, it returns position size greater than zero.
+    pub symbol: Option<String>,
+    /// Base coin, uppercase only. option only. Return all option positions if not passed
+    pub base_coin: Option<String>,
+    /// Settle coin
+    /// linear: either symbol or settleCoin is required. symbol has a higher priority
+    pub settle_coin: Option<String>,
+    /// Limit for data size per page. [1, 200]. Default: 20
+    pub limit: Option<i64>,
+    /// Cursor. Use the nextPageCursor token from the response to retrieve the next page of the result set
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionInfo {
+    /// Product type
+    pub category: Category,
+    /// Refer to the cursor request parameter
+    pub next_page_cursor: String,
+    pub list: Vec<Position>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    /// Position idx, used to identify positions in different position modes
+    /// 0: One-Way Mode
+    /// 1: Buy side of both side mode
+    /// 2: Sell side of both side mode
+    /// riskId:Snteger, /// Risk tier ID
+    /// for portfolio margin mode, this field returns 0, which means risk limit rules are invalid
+    pub position_idx: PositionIdx,
+    /// Risk limit value
+    /// for portfolio margin mode, this field returns 0, which means risk limit rules are invalid
     #[serde(deserialize_with = "number")]
-    pub risk_limit_value: f64,
+    pub risk_limit_value: Number,
     /// Symbol name
     pub symbol: String,
     /// Position side. Buy: long, Sell: short
@@ -715,14 +1326,14 @@ pub struct Position {
     pub side: Side,
     /// Position size, always positive
     #[serde(deserialize_with = "number")]
-    pub size: f64,
+    pub size: Number,
     /// Average entry price
     /// For USDC Perp & Futures, it indicates average entry price, and it will not be changed with 8-hour session settlement
     #[serde(deserialize_with = "number")]
-    pub avg_price: f64,
+    pub avg_price: Number,
     /// Position value
     #[serde(deserialize_with = "number")]
-    pub position_value: f64,
+    pub position_value: Number,
     /// Trade mode
     /// Classic & UTA1.0(inverse): 0: cross-margin, 1: isolated margin
     /// UTA2.0, UTA1.0(execpt inverse): deprecated, always 0, check Get Account Info to know the margin mode
@@ -736,10 +1347,10 @@ pub struct Position {
     /// Position leverage
     /// for portfolio margin mode, this field returns "", which means leverage rules are invalid
     #[serde(deserialize_with = "option_number")]
-    pub leverage: Option<f64>,
+    pub leverage: Option<Number>,
     /// Mark price
     #[serde(deserialize_with = "number")]
-    pub mark_price: f64,
+    pub mark_price: Number,
     /// Position liquidation price
     /// UTA2.0(isolated margin), UTA1.0(isolated margin), UTA1.0(inverse), Classic account:
     /// it is the real price for isolated and cross positions, and keeps "" when liqPrice <= minPrice or liqPrice >= maxPrice
@@ -747,94 +1358,1847 @@ pub struct Position {
     /// it is an estimated price for cross positions(because the unified mode controls the risk rate according to the account), and keeps "" when liqPrice <= minPrice or liqPrice >= maxPrice
     /// this field is empty for Portfolio Margin Mode, and no liquidation price will be provided
     #[serde(deserialize_with = "option_number")]
-    pub liq_price: Option<f64>,
+    pub liq_price: Option<Number>,
     /// Bankruptcy price
     #[serde(deserialize_with = "number")]
-    pub bust_price: f64,
+    pub bust_price: Number,
     /// Initial margin
     /// Classic & UTA1.0(inverse): ignore this field
     /// UTA portfolio margin mode, it returns ""
     #[serde(deserialize_with = "number")]
-    pub position_i_m: f64,
+    pub position_i_m: Number,
     /// Maintenance margin
     /// Classic & UTA1.0(inverse): ignore this field
     /// UTA portfolio margin mode, it returns ""
     #[serde(deserialize_with = "number")]
-    pub position_m_m: f64,
+    pub position_m_m: Number,
     /// Position margin
     /// Classic & UTA1.0(inverse) can refer to this field to get the position initial margin plus position closing fee
     #[serde(deserialize_with = "number")]
-    pub position_balance: f64,
+    pub position_balance: Number,
     /// Take profit price
     #[serde(deserialize_with = "number")]
-    pub take_profit: f64,
+    pub take_profit: Number,
     /// Stop loss price
     #[serde(deserialize_with = "number")]
-    pub stop_loss: f64,
+    pub stop_loss: Number,
     /// Trailing stop (The distance from market price)
     #[serde(deserialize_with = "number")]
-    pub trailing_stop: f64,
+    pub trailing_stop: Number,
     /// USDC contract session avg price, it is the same figure as avg entry price shown in the web UI
     #[serde(deserialize_with = "option_number")]
-    pub session_avg_price: Option<f64>,
-    /// Delta
-    pub delta: Option<String>,
-    /// Gamma
-    pub gamma: Option<String>,
-    /// Vega
-    pub vega: Option<String>,
-    /// Theta
-    pub theta: Option<String>,
-    /// Unrealised PnL
+    pub session_avg_price: Option<Number>,
+    /// Delta
+    pub delta: Option<String>,
+    /// Gamma
+    pub gamma: Option<String>,
+    /// Vega
+    pub vega: Option<String>,
+    /// Theta
+    pub theta: Option<String>,
+    /// Unrealised PnL
+    #[serde(deserialize_with = "number")]
+    pub unrealised_pnl: Number,
+    /// The realised PnL for the current holding position
+    #[serde(deserialize_with = "number")]
+    pub cur_realised_pnl: Number,
+    /// Cumulative realised pnl
+    /// Futures & Perps: it is the all time cumulative realised P&L
+    /// Option: always "", meaningless
+    #[serde(deserialize_with = "number")]
+    pub cum_realised_pnl: Number,
+    /// Auto-deleverage rank indicator. What is Auto-Deleveraging?
+    pub adl_rank_indicator: i64,
+    /// Timestamp of the first time a position was created on this symbol (ms)
+    #[serde(deserialize_with = "number")]
+    pub created_time: i64,
+    /// Position updated timestamp (ms)
+    #[serde(deserialize_with = "number")]
+    pub updated_time: i64,
+    /// Cross sequence, used to associate each fill and each position update
+    /// Different symbols may have the same seq, please use seq + symbol to check unique
+    /// Returns "-1" if the symbol has never been traded
+    /// Returns the seq updated by the last transaction when there are settings like leverage, risk limit
+    pub seq: i64,
+    /// Useful when Bybit lower the risk limit
+    /// true: Only allowed to reduce the position. You can consider a series of measures, e.g., lower the risk limit, decrease leverage or reduce the position, add margin, or cancel orders, after these operations, you can call confirm new risk limit endpoint to check if your position can be removed the reduceOnly mark
+    /// false: There is no restriction, and it means your position is under the risk when the risk limit is systematically adjusted
+    /// Only meaningful for isolated margin & cross margin of USDT Perp, USDC Perp, USDC Futures, Inverse Perp and Inverse Futures, meaningless for others
+    pub is_reduce_only: bool,
+    /// Useful when Bybit lower the risk limit
+    /// When isReduceOnly=true: the timestamp (ms) when the MMR will be forcibly adjusted by the system
+    /// When isReduceOnly=false: the timestamp when the MMR had been adjusted by system
+    /// It returns the timestamp when the system operates, and if you manually operate, there is no timestamp
+    /// Keeps "" by default, if there was a lower risk limit system adjustment previously, it shows that system operation timestamp
+    /// Only meaningful for isolated margin & cross margin of USDT Perp, USDC Perp, USDC Futures, Inverse Perp and Inverse Futures, meaningless for others
+    pub mmr_sys_updated_time: Option<String>,
+    /// Useful when Bybit lower the risk limit
+    /// When isReduceOnly=true: the timestamp (ms) when the leverage will be forcibly adjusted by the system
+    /// When isReduceOnly=false: the timestamp when the leverage had been adjusted by system
+    /// It returns the timestamp when the system operates, and if you manually operate, there is no timestamp
+    /// Keeps "" by default, if there was a lower risk limit system adjustment previously, it shows that system operation timestamp
+    /// Only meaningful for isolated margin & cross margin of USDT Perp, USDC Perp, USDC Futures, Inverse Perp and Inverse Futures, meaningless for others
+    pub leverage_sys_updated_time: Option<String>,
+    /// deprecated, always "Full"
+    pub tpsl_mode: String,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SetLeverageParams {
+    /// Product type. linear, inverse
+    pub category: Category,
+    /// Symbol name, like BTCUSDT, uppercase only
+    pub symbol: String,
+    /// [1, max leverage]. One-way mode: buyLeverage must be the same as sellLeverage
+    #[serde(serialize_with = "as_string")]
+    pub buy_leverage: Number,
+    #[serde(serialize_with = "as_string")]
+    pub sell_leverage: Number,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SetRiskLimitParams {
+    /// Product type. linear, inverse
+    pub category: Category,
+    /// Symbol name, like BTCUSDT, uppercase only
+    pub symbol: String,
+    /// Risk limit ID, see [`RiskLimitTier::id`]
+    pub risk_id: u64,
+    /// Required under hedge-mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_idx: Option<PositionIdx>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetRiskLimit {
+    pub category: Category,
+    pub risk_id: u64,
+    /// The position limit value corresponding to this risk ID
+    #[serde(deserialize_with = "number")]
+    pub risk_limit_value: Number,
+}
+
+/// Take profit, stop loss or trailing stop of a position. Start from
+/// [`TradingStopParams::new`] and set the stops to change; passing 0 cancels a stop.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TradingStopParams {
+    /// Product type. linear, inverse
+    pub category: Category,
+    /// Symbol name, like BTCUSDT, uppercase only
+    pub symbol: String,
+    /// Full: entire position TP/SL, Partial: partial position TP/SL
+    pub tpsl_mode: TpslMode,
+    /// Used to identify positions in different position modes
+    pub position_idx: PositionIdx,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_as_string"
+    )]
+    pub take_profit: Option<Number>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_as_string"
+    )]
+    pub stop_loss: Option<Number>,
+    /// Trailing stop by price distance
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_as_string"
+    )]
+    pub trailing_stop: Option<Number>,
+    /// Trailing stop trigger price. The trailing stop is triggered when this price is reached
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_as_string"
+    )]
+    pub active_price: Option<Number>,
+    /// The price type to trigger take profit. Default: LastPrice
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tp_trigger_by: Option<TriggerBy>,
+    /// The price type to trigger stop loss. Default: LastPrice
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sl_trigger_by: Option<TriggerBy>,
+    /// Take profit size. Partial mode only, must equal `sl_size`
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_as_string"
+    )]
+    pub tp_size: Option<Number>,
+    /// Stop loss size. Partial mode only, must equal `tp_size`
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_as_string"
+    )]
+    pub sl_size: Option<Number>,
+    /// Limit order price when take profit is triggered. Partial mode with a Limit `tp_order_type` only
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_as_string"
+    )]
+    pub tp_limit_price: Option<Number>,
+    /// Limit order price when stop loss is triggered. Partial mode with a Limit `sl_order_type` only
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "option_as_string"
+    )]
+    pub sl_limit_price: Option<Number>,
+    /// Market (default), Limit. Full mode supports Market only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tp_order_type: Option<OrderType>,
+    /// Market (default), Limit. Full mode supports Market only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sl_order_type: Option<OrderType>,
+}
+
+impl TradingStopParams {
+    /// Full mode, every stop left unchanged.
+    pub fn new(category: Category, symbol: &str, position_idx: PositionIdx) -> Self {
+        Self {
+            category,
+            symbol: symbol.to_owned(),
+            tpsl_mode: TpslMode::Full,
+            position_idx,
+            take_profit: None,
+            stop_loss: None,
+            trailing_stop: None,
+            active_price: None,
+            tp_trigger_by: None,
+            sl_trigger_by: None,
+            tp_size: None,
+            sl_size: None,
+            tp_limit_price: None,
+            sl_limit_price: None,
+            tp_order_type: None,
+            sl_order_type: None,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SwitchIsolatedParams {
+    /// Product type. linear, inverse
+    pub category: Category,
+    /// Symbol name, like BTCUSDT, uppercase only
+    pub symbol: String,
+    /// 0: cross margin, 1: isolated margin
+    pub trade_mode: TradeMode,
+    /// The value must be the same as `sell_leverage`
+    #[serde(serialize_with = "as_string")]
+    pub buy_leverage: Number,
+    #[serde(serialize_with = "as_string")]
+    pub sell_leverage: Number,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SwitchModeParams {
+    /// Product type. linear: USDT contract, inverse: inverse futures
+    pub category: Category,
+    /// Symbol name, like BTCUSDT, uppercase only. Either symbol or coin is required
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    /// Coin, uppercase only. Switches every symbol settled in this coin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coin: Option<String>,
+    pub mode: PositionMode,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SetAutoAddMarginParams {
+    /// Product type. linear, inverse
+    pub category: Category,
+    /// Symbol name, like BTCUSDT, uppercase only
+    pub symbol: String,
+    pub auto_add_margin: AutoAddMargin,
+    /// Required under hedge-mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_idx: Option<PositionIdx>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetExecutionsParams {
+    /// Product type. linear, inverse, spot, option
+    pub category: Category,
+    /// Symbol name, like BTCUSDT, uppercase only
+    pub symbol: Option<String>,
+    /// Order ID
+    pub order_id: Option<String>,
+    /// User customised order ID
+    pub order_link_id: Option<String>,
+    /// Base coin, uppercase only
+    pub base_coin: Option<String>,
+    /// The start timestamp (ms)
+    /// startTime and endTime are not passed, return 7 days by default
+    /// The window between startTime and endTime is at most 7 days
+    pub start_time: Option<u64>,
+    /// The end timestamp (ms)
+    pub end_time: Option<u64>,
+    /// Execution type
+    pub exec_type: Option<ExecType>,
+    /// Limit for data size per page. [1, 100]. Default: 50
+    pub limit: Option<i64>,
+    /// Cursor. Use the nextPageCursor token from the response to retrieve the next page of the result set
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Executions {
+    /// Product type
+    pub category: Category,
+    /// Refer to the cursor request parameter
+    pub next_page_cursor: String,
+    pub list: Vec<Execution>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Execution {
+    /// Symbol name
+    pub symbol: String,
+    /// Order ID
+    pub order_id: String,
+    /// User customized order ID
+    pub order_link_id: String,
+    /// Side. Buy,Sell
+    pub side: Side,
+    /// Order price
+    #[serde(deserialize_with = "number")]
+    pub order_price: Number,
+    /// Order qty
+    #[serde(deserialize_with = "number")]
+    pub order_qty: Number,
+    /// The remaining qty not executed
+    #[serde(deserialize_with = "number")]
+    pub leaves_qty: Number,
+    /// Order create type
+    /// Spot, Option do not have this key
+    pub create_type: Option<CreateType>,
+    /// Order type. Market,Limit
+    pub order_type: OrderType,
+    /// Stop order type. If the order is not stop order, it either returns UNKNOWN or ""
+    pub stop_order_type: StopOrderType,
+    /// Executed trading fee
+    #[serde(deserialize_with = "number")]
+    pub exec_fee: Number,
+    /// Execution ID
+    pub exec_id: String,
+    /// Execution price
+    #[serde(deserialize_with = "number")]
+    pub exec_price: Number,
+    /// Execution qty
+    #[serde(deserialize_with = "number")]
+    pub exec_qty: Number,
+    /// Executed type
+    pub exec_type: ExecType,
+    /// Executed order value
+    #[serde(deserialize_with = "number")]
+    pub exec_value: Number,
+    /// Executed timestamp (ms)
+    #[serde(deserialize_with = "number")]
+    pub exec_time: u64,
+    /// Spot trading fee currency
+    pub fee_currency: Option<String>,
+    /// Is maker order. true: maker, false: taker
+    pub is_maker: bool,
+    /// Trading fee rate
+    #[serde(deserialize_with = "option_number")]
+    pub fee_rate: Option<Number>,
+    /// Implied volatility. Valid for option
+    #[serde(deserialize_with = "option_number")]
+    pub trade_iv: Option<Number>,
+    /// Implied volatility of mark price. Valid for option
+    #[serde(deserialize_with = "option_number")]
+    pub mark_iv: Option<Number>,
+    /// The mark price of the symbol when executing
+    #[serde(deserialize_with = "option_number")]
+    pub mark_price: Option<Number>,
+    /// The index price of the symbol when executing. Valid for option only
+    #[serde(deserialize_with = "option_number")]
+    pub index_price: Option<Number>,
+    /// The underlying price of the symbol when executing. Valid for option
+    #[serde(deserialize_with = "option_number")]
+    pub underlying_price: Option<Number>,
+    /// Paradigm block trade ID
+    pub block_trade_id: Option<String>,
+    /// Closed position size
+    #[serde(deserialize_with = "option_number")]
+    pub closed_size: Option<Number>,
+    /// Cross sequence, used to associate each fill and each position update
+    pub seq: i64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetClosedPnlParams {
+    /// Product type
+    /// UTA2.0, UTA1.0: linear, inverse
+    /// Classic account: linear, inverse
+    pub category: Category,
+    /// Symbol name, like BTCUSDT, uppercase only
+    pub symbol: Option<String>,
+    /// The start timestamp (ms)
+    /// startTime and endTime are not passed, return 7 days by default
+    /// The window between startTime and endTime is at most 7 days
+    pub start_time: Option<u64>,
+    /// The end timestamp (ms)
+    pub end_time: Option<u64>,
+    /// Limit for data size per page. [1, 100]. Default: 50
+    pub limit: Option<i64>,
+    /// Cursor. Use the nextPageCursor token from the response to retrieve the next page of the result set
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ClosedPnl {
+    /// Product type
+    pub category: Category,
+    /// Refer to the cursor request parameter
+    pub next_page_cursor: String,
+    pub list: Vec<ClosedPnlRecord>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ClosedPnlRecord {
+    /// Symbol name
+    pub symbol: String,
+    /// Order ID
+    pub order_id: String,
+    /// Buy, Sell
+    pub side: Side,
+    /// Order qty
+    #[serde(deserialize_with = "number")]
+    pub qty: Number,
+    /// Order price
+    #[serde(deserialize_with = "number")]
+    pub order_price: Number,
+    /// Order type. Market,Limit
+    pub order_type: OrderType,
+    /// Exec type
+    pub exec_type: ExecType,
+    /// Closed size
+    #[serde(deserialize_with = "number")]
+    pub closed_size: Number,
+    /// Cumulated Position value
+    #[serde(deserialize_with = "number")]
+    pub cum_entry_value: Number,
+    /// Average entry price
+    #[serde(deserialize_with = "number")]
+    pub avg_entry_price: Number,
+    /// Cumulated exit position value
+    #[serde(deserialize_with = "number")]
+    pub cum_exit_value: Number,
+    /// Average exit price
+    #[serde(deserialize_with = "number")]
+    pub avg_exit_price: Number,
+    /// Closed PnL
+    #[serde(deserialize_with = "number")]
+    pub closed_pnl: Number,
+    /// The number of fills in a single order
+    #[serde(deserialize_with = "number")]
+    pub fill_count: u64,
+    /// leverage
+    #[serde(deserialize_with = "number")]
+    pub leverage: Number,
+    /// The created time (ms)
+    #[serde(deserialize_with = "number")]
+    pub created_time: u64,
+    /// The updated time (ms)
+    #[serde(deserialize_with = "number")]
+    pub updated_time: u64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTransactionLogParams {
+    /// Account Type. UNIFIED
+    pub account_type: Option<AccountType>,
+    /// Product type. spot, linear, option, inverse
+    pub category: Option<Category>,
+    /// Currency, uppercase only
+    pub currency: Option<String>,
+    /// BaseCoin, uppercase only. e.g., BTC of BTCPERP
+    pub base_coin: Option<String>,
+    /// Types of transaction logs
+    #[serde(rename = "type")]
+    pub type_: Option<Type>,
+    /// The start timestamp (ms)
+    /// startTime and endTime are not passed, return 24 hours by default
+    /// The window between startTime and endTime is at most 7 days
+    pub start_time: Option<u64>,
+    /// The end timestamp (ms)
+    pub end_time: Option<u64>,
+    /// Limit for data size per page. [1, 50]. Default: 20
+    pub limit: Option<i64>,
+    /// Cursor. Use the nextPageCursor token from the response to retrieve the next page of the result set
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionLog {
+    /// Refer to the cursor request parameter
+    pub next_page_cursor: String,
+    pub list: Vec<Transaction>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Transaction {
+    /// Unique id
+    pub id: String,
+    /// Symbol name
+    pub symbol: String,
+    /// Product type
+    pub category: Category,
+    /// Side. Buy,Sell,None
+    pub side: String,
+    /// Transaction timestamp (ms)
+    #[serde(deserialize_with = "number")]
+    pub transaction_time: u64,
+    /// Type
+    #[serde(rename = "type")]
+    pub type_: Type,
+    /// Quantity
+    /// Spot: the negative means the qty of this currency is decreased, the positive means the qty of this currency is increased
+    #[serde(deserialize_with = "option_number")]
+    pub qty: Option<Number>,
+    /// Size. The rest position size after the trade is executed, and it has direction, i.e., short with "-"
+    #[serde(deserialize_with = "option_number")]
+    pub size: Option<Number>,
+    /// e.g., USDC, USDT, BTC, ETH
+    pub currency: String,
+    /// Trade price
+    #[serde(deserialize_with = "option_number")]
+    pub trade_price: Option<Number>,
+    /// Funding fee
+    /// Positive fee value means an expense and negative fee value means a rebate
+    #[serde(deserialize_with = "option_number")]
+    pub funding: Option<Number>,
+    /// Trading fee
+    /// Positive fee value means an expense and negative fee value means a rebate
+    #[serde(deserialize_with = "option_number")]
+    pub fee: Option<Number>,
+    /// Cash flow, e.g., (1) close the position, and unRPL converts to RPL, (2) 8-hour session settlement for USDC Perp and Futures, (3) transferIn and transferOut. This does not include trading fee, funding fee
+    #[serde(deserialize_with = "option_number")]
+    pub cash_flow: Option<Number>,
+    /// Change = cashFlow - funding - fee
+    #[serde(deserialize_with = "number")]
+    pub change: Number,
+    /// Cash balance. This is the wallet balance after a cash change
+    #[serde(deserialize_with = "number")]
+    pub cash_balance: Number,
+    /// Trading fee rate
+    #[serde(deserialize_with = "option_number")]
+    pub fee_rate: Option<Number>,
+    /// The change of bonus
+    #[serde(deserialize_with = "option_number")]
+    pub bonus_change: Option<Number>,
+    /// Trade ID
+    pub trade_id: String,
+    /// Order ID
+    pub order_id: String,
+    /// User customised order ID
+    pub order_link_id: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetWalletBalanceParams {
+    /// Account type. UTA2.0: UNIFIED, UTA1.0: UNIFIED, CONTRACT (inverse), classic: CONTRACT, SPOT
+    pub account_type: AccountType,
+    /// Coin names, comma separated, uppercase only. Default: coins with a balance
+    pub coin: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct WalletBalance {
+    pub list: Vec<Wallet>,
+}
+
+/// Balance of one account. Account-wide rates and totals are `None` where Bybit returns ""
+/// for the account type or margin mode, e.g. isolated margin.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Wallet {
+    pub account_type: AccountType,
+    /// Initial margin rate
+    #[serde(rename = "accountIMRate", deserialize_with = "option_number")]
+    pub account_im_rate: Option<Number>,
+    /// Maintenance margin rate
+    #[serde(rename = "accountMMRate", deserialize_with = "option_number")]
+    pub account_mm_rate: Option<Number>,
+    /// Account LTV: account total borrowed size / (account total equity + account total borrowed size)
+    #[serde(rename = "accountLTV", deserialize_with = "option_number")]
+    pub account_ltv: Option<Number>,
+    /// Account equity in USD
+    #[serde(deserialize_with = "option_number")]
+    pub total_equity: Option<Number>,
+    /// Wallet balance in USD
+    #[serde(deserialize_with = "option_number")]
+    pub total_wallet_balance: Option<Number>,
+    /// Margin balance in USD, i.e. totalWalletBalance + totalPerpUPL
+    #[serde(deserialize_with = "option_number")]
+    pub total_margin_balance: Option<Number>,
+    /// Available balance in USD, i.e. totalMarginBalance - totalInitialMargin
+    #[serde(deserialize_with = "option_number")]
+    pub total_available_balance: Option<Number>,
+    /// Unrealised P&L of perpetuals and futures in USD
+    #[serde(rename = "totalPerpUPL", deserialize_with = "option_number")]
+    pub total_perp_upl: Option<Number>,
+    /// Initial margin in USD
+    #[serde(deserialize_with = "option_number")]
+    pub total_initial_margin: Option<Number>,
+    /// Maintenance margin in USD
+    #[serde(deserialize_with = "option_number")]
+    pub total_maintenance_margin: Option<Number>,
+    pub coin: Vec<CoinBalance>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinBalance {
+    /// Coin name, such as BTC, ETH, USDT, USDC
+    pub coin: String,
+    /// Equity of the coin
+    #[serde(deserialize_with = "number")]
+    pub equity: Number,
+    /// USD value of the coin
+    #[serde(deserialize_with = "number")]
+    pub usd_value: Number,
+    /// Wallet balance of the coin
+    #[serde(deserialize_with = "number")]
+    pub wallet_balance: Number,
+    /// Locked balance due to the spot open order
+    #[serde(deserialize_with = "option_number")]
+    pub locked: Option<Number>,
+    /// Spot hedging qty of the coin
+    #[serde(default, deserialize_with = "option_number")]
+    pub spot_hedging_qty: Option<Number>,
+    /// Borrow amount of the coin
+    #[serde(deserialize_with = "option_number")]
+    pub borrow_amount: Option<Number>,
+    /// Accrued interest
+    #[serde(deserialize_with = "option_number")]
+    pub accrued_interest: Option<Number>,
+    /// Deprecated for UTA2.0, always ""
+    #[serde(deserialize_with = "option_number")]
+    pub available_to_withdraw: Option<Number>,
+    /// Deprecated, always ""
+    #[serde(default, deserialize_with = "option_number")]
+    pub available_to_borrow: Option<Number>,
+    /// Pre-occupied margin of orders
+    #[serde(rename = "totalOrderIM", deserialize_with = "option_number")]
+    pub total_order_im: Option<Number>,
+    /// Initial margin of positions
+    #[serde(rename = "totalPositionIM", deserialize_with = "option_number")]
+    pub total_position_im: Option<Number>,
+    /// Maintenance margin of positions
+    #[serde(rename = "totalPositionMM", deserialize_with = "option_number")]
+    pub total_position_mm: Option<Number>,
+    /// Unrealised P&L
+    #[serde(deserialize_with = "option_number")]
+    pub unrealised_pnl: Option<Number>,
+    /// Cumulative realised P&L
+    #[serde(deserialize_with = "option_number")]
+    pub cum_realised_pnl: Option<Number>,
+    /// Bonus. Unique field for UNIFIED account
+    #[serde(deserialize_with = "option_number")]
+    pub bonus: Option<Number>,
+    /// Whether the coin can be used as collateral by the platform
+    pub margin_collateral: bool,
+    /// Whether the collateral is turned on by the user
+    pub collateral_switch: bool,
+}
+
+/// Result of `/v5/account/info`.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInfo {
+    pub unified_margin_status: UnifiedMarginStatus,
+    pub margin_mode: MarginMode,
+    /// Whether this account is a leader (copytrading)
+    pub is_master_trader: bool,
+    /// Whether the unified account enables spot hedging. ON, OFF
+    pub spot_hedging_status: String,
+    /// Account data updated timestamp (ms)
+    #[serde(deserialize_with = "number")]
+    pub updated_time: u64,
+    /// Deprecated, always OFF. Disconnected-CancelAll-Prevention status: ON, OFF
+    pub dcp_status: String,
+    /// Deprecated, always 0. DCP trigger time window (s)
+    pub time_window: i64,
+    /// Smp group ID. 0 when the uid does not belong to any group
+    pub smp_group: i64,
+}
+
+#[derive(Serialize)]
+pub struct GetCollateralInfoParams {
+    /// Asset currency of all current collateral, uppercase only
+    pub currency: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct CollateralInfo {
+    pub list: Vec<CollateralCoin>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CollateralCoin {
+    pub currency: String,
+    /// Hourly borrow rate
+    #[serde(deserialize_with = "option_number")]
+    pub hourly_borrow_rate: Option<Number>,
+    /// Max borrow amount. This value is shared across main-sub UIDs
+    #[serde(deserialize_with = "option_number")]
+    pub max_borrowing_amount: Option<Number>,
+    /// Deprecated. The maximum limit for interest-free borrowing
+    #[serde(deserialize_with = "option_number")]
+    pub free_borrowing_limit: Option<Number>,
+    /// The amount of borrowing within the free borrowing limit
+    #[serde(deserialize_with = "option_number")]
+    pub free_borrow_amount: Option<Number>,
+    /// Borrow amount
+    #[serde(deserialize_with = "option_number")]
+    pub borrow_amount: Option<Number>,
+    /// The sum of borrowing amount for other accounts under the same main account
+    #[serde(deserialize_with = "option_number")]
+    pub other_borrow_amount: Option<Number>,
+    /// Available amount to borrow. This value is shared across main-sub UIDs
+    #[serde(deserialize_with = "option_number")]
+    pub available_to_borrow: Option<Number>,
+    /// Whether the currency can be borrowed
+    pub borrowable: bool,
+    /// Borrow usage rate: sum of main & sub accounts borrowAmount / maxBorrowingAmount
+    #[serde(deserialize_with = "option_number")]
+    pub borrow_usage_rate: Option<Number>,
+    /// Whether it can be used as a margin collateral currency (platform)
+    pub margin_collateral: bool,
+    /// Whether the collateral is turned on by the user
+    pub collateral_switch: bool,
+    /// Deprecated, use the tiered collateral ratio
+    #[serde(deserialize_with = "option_number")]
+    pub collateral_ratio: Option<Number>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBorrowHistoryParams {
+    /// USDC, USDT, BTC, ETH etc, uppercase only
+    pub currency: Option<String>,
+    /// The start timestamp (ms)
+    /// startTime and endTime are not passed, return 30 days by default
+    /// The window between startTime and endTime is at most 30 days
+    pub start_time: Option<u64>,
+    /// The end timestamp (ms)
+    pub end_time: Option<u64>,
+    /// Limit for data size per page. [1, 50]. Default: 20
+    pub limit: Option<i64>,
+    /// Cursor. Use the nextPageCursor token from the response to retrieve the next page of the result set
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowHistory {
+    /// Refer to the cursor request parameter
+    pub next_page_cursor: String,
+    pub list: Vec<BorrowRecord>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowRecord {
+    /// USDC, USDT, BTC, ETH
+    pub currency: String,
+    /// Created timestamp (ms)
+    pub created_time: u64,
+    /// Interest
+    #[serde(deserialize_with = "number")]
+    pub borrow_cost: Number,
+    /// Hourly borrow rate
+    #[serde(deserialize_with = "number")]
+    pub hourly_borrow_rate: Number,
+    /// Interest bearing borrow size
+    #[serde(rename = "InterestBearingBorrowSize", deserialize_with = "number")]
+    pub interest_bearing_borrow_size: Number,
+    /// Cost exemption
+    #[serde(deserialize_with = "number")]
+    pub cost_exemption: Number,
+    /// Total borrow amount
+    #[serde(deserialize_with = "number")]
+    pub borrow_amount: Number,
+    /// Unrealised loss
+    #[serde(deserialize_with = "number")]
+    pub unrealised_loss: Number,
+    /// The borrowed amount for interest free
+    #[serde(deserialize_with = "number")]
+    pub free_borrowed_amount: Number,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetMarginModeParams {
+    pub set_margin_mode: MarginMode,
+}
+
+/// Result of `/v5/account/set-margin-mode`. Empty when the mode was switched.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct SetMarginMode {
+    pub reasons: Vec<SetMarginModeReason>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SetMarginModeReason {
+    /// Fail reason code
+    pub reason_code: String,
+    /// Fail reason msg
+    pub reason_msg: String,
+}
+
+/// Demo trading only.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DemoApplyMoneyParams {
+    /// 0 (default): add demo funds, 1: reduce demo funds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adjust_type: Option<u8>,
+    pub uta_demo_apply_money: Vec<DemoApplyCoin>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DemoApplyCoin {
+    /// BTC, ETH, USDT or USDC
+    pub coin: String,
+    /// Amount, e.g. BTC: at most 15, USDT: at most 100000
+    #[serde(serialize_with = "as_string")]
+    pub amount_str: Number,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDeliveryRecordParams {
+    /// linear (USDC futures), inverse, option
+    pub category: Category,
+    pub symbol: Option<String>,
+    /// Expiry date, e.g. 25MAR22. Default: all
+    pub exp_date: Option<String>,
+    /// The start timestamp (ms). The window to endTime is at most 30 days
+    pub start_time: Option<u64>,
+    /// The end timestamp (ms)
+    pub end_time: Option<u64>,
+    /// [1, 50]. Default: 20
+    pub limit: Option<i64>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryRecords {
+    pub category: Category,
+    pub next_page_cursor: String,
+    pub list: Vec<DeliveryRecord>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DeliveryRecord {
+    /// Delivery time (ms)
+    pub delivery_time: u64,
+    pub symbol: String,
+    pub side: Side,
+    /// Executed size
+    #[serde(deserialize_with = "number")]
+    pub position: Number,
+    /// Delivery price
+    #[serde(deserialize_with = "number")]
+    pub delivery_price: Number,
+    /// Exercise price, option only
+    #[serde(deserialize_with = "option_number")]
+    pub strike: Option<Number>,
+    /// Trading fee
+    #[serde(deserialize_with = "number")]
+    pub fee: Number,
+    /// Realized PnL of the delivery
+    #[serde(deserialize_with = "number")]
+    pub delivery_rpl: Number,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSettlementRecordParams {
+    /// linear (USDC contract)
+    pub category: Category,
+    pub symbol: Option<String>,
+    /// The start timestamp (ms). The window to endTime is at most 7 days
+    pub start_time: Option<u64>,
+    /// The end timestamp (ms)
+    pub end_time: Option<u64>,
+    /// [1, 50]. Default: 20
+    pub limit: Option<i64>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SettlementRecords {
+    pub category: Category,
+    pub next_page_cursor: String,
+    pub list: Vec<SettlementRecord>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SettlementRecord {
+    pub symbol: String,
+    pub side: Side,
+    /// Position size
+    #[serde(deserialize_with = "number")]
+    pub size: Number,
+    /// Settlement price
+    #[serde(deserialize_with = "number")]
+    pub session_avg_price: Number,
+    /// Mark price
+    #[serde(deserialize_with = "number")]
+    pub mark_price: Number,
+    /// Realised PnL
+    #[serde(deserialize_with = "number")]
+    pub realised_pnl: Number,
+    /// Created time (ms)
+    #[serde(deserialize_with = "number")]
+    pub created_time: u64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetCoinGreeksParams {
+    /// Base coin, uppercase only. Default: all
+    pub base_coin: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct CoinGreeks {
+    pub list: Vec<CoinGreek>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinGreek {
+    pub base_coin: String,
+    #[serde(deserialize_with = "number")]
+    pub total_delta: Number,
+    #[serde(deserialize_with = "number")]
+    pub total_gamma: Number,
+    #[serde(deserialize_with = "number")]
+    pub total_vega: Number,
+    #[serde(deserialize_with = "number")]
+    pub total_theta: Number,
+}
+
+/// Transfer between accounts of one UID. Create it once with [`InterTransferParams::new`]
+/// and resend the same value on retries: Bybit executes a `transferId` only once.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InterTransferParams {
+    /// UUID, generated by [`InterTransferParams::new`]
+    pub transfer_id: String,
+    /// Coin, uppercase only
+    pub coin: String,
+    #[serde(serialize_with = "as_string")]
+    pub amount: Number,
+    pub from_account_type: AccountType,
+    pub to_account_type: AccountType,
+}
+
+impl InterTransferParams {
+    pub fn new(
+        coin: &str,
+        amount: Number,
+        from_account_type: AccountType,
+        to_account_type: AccountType,
+    ) -> Self {
+        Self {
+            transfer_id: Uuid::new_v4().to_string(),
+            coin: coin.to_owned(),
+            amount,
+            from_account_type,
+            to_account_type,
+        }
+    }
+}
+
+/// Transfer between UIDs of a master account. Create it once with
+/// [`UniversalTransferParams::new`] and resend the same value on retries: Bybit executes a
+/// `transferId` only once.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversalTransferParams {
+    /// UUID, generated by [`UniversalTransferParams::new`]
+    pub transfer_id: String,
+    /// Coin, uppercase only
+    pub coin: String,
+    #[serde(serialize_with = "as_string")]
+    pub amount: Number,
+    pub from_member_id: u64,
+    pub to_member_id: u64,
+    pub from_account_type: AccountType,
+    pub to_account_type: AccountType,
+}
+
+impl UniversalTransferParams {
+    pub fn new(
+        coin: &str,
+        amount: Number,
+        (from_member_id, from_account_type): (u64, AccountType),
+        (to_member_id, to_account_type): (u64, AccountType),
+    ) -> Self {
+        Self {
+            transfer_id: Uuid::new_v4().to_string(),
+            coin: coin.to_owned(),
+            amount,
+            from_member_id,
+            to_member_id,
+            from_account_type,
+            to_account_type,
+        }
+    }
+}
+
+/// Result of `/v5/asset/transfer/inter-transfer` and `/v5/asset/transfer/universal-transfer`.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferResult {
+    pub transfer_id: String,
+    /// Empty when the transfer is still being processed
+    pub status: Option<TransferStatus>,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTransferRecordsParams {
+    /// UUID of the transfer
+    pub transfer_id: Option<String>,
+    /// Coin, uppercase only
+    pub coin: Option<String>,
+    pub status: Option<TransferStatus>,
+    /// The start timestamp (ms). The window to endTime is at most 7 days
+    pub start_time: Option<u64>,
+    /// The end timestamp (ms)
+    pub end_time: Option<u64>,
+    /// [1, 50]. Default: 20
+    pub limit: Option<i64>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferRecords {
+    pub next_page_cursor: String,
+    pub list: Vec<TransferRecord>,
+}
+
+/// Item of the inter and universal transfer records. Member ids are only set on universal
+/// transfers.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferRecord {
+    pub transfer_id: String,
+    pub coin: String,
+    #[serde(deserialize_with = "number")]
+    pub amount: Number,
+    #[serde(default)]
+    pub from_member_id: Option<String>,
+    #[serde(default)]
+    pub to_member_id: Option<String>,
+    pub from_account_type: AccountType,
+    pub to_account_type: AccountType,
+    /// Transfer created timestamp (ms)
+    #[serde(deserialize_with = "number")]
+    pub timestamp: u64,
+    pub status: TransferStatus,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnableUniversalTransferParams {
+    /// Sub UIDs allowed to use universal transfers
+    pub sub_member_ids: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SubMembers {
+    /// All sub UIDs under the main UID
+    pub sub_member_ids: Vec<String>,
+    /// Sub UIDs with universal transfer enabled
+    pub transferable_sub_member_ids: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTransferableCoinsParams {
+    pub from_account_type: AccountType,
+    pub to_account_type: AccountType,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct TransferableCoins {
+    pub list: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetCoinBalanceParams {
+    /// UID. Required when querying a sub UID balance with the master api key
+    pub member_id: Option<String>,
+    /// UID, required when querying the transferable balance between different UIDs
+    pub to_member_id: Option<String>,
+    pub account_type: AccountType,
+    /// Coin, uppercase only
+    pub coin: String,
+    /// To account type, required when querying the transferable balance
+    pub to_account_type: Option<AccountType>,
+    /// 0 (default): not query bonus, 1: query bonus
+    pub with_bonus: Option<u8>,
+    /// Whether to query the delay withdraw/transfer safe amount. 0 (default): false, 1: true
+    pub with_transfer_safe_amount: Option<u8>,
+    /// For OTC loan users, whether to query the transferable amount. 0 (default): false, 1: true
+    pub with_ltv_transfer_safe_amount: Option<u8>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SingleCoinBalance {
+    pub account_type: AccountType,
+    /// Biz type
+    pub biz_type: i64,
+    pub account_id: String,
+    pub member_id: String,
+    pub balance: TransferBalance,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferBalance {
+    pub coin: String,
+    #[serde(deserialize_with = "number")]
+    pub wallet_balance: Number,
+    /// Transferable balance
+    #[serde(deserialize_with = "option_number")]
+    pub transfer_balance: Option<Number>,
+    #[serde(deserialize_with = "option_number")]
+    pub bonus: Option<Number>,
+    /// Safe amount to transfer, "" when not queried
+    #[serde(default, deserialize_with = "option_number")]
+    pub transfer_safe_amount: Option<Number>,
+    /// Transferable amount for the OTC loan, "" when not queried
+    #[serde(default, deserialize_with = "option_number")]
+    pub ltv_transfer_safe_amount: Option<Number>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAssetInfoParams {
+    /// SPOT
+    pub account_type: AccountType,
+    pub coin: Option<String>,
+}
+
+/// Result of `/v5/asset/transfer/query-asset-info` (classic spot account).
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct AssetInfo {
+    pub spot: SpotAssetInfo,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct SpotAssetInfo {
+    /// Account status. ACCOUNT_STATUS_NORMAL, ACCOUNT_STATUS_UNSPECIFIED
+    pub status: String,
+    pub assets: Vec<SpotAsset>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct SpotAsset {
+    pub coin: String,
+    /// Freeze amount
+    #[serde(deserialize_with = "number")]
+    pub frozen: Number,
+    /// Free balance
+    #[serde(deserialize_with = "number")]
+    pub free: Number,
+    /// Amount in withdrawing
+    #[serde(deserialize_with = "option_number")]
+    pub withdraw: Option<Number>,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAllowedDepositCoinsParams {
+    /// Coin, uppercase only
+    pub coin: Option<String>,
+    /// Chain, e.g. ETH
+    pub chain: Option<String>,
+    /// [1, 35]. Default: 10
+    pub limit: Option<i64>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AllowedDepositCoins {
+    pub config_list: Vec<AllowedDepositCoin>,
+    pub next_page_cursor: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AllowedDepositCoin {
+    pub coin: String,
+    pub chain: String,
+    pub coin_show_name: String,
+    pub chain_type: String,
+    /// Number of confirmations for deposit
+    pub block_confirm_number: i64,
+    /// Minimum deposit amount
+    #[serde(deserialize_with = "number")]
+    pub min_deposit_amount: Number,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDepositRecordsParams {
+    /// Internal id, to query a specific record
+    pub id: Option<String>,
+    /// Transaction hash
+    #[serde(rename = "txID")]
+    pub tx_id: Option<String>,
+    /// Coin, uppercase only
+    pub coin: Option<String>,
+    /// The start timestamp (ms). The window to endTime is at most 30 days
+    pub start_time: Option<u64>,
+    /// The end timestamp (ms)
+    pub end_time: Option<u64>,
+    /// [1, 50]. Default: 50
+    pub limit: Option<i64>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSubDepositRecordsParams {
+    /// Sub UID
+    pub sub_member_id: String,
+    /// Coin, uppercase only
+    pub coin: Option<String>,
+    /// The start timestamp (ms). The window to endTime is at most 30 days
+    pub start_time: Option<u64>,
+    /// The end timestamp (ms)
+    pub end_time: Option<u64>,
+    /// [1, 50]. Default: 50
+    pub limit: Option<i64>,
+    pub cursor: Option<String>,
+}
+
+/// Result of `/v5/asset/deposit/query-record` and `/v5/asset/deposit/query-sub-member-record`.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositRecords {
+    pub rows: Vec<DepositRecord>,
+    pub next_page_cursor: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositRecord {
+    /// Unique id
+    #[serde(default)]
+    pub id: String,
+    pub coin: String,
+    pub chain: String,
+    #[serde(deserialize_with = "number")]
+    pub amount: Number,
+    /// Transaction ID
+    #[serde(rename = "txID")]
+    pub tx_id: String,
+    pub status: DepositStatus,
+    /// Deposit target address
+    pub to_address: String,
+    /// Tag of the deposit target address
+    pub tag: String,
+    /// Deposit fee
+    #[serde(deserialize_with = "option_number")]
+    pub deposit_fee: Option<Number>,
+    /// Last updated time (ms)
+    #[serde(deserialize_with = "number")]
+    pub success_at: u64,
+    /// Number of confirmation blocks
+    pub confirmations: String,
+    /// Transaction sequence number
+    pub tx_index: String,
+    /// Hash number on the chain
+    pub block_hash: String,
+    /// The deposit limit for this coin in this chain. "-1" means no limit
+    pub batch_release_limit: String,
+    /// 0: normal deposit, 10: the deposit reaches daily deposit limit, 20: abnormal deposit
+    pub deposit_type: String,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetWithdrawRecordsParams {
+    /// Withdraw ID
+    #[serde(rename = "withdrawID")]
+    pub withdraw_id: Option<String>,
+    /// Transaction hash
+    #[serde(rename = "txID")]
+    pub tx_id: Option<String>,
+    /// Coin, uppercase only
+    pub coin: Option<String>,
+    /// 0 (default): on chain, 1: off chain, 2: all
+    pub withdraw_type: Option<u8>,
+    /// The start timestamp (ms). The window to endTime is at most 30 days
+    pub start_time: Option<u64>,
+    /// The end timestamp (ms)
+    pub end_time: Option<u64>,
+    /// [1, 50]. Default: 50
+    pub limit: Option<i64>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawRecords {
+    pub rows: Vec<WithdrawRecord>,
+    pub next_page_cursor: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawRecord {
+    pub withdraw_id: String,
+    /// Transaction ID. It returns "" when the withdrawal failed or was cancelled
+    #[serde(rename = "txID")]
+    pub tx_id: String,
+    /// 0: on chain, 1: off chain
+    pub withdraw_type: i64,
+    pub coin: String,
+    pub chain: String,
+    #[serde(deserialize_with = "number")]
+    pub amount: Number,
+    #[serde(deserialize_with = "option_number")]
+    pub withdraw_fee: Option<Number>,
+    pub status: WithdrawStatus,
+    pub to_address: String,
+    pub tag: String,
+    /// Withdraw created timestamp (ms)
+    #[serde(deserialize_with = "number")]
+    pub create_time: u64,
+    /// Withdraw updated timestamp (ms)
+    #[serde(deserialize_with = "number")]
+    pub update_time: u64,
+}
+
+#[derive(Serialize, Default)]
+pub struct GetCoinInfoParams {
+    /// Coin, uppercase only. Default: all
+    pub coin: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct CoinInfo {
+    pub rows: Vec<CoinInfoRow>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinInfoRow {
+    pub name: String,
+    pub coin: String,
+    /// Maximum withdraw amount per transaction
+    #[serde(deserialize_with = "number")]
+    pub remain_amount: Number,
+    pub chains: Vec<CoinChain>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinChain {
+    pub chain: String,
+    pub chain_type: String,
+    /// Number of confirmations for deposit
+    pub confirmation: String,
+    /// "" when the chain does not support withdrawal
+    #[serde(deserialize_with = "option_number")]
+    pub withdraw_fee: Option<Number>,
+    #[serde(deserialize_with = "option_number")]
+    pub deposit_min: Option<Number>,
+    #[serde(deserialize_with = "option_number")]
+    pub withdraw_min: Option<Number>,
+    /// Precision of withdraw or deposit
+    #[serde(deserialize_with = "number")]
+    pub min_accuracy: u32,
+    /// 0: suspended, 1: normal
+    #[serde(deserialize_with = "number")]
+    pub chain_deposit: u8,
+    /// 0: suspended, 1: normal
+    #[serde(deserialize_with = "number")]
+    pub chain_withdraw: u8,
+    /// Withdraw fee rate, e.g. 0.022 means 2.2%
+    #[serde(default, deserialize_with = "option_number")]
+    pub withdraw_percentage_fee: Option<Number>,
+    /// Contract address, "" for the native coin of the chain
+    #[serde(default)]
+    pub contract_address: String,
+}
+
+/// Withdraws to an address, or to a Bybit UID with `forceChain` 0.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawParams {
+    /// Coin, uppercase only
+    pub coin: String,
+    /// Chain, e.g. ETH. Required when `force_chain` is 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chain: Option<String>,
+    /// Wallet address, or a Bybit UID for internal transfers
+    pub address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    #[serde(serialize_with = "as_string")]
+    pub amount: Number,
+    /// Current timestamp (ms), used for validation
+    pub timestamp: u64,
+    /// 0 (default): internal transfer when the address belongs to Bybit, 1: on chain, 2: UID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force_chain: Option<u8>,
+    /// SPOT (default), FUND
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_type: Option<AccountType>,
+    /// 0 (default): amount is the received amount, 1: the fee is deducted from the amount
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee_type: Option<u8>,
+    /// Customised ID, one withdrawal per ID within 30 minutes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct WithdrawId {
+    pub id: String,
+}
+
+#[derive(Serialize)]
+pub struct CancelWithdrawParams {
+    /// Withdraw ID
+    pub id: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct CancelWithdraw {
+    /// 0: fail, 1: success
+    pub status: u8,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDepositAddressParams {
+    /// Coin, uppercase only
+    pub coin: String,
+    /// Chain, e.g. ETH. Default: all chains of the coin
+    pub chain_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct DepositAddress {
+    pub coin: String,
+    pub chains: Vec<DepositChain>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSubDepositAddressParams {
+    /// Coin, uppercase only
+    pub coin: String,
+    /// Chain, e.g. ETH
+    pub chain_type: String,
+    /// Sub UID
+    pub sub_member_id: String,
+}
+
+/// Result of `/v5/asset/deposit/query-sub-member-address`, which has a single chain.
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct SubDepositAddress {
+    pub coin: String,
+    pub chains: DepositChain,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositChain {
+    pub chain_type: String,
+    /// Deposit address
+    pub address_deposit: String,
+    /// Tag of the deposit address
+    pub tag_deposit: String,
+    pub chain: String,
+    /// The deposit limit for this coin in this chain. "-1" means no limit
+    pub batch_release_limit: String,
+    /// Contract address, "" for the native coin of the chain
+    #[serde(default)]
+    pub contract_address: String,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetConvertRecordsParams {
+    /// The currency to convert from, e.g. BTC
+    pub from_coin: Option<String>,
+    /// The currency to convert to, e.g. USDT
+    pub to_coin: Option<String>,
+    /// [1, 100]. Default: 10
+    pub limit: Option<i64>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertRecords {
+    pub order_body: Vec<ConvertRecord>,
+    pub next_page_cursor: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertRecord {
+    pub from_coin: String,
+    #[serde(deserialize_with = "number")]
+    pub from_amount: Number,
+    pub to_coin: String,
+    #[serde(deserialize_with = "number")]
+    pub to_amount: Number,
+    #[serde(deserialize_with = "number")]
+    pub exchange_rate: Number,
+    /// Exchange created timestamp (s)
+    #[serde(deserialize_with = "number")]
+    pub created_time: u64,
+    /// Exchange transaction ID
+    pub exchange_tx_id: String,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SwitchSpotMarginModeParams {
+    pub spot_margin_mode: SpotMarginMode,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SwitchSpotMarginMode {
+    pub spot_margin_mode: SpotMarginMode,
+}
+
+#[derive(Serialize, Debug)]
+pub struct SetSpotLeverageParams {
+    /// [2, 10]
+    #[serde(serialize_with = "as_string")]
+    pub leverage: Number,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SetPledgeTokenParams {
+    /// Coin, uppercase only
+    pub coin: String,
+    /// Whether the coin is used as collateral for spot margin trading
+    pub pledge: bool,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetLeveragedTokenInfoParams {
+    /// Abbreviation of the LT, e.g. BTC3L. Returns all LTs if not passed
+    pub lt_coin: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct LeveragedTokenInfo {
+    pub list: Vec<LeveragedToken>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LeveragedToken {
+    /// Abbreviation, e.g. BTC3L
+    pub lt_coin: String,
+    /// Full name
+    pub lt_name: String,
+    /// Maximum purchase amount in a single transaction
+    #[serde(deserialize_with = "number")]
+    pub max_purchase: Number,
+    /// Minimum purchase amount in a single transaction
+    #[serde(deserialize_with = "number")]
+    pub min_purchase: Number,
+    /// Maximum purchase amount in a single day
+    #[serde(deserialize_with = "number")]
+    pub max_purchase_daily: Number,
+    /// Maximum redemption amount in a single transaction
+    #[serde(deserialize_with = "number")]
+    pub max_redeem: Number,
+    /// Minimum redemption amount in a single transaction
+    #[serde(deserialize_with = "number")]
+    pub min_redeem: Number,
+    /// Maximum redemption amount in a single day
+    #[serde(deserialize_with = "number")]
+    pub max_redeem_daily: Number,
+    #[serde(deserialize_with = "number")]
+    pub purchase_fee_rate: Number,
+    #[serde(deserialize_with = "number")]
+    pub redeem_fee_rate: Number,
+    pub lt_status: LtStatus,
+    /// Funding fee charged daily to users who hold the LT
+    #[serde(deserialize_with = "option_number")]
+    pub fund_fee: Option<Number>,
+    /// Timestamp (ms) of the funding fee
+    #[serde(deserialize_with = "number")]
+    pub fund_fee_time: u64,
+    /// Management fee rate
+    #[serde(deserialize_with = "number")]
+    pub manage_fee_rate: Number,
+    /// Timestamp (ms) of the management fee
+    #[serde(deserialize_with = "number")]
+    pub manage_fee_time: u64,
+    /// Internal field
+    pub value: String,
+    /// Net value
+    #[serde(deserialize_with = "number")]
+    pub net_value: Number,
+    /// Total purchase upper limit
+    #[serde(deserialize_with = "number")]
+    pub total: Number,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetLeveragedTokenReferenceParams {
+    /// Abbreviation of the LT, e.g. BTC3L
+    pub lt_coin: String,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LeveragedTokenReference {
+    pub lt_coin: String,
+    /// Net asset value
+    #[serde(deserialize_with = "number")]
+    pub nav: Number,
+    /// Update time (ms) of the net asset value
+    #[serde(deserialize_with = "number")]
+    pub nav_time: u64,
+    /// Circulating supply in the secondary market
+    #[serde(deserialize_with = "number")]
+    pub circulation: Number,
+    /// Basket
+    #[serde(deserialize_with = "number")]
+    pub basket: Number,
+    /// Real leverage calculated by the last traded price
+    #[serde(deserialize_with = "number")]
+    pub leverage: Number,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PurchaseLeveragedTokenParams {
+    /// Abbreviation of the LT, e.g. BTC3L
+    pub lt_coin: String,
+    /// Purchase amount in the quote coin
+    #[serde(serialize_with = "as_string")]
+    pub amount: Number,
+    /// Serial number, a customised ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serial_no: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LeveragedTokenPurchase {
+    pub lt_coin: String,
+    pub lt_order_status: LtOrderStatus,
+    /// Executed quantity of the LT
+    #[serde(deserialize_with = "option_number")]
+    pub exec_qty: Option<Number>,
+    /// Executed amount of the quote coin
+    #[serde(deserialize_with = "option_number")]
+    pub exec_amt: Option<Number>,
+    /// Purchase amount
     #[serde(deserialize_with = "number")]
-    pub unrealised_pnl: f64,
-    /// The realised PnL for the current holding position
+    pub amount: Number,
+    /// Order ID
+    pub purchase_id: String,
+    pub serial_no: String,
+    /// Quote coin
+    pub value_coin: String,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RedeemLeveragedTokenParams {
+    /// Abbreviation of the LT, e.g. BTC3L
+    pub lt_coin: String,
+    /// Redeem quantity of the LT
+    #[serde(serialize_with = "as_string")]
+    pub quantity: Number,
+    /// Serial number, a customised ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub serial_no: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LeveragedTokenRedemption {
+    pub lt_coin: String,
+    pub lt_order_status: LtOrderStatus,
+    /// Redeem quantity
     #[serde(deserialize_with = "number")]
-    pub cur_realised_pnl: f64,
-    /// Cumulative realised pnl
-    /// Futures & Perps: it is the all time cumulative realised P&L
-    /// Option: always "", meaningless
+    pub quantity: Number,
+    /// Executed quantity of the LT
+    #[serde(deserialize_with = "option_number")]
+    pub exec_qty: Option<Number>,
+    /// Executed amount of the quote coin
+    #[serde(deserialize_with = "option_number")]
+    pub exec_amt: Option<Number>,
+    /// Order ID
+    pub redeem_id: String,
+    pub serial_no: String,
+    /// Quote coin
+    pub value_coin: String,
+}
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetLeveragedTokenOrdersParams {
+    /// Abbreviation of the LT, e.g. BTC3L
+    pub lt_coin: Option<String>,
+    /// Order ID
+    pub order_id: Option<String>,
+    /// The start timestamp (ms)
+    pub start_time: Option<u64>,
+    /// The end timestamp (ms)
+    pub end_time: Option<u64>,
+    /// [1, 500]. Default: 100
+    pub limit: Option<i64>,
+    pub lt_order_type: Option<LtOrderType>,
+    /// Serial number, a customised ID
+    pub serial_no: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct LeveragedTokenOrders {
+    pub list: Vec<LeveragedTokenOrder>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct LeveragedTokenOrder {
+    pub lt_coin: String,
+    pub order_id: String,
+    pub lt_order_type: LtOrderType,
+    /// Order time (ms)
     #[serde(deserialize_with = "number")]
-    pub cum_realised_pnl: f64,
-    /// Auto-deleverage rank indicator. What is Auto-Deleveraging?
-    pub adl_rank_indicator: i64,
-    /// Timestamp of the first time a position was created on this symbol (ms)
+    pub order_time: u64,
+    /// Last update time (ms) of the order status
     #[serde(deserialize_with = "number")]
-    pub created_time: i64,
-    /// Position updated timestamp (ms)
+    pub update_time: u64,
+    pub lt_order_status: LtOrderStatus,
+    /// Trading fees
+    #[serde(deserialize_with = "option_number")]
+    pub fee: Option<Number>,
+    /// Order quantity of the LT
     #[serde(deserialize_with = "number")]
-    pub updated_time: i64,
-    /// Cross sequence, used to associate each fill and each position update
-    /// Different symbols may have the same seq, please use seq + symbol to check unique
-    /// Returns "-1" if the symbol has never been traded
-    /// Returns the seq updated by the last transaction when there are settings like leverage, risk limit
-    pub seq: i64,
-    /// Useful when Bybit lower the risk limit
-    /// true: Only allowed to reduce the position. You can consider a series of measures, e.g., lower the risk limit, decrease leverage or reduce the position, add margin, or cancel orders, after these operations, you can call confirm new risk limit endpoint to check if your position can be removed the reduceOnly mark
-    /// false: There is no restriction, and it means your position is under the risk when the risk limit is systematically adjusted
-    /// Only meaningful for isolated margin & cross margin of USDT Perp, USDC Perp, USDC Futures, Inverse Perp and Inverse Futures, meaningless for others
-    pub is_reduce_only: bool,
-    /// Useful when Bybit lower the risk limit
-    /// When isReduceOnly=true: the timestamp (ms) when the MMR will be forcibly adjusted by the system
-    /// When isReduceOnly=false: the timestamp when the MMR had been adjusted by system
-    /// It returns the timestamp when the system operates, and if you manually operate, there is no timestamp
-    /// Keeps "" by default, if there was a lower risk limit system adjustment previously, it shows that system operation timestamp
-    /// Only meaningful for isolated margin & cross margin of USDT Perp, USDC Perp, USDC Futures, Inverse Perp and Inverse Futures, meaningless for others
-    pub mmr_sys_updated_time: Option<String>,
-    /// Useful when Bybit lower the risk limit
-    /// When isReduceOnly=true: the timestamp (ms) when the leverage will be forcibly adjusted by the system
-    /// When isReduceOnly=false: the timestamp when the leverage had been adjusted by system
-    /// It returns the timestamp when the system operates, and if you manually operate, there is no timestamp
-    /// Keeps "" by default, if there was a lower risk limit system adjustment previously, it shows that system operation timestamp
-    /// Only meaningful for isolated margin & cross margin of USDT Perp, USDC Perp, USDC Futures, Inverse Perp and Inverse Futures, meaningless for others
-    pub leverage_sys_updated_time: Option<String>,
-    /// deprecated, always "Full"
-    pub tpsl_mode: String,
+    pub amount: Number,
+    /// Filled value
+    #[serde(deserialize_with = "option_number")]
+    pub value: Option<Number>,
+    /// Quote coin
+    pub value_coin: String,
+    pub serial_no: String,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAnnouncementsParams {
+    pub locale: Locale,
+    #[serde(rename = "type")]
+    pub type_: Option<AnnouncementType>,
+    /// Announcement tag, e.g. Spot, Derivatives, Spot Listings
+    pub tag: Option<String>,
+    /// Page number, from 1. Default: 1
+    pub page: Option<u32>,
+    /// Limit for data size per page. Default: 20
+    pub limit: Option<u32>,
+}
+
+impl GetAnnouncementsParams {
+    /// Every announcement in `locale`.
+    pub fn new(locale: Locale) -> Self {
+        Self {
+            locale,
+            type_: None,
+            tag: None,
+            page: None,
+            limit: None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Announcements {
+    /// Total number of announcements matching the filters
+    pub total: u64,
+    pub list: Vec<Announcement>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Announcement {
+    pub title: String,
+    pub description: String,
+    #[serde(rename = "type")]
+    pub type_: AnnouncementTypeInfo,
+    pub tags: Vec<String>,
+    /// Link of the announcement
+    pub url: String,
+    /// Timestamp (ms) of the announcement
+    pub date_timestamp: u64,
+    /// Start timestamp (ms) of the event, 0 if none
+    #[serde(default)]
+    pub start_date_timestamp: u64,
+    /// End timestamp (ms) of the event, 0 if none
+    #[serde(default)]
+    pub end_date_timestamp: u64,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+pub struct AnnouncementTypeInfo {
+    /// Localised name of the type
+    pub title: String,
+    pub key: AnnouncementType,
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::common::deserialize_slice;
+    use crate::{common::deserialize_slice, number::n};
 
     use super::*;
 
@@ -902,32 +3266,32 @@ mod tests {
                 delivery_fee_rate: None,
                 price_scale: 2,
                 leverage_filter: LeverageFilter {
-                    min_leverage: 1.0,
-                    max_leverage: 100.00,
-                    leverage_step: 0.01,
+                    min_leverage: n!(1.0),
+                    max_leverage: n!(100.00),
+                    leverage_step: n!(0.01),
                 },
                 price_filter: PriceFilter {
-                    min_price: 0.10,
-                    max_price: 1999999.80,
-                    tick_size: 0.10,
+                    min_price: n!(0.10),
+                    max_price: n!(1999999.80),
+                    tick_size: n!(0.10),
                 },
                 lot_size_filter: LotSizeFilter {
-                    min_notional_value: 5.0,
-                    max_order_qty: 1190.000,
-                    max_mkt_order_qty: 500.000,
-                    min_order_qty: 0.001,
-                    qty_step: 0.001,
-                    post_only_max_order_qty: 1190.000,
+                    min_notional_value: n!(5.0),
+                    max_order_qty: n!(1190.000),
+                    max_mkt_order_qty: n!(500.000),
+                    min_order_qty: n!(0.001),
+                    qty_step: n!(0.001),
+                    post_only_max_order_qty: n!(1190.000),
                 },
                 unified_margin_trade: true,
                 funding_interval: 480,
                 settle_coin: String::from("USDT"),
                 copy_trading: CopyTrading::Both,
-                upper_funding_rate: 0.00375,
-                lower_funding_rate: -0.00375,
+                upper_funding_rate: n!(0.00375),
+                lower_funding_rate: n!(-0.00375),
                 risk_parameters: RiskParameters {
-                    price_limit_ratio_x: 0.01,
-                    price_limit_ratio_y: 0.02,
+                    price_limit_ratio_x: n!(0.01),
+                    price_limit_ratio_y: n!(0.02),
                 },
                 is_pre_listing: false,
                 pre_listing_info: None,
@@ -986,21 +3350,21 @@ mod tests {
                     base_coin: String::from("BTC"),
                     quote_coin: String::from("USDT"),
                     risk_parameters: RiskParameters {
-                        price_limit_ratio_x: 0.01,
-                        price_limit_ratio_y: 0.02,
+                        price_limit_ratio_x: n!(0.01),
+                        price_limit_ratio_y: n!(0.02),
                     },
                     innovation: Innovation::False,
                     margin_trading: String::from("utaOnly"), // TODO: Rewrite.
                     st_tag: String::from("0"),               // TODO: Rewrite.
                     lot_size_filter: SpotLotSizeFilter {
-                        base_precision: 0.000001,
-                        quote_precision: 0.0000001,
-                        min_order_qty: 0.000011,
-                        max_order_qty: 83.0,
-                        min_order_amt: 5.0,
-                        max_order_amt: 8000000.0,
+                        base_precision: n!(0.000001),
+                        quote_precision: n!(0.0000001),
+                        min_order_qty: n!(0.000011),
+                        max_order_qty: n!(83.0),
+                        min_order_amt: n!(5.0),
+                        max_order_amt: n!(8000000.0),
                     },
-                    price_filter: SpotPriceFilter { tick_size: 0.1 },
+                    price_filter: SpotPriceFilter { tick_size: n!(0.1) },
                 }],
             },
             time: 1746213108077,
@@ -1009,6 +3373,65 @@ mod tests {
         assert_eq!(message, expected);
     }
 
+    #[test]
+    fn deserialize_response_instruments_info_option() {
+        let json = r#"{
+            "category": "option",
+            "nextPageCursor": "",
+            "list": [
+                {
+                    "symbol": "ETH-3JAN23-1250-P",
+                    "status": "Trading",
+                    "baseCoin": "ETH",
+                    "quoteCoin": "USD",
+                    "settleCoin": "USDC",
+                    "optionsType": "Put",
+                    "launchTime": "1672560000000",
+                    "deliveryTime": "1672732800000",
+                    "deliveryFeeRate": "0.00015",
+                    "priceFilter": {
+                        "minPrice": "0.1",
+                        "maxPrice": "10000000",
+                        "tickSize": "0.1"
+                    },
+                    "lotSizeFilter": {
+                        "maxOrderQty": "1500",
+                        "minOrderQty": "0.1",
+                        "qtyStep": "0.1"
+                    },
+                    "displayName": "ETHUSDT-3JAN23-1250-P"
+                }
+            ]
+        }"#;
+        let message: InstrumentsInfo = deserialize_slice(json.as_bytes()).unwrap();
+        let expected = InstrumentsInfo::Option {
+            next_page_cursor: String::new(),
+            list: vec![OptionInstrumentsInfo {
+                symbol: String::from("ETH-3JAN23-1250-P"),
+                options_type: OptionsType::Put,
+                status: Status::Trading,
+                base_coin: String::from("ETH"),
+                quote_coin: String::from("USD"),
+                settle_coin: String::from("USDC"),
+                launch_time: 1672560000000,
+                delivery_time: 1672732800000,
+                delivery_fee_rate: Some(n!(0.00015)),
+                price_filter: PriceFilter {
+                    min_price: n!(0.1),
+                    max_price: n!(10000000.0),
+                    tick_size: n!(0.1),
+                },
+                lot_size_filter: OptionLotSizeFilter {
+                    max_order_qty: n!(1500.0),
+                    min_order_qty: n!(0.1),
+                    qty_step: n!(0.1),
+                },
+                display_name: String::from("ETHUSDT-3JAN23-1250-P"),
+            }],
+        };
+        assert_eq!(message, expected);
+    }
+
     #[test]
     fn deserialize_response_instruments_info_pre_launch() {
         // Pre-market Perpetual instrument structure
@@ -1102,32 +3525,32 @@ mod tests {
                 delivery_fee_rate: None,
                 price_scale: 4,
                 leverage_filter: LeverageFilter {
-                    min_leverage: 1.0,
-                    max_leverage: 5.00,
-                    leverage_step: 0.01,
+                    min_leverage: n!(1.0),
+                    max_leverage: n!(5.00),
+                    leverage_step: n!(0.01),
                 },
                 price_filter: PriceFilter {
-                    min_price: 0.0001,
-                    max_price: 1999.9998,
-                    tick_size: 0.0001,
+                    min_price: n!(0.0001),
+                    max_price: n!(1999.9998),
+                    tick_size: n!(0.0001),
                 },
                 lot_size_filter: LotSizeFilter {
-                    min_notional_value: 5.0,
-                    max_order_qty: 70000.0,
-                    max_mkt_order_qty: 14000.0,
-                    min_order_qty: 1.0,
-                    qty_step: 1.0,
-                    post_only_max_order_qty: 70000.0,
+                    min_notional_value: n!(5.0),
+                    max_order_qty: n!(70000.0),
+                    max_mkt_order_qty: n!(14000.0),
+                    min_order_qty: n!(1.0),
+                    qty_step: n!(1.0),
+                    post_only_max_order_qty: n!(70000.0),
                 },
                 unified_margin_trade: true,
                 funding_interval: 480,
                 settle_coin: String::from("USDT"),
                 copy_trading: CopyTrading::None,
-                upper_funding_rate: 0.05,
-                lower_funding_rate: -0.05,
+                upper_funding_rate: n!(0.05),
+                lower_funding_rate: n!(-0.05),
                 risk_parameters: RiskParameters {
-                    price_limit_ratio_x: 0.05,
-                    price_limit_ratio_y: 0.1,
+                    price_limit_ratio_x: n!(0.05),
+                    price_limit_ratio_y: n!(0.1),
                 },
                 is_pre_listing: true,
                 pre_listing_info: Some(PreListingInfo {
@@ -1155,9 +3578,9 @@ mod tests {
                         },
                     ],
                     auction_fee_info: AuctionFeeInfo {
-                        auction_fee_rate: 0.0,
-                        taker_fee_rate: 0.001,
-                        maker_fee_rate: 0.0004,
+                        auction_fee_rate: n!(0.0),
+                        taker_fee_rate: n!(0.001),
+                        maker_fee_rate: n!(0.0004),
                     },
                 }),
             }],
@@ -1223,33 +3646,33 @@ mod tests {
                 next_page_cursor: String::from(""),
                 list: vec![Position {
                     position_idx: PositionIdx::OneWay,
-                    risk_limit_value: 150.0,
+                    risk_limit_value: n!(150.0),
                     symbol: String::from("BTCUSD"),
                     side: Side::Sell,
-                    size: 300.0,
-                    avg_price: 27464.50441675,
-                    position_value: 0.01092319,
+                    size: n!(300.0),
+                    avg_price: n!(27464.50441675),
+                    position_value: n!(0.01092319),
                     trade_mode: TradeMode::CrossMargin,
                     auto_add_margin: AutoAddMargin::True,
                     position_status: PositionStatus::Normal,
-                    leverage: Some(10.0),
-                    mark_price: 28224.5,
+                    leverage: Some(n!(10.0)),
+                    mark_price: n!(28224.5),
                     liq_price: None,
-                    bust_price: 999999.0,
-                    position_i_m: 0.00010923,
-                    position_m_m: 0.0000015,
-                    position_balance: 0.00139186,
-                    take_profit: 0.0,
-                    stop_loss: 0.0,
-                    trailing_stop: 0.0,
+                    bust_price: n!(999999.0),
+                    position_i_m: n!(0.00010923),
+                    position_m_m: n!(0.0000015),
+                    position_balance: n!(0.00139186),
+                    take_profit: n!(0.0),
+                    stop_loss: n!(0.0),
+                    trailing_stop: n!(0.0),
                     session_avg_price: None,
                     delta: None,
                     gamma: None,
                     vega: None,
                     theta: None,
-                    unrealised_pnl: -0.00029413,
-                    cur_realised_pnl: 0.00013123,
-                    cum_realised_pnl: -0.00096902,
+                    unrealised_pnl: n!(-0.00029413),
+                    cur_realised_pnl: n!(0.00013123),
+                    cum_realised_pnl: n!(-0.00096902),
                     adl_rank_indicator: 2,
                     created_time: 1676538056258,
                     updated_time: 1697673600012,
@@ -1265,4 +3688,415 @@ mod tests {
         };
         assert_eq!(message, expected);
     }
+
+    #[test]
+    fn deserialize_response_executions() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "nextPageCursor": "132766%3A2%2C132766%3A2",
+                "category": "linear",
+                "list": [
+                    {
+                        "symbol": "ETHPERP",
+                        "orderType": "Market",
+                        "underlyingPrice": "",
+                        "orderLinkId": "",
+                        "side": "Buy",
+                        "indexPrice": "",
+                        "orderId": "8c065341-7b52-4ca9-ac2c-37e31ac55c94",
+                        "stopOrderType": "UNKNOWN",
+                        "leavesQty": "0",
+                        "execTime": "1672282722429",
+                        "feeCurrency": "",
+                        "isMaker": false,
+                        "execFee": "0.071409",
+                        "feeRate": "0.0006",
+                        "execId": "e0cbe81d-0f18-5866-9415-cf319b5dab3b",
+                        "tradeIv": "",
+                        "blockTradeId": "",
+                        "markPrice": "1183.54",
+                        "execPrice": "1190.15",
+                        "markIv": "",
+                        "orderQty": "0.1",
+                        "orderPrice": "1236.9",
+                        "execValue": "119.015",
+                        "execType": "Trade",
+                        "execQty": "0.1",
+                        "closedSize": "",
+                        "seq": 4688002127
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1672283754510
+        }"#;
+        let message: Response<Executions> = deserialize_slice(json.as_bytes()).unwrap();
+        assert_eq!(message.result.next_page_cursor, "132766%3A2%2C132766%3A2");
+        let execution = &message.result.list[0];
+        assert_eq!(execution.stop_order_type, StopOrderType::UNKNOWN);
+        assert_eq!(execution.exec_type, ExecType::Trade);
+        assert_eq!(execution.exec_time, 1672282722429);
+        assert_eq!(execution.mark_price, Some(n!(1183.54)));
+        assert_eq!(execution.closed_size, None);
+    }
+
+    #[test]
+    fn deserialize_response_transaction_log() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "nextPageCursor": "21963%3A1%2C14954%3A1",
+                "list": [
+                    {
+                        "transactionTime": "1684835712310",
+                        "symbol": "BTCUSDT",
+                        "currency": "USDT",
+                        "category": "linear",
+                        "side": "Buy",
+                        "type": "TRADE",
+                        "change": "-3.70788",
+                        "cashFlow": "0",
+                        "cashBalance": "199996.29212",
+                        "funding": "",
+                        "fee": "3.70788",
+                        "feeRate": "0.0006",
+                        "bonusChange": "",
+                        "size": "0.1",
+                        "qty": "0.1",
+                        "tradePrice": "61798",
+                        "tradeId": "8519d75e-3bcb-5a7e-88ea-1c8f7e3b5a7d",
+                        "orderId": "0f1b7d4c-2c46-4f8e-8f7a-7f6e7a2ec09e",
+                        "orderLinkId": "",
+                        "id": "592324_BTCUSDT_161440_0"
+                    }
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1684835712313
+        }"#;
+        let message: Response<TransactionLog> = deserialize_slice(json.as_bytes()).unwrap();
+        let transaction = &message.result.list[0];
+        assert_eq!(transaction.type_, Type::Trade);
+        assert_eq!(transaction.category, Category::Linear);
+        assert_eq!(transaction.funding, None);
+        assert_eq!(transaction.fee, Some(n!(3.70788)));
+        assert_eq!(transaction.change, n!(-3.70788));
+    }
+
+    #[test]
+    fn deserialize_response_mark_price_kline() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "symbol": "BTCUSDT",
+                "category": "linear",
+                "list": [
+                    ["1670608800000", "17164.16", "17164.16", "17121.5", "17131.64"]
+                ]
+            },
+            "retExtInfo": {},
+            "time": 1672026361839
+        }"#;
+        let message: Response<PriceKLine> = deserialize_slice(json.as_bytes()).unwrap();
+        let expected = PriceKLine::Linear {
+            symbol: String::from("BTCUSDT"),
+            list: vec![PriceKLineRow {
+                start_time: 1670608800000,
+                open_price: n!(17164.16),
+                high_price: n!(17164.16),
+                low_price: n!(17121.5),
+                close_price: n!(17131.64),
+            }],
+        };
+        assert_eq!(message.result, expected);
+    }
+
+    #[test]
+    fn deserialize_response_orderbook() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "s": "BTCUSDT",
+                "a": [["65557.7", "16.606555"]],
+                "b": [["65485.47", "47.081829"], ["65485.46", "0.5"]],
+                "ts": 1716863719031,
+                "u": 230704,
+                "seq": 1432604333,
+                "cts": 1716863718905
+            },
+            "retExtInfo": {},
+            "time": 1716863719382
+        }"#;
+        let message: Response<OrderBookSnapshot> = deserialize_slice(json.as_bytes()).unwrap();
+        let orderbook = message.result;
+        assert_eq!(orderbook.symbol, "BTCUSDT");
+        assert_eq!(
+            orderbook.asks,
+            vec![OrderBookLevelMsg(n!(65557.7), n!(16.606555))]
+        );
+        assert_eq!(orderbook.bids.len(), 2);
+        assert_eq!(orderbook.bids[1], OrderBookLevelMsg(n!(65485.46), n!(0.5)));
+        assert_eq!(orderbook.update_id, 230704);
+        assert_eq!(orderbook.seq, 1432604333);
+        assert_eq!(orderbook.cts, 1716863718905);
+    }
+
+    #[test]
+    fn deserialize_response_funding_history() {
+        let json = r#"{
+            "category": "linear",
+            "list": [
+                {
+                    "symbol": "ETHPERP",
+                    "fundingRate": "0.0001",
+                    "fundingRateTimestamp": "1672041600000"
+                }
+            ]
+        }"#;
+        let message: FundingHistory = deserialize_slice(json.as_bytes()).unwrap();
+        let expected = FundingHistory::Linear {
+            list: vec![FundingRate {
+                symbol: String::from("ETHPERP"),
+                funding_rate: n!(0.0001),
+                funding_rate_timestamp: 1672041600000,
+            }],
+        };
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn deserialize_response_open_interest() {
+        let json = r#"{
+            "symbol": "BTCUSD",
+            "category": "inverse",
+            "list": [
+                {
+                    "openInterest": "461134384.00000000",
+                    "timestamp": "1669571400000"
+                },
+                {
+                    "openInterest": "461134292.00000000",
+                    "timestamp": "1669571100000"
+                }
+            ],
+            "nextPageCursor": ""
+        }"#;
+        let message: OpenInterest = deserialize_slice(json.as_bytes()).unwrap();
+        let OpenInterest::Inverse {
+            symbol,
+            list,
+            next_page_cursor,
+        } = message
+        else {
+            panic!("unexpected category");
+        };
+        assert_eq!(symbol, "BTCUSD");
+        assert_eq!(next_page_cursor, "");
+        assert_eq!(
+            list[1],
+            OpenInterestRow {
+                open_interest: n!(461134292.0),
+                timestamp: 1669571100000,
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_response_historical_volatility() {
+        let json = r#"{
+            "retCode": 0,
+            "retMsg": "SUCCESS",
+            "category": "option",
+            "result": [
+                {
+                    "period": 7,
+                    "value": "0.27545620",
+                    "time": "1688477400000"
+                }
+            ],
+            "retExtInfo": {},
+            "time": 1688477406577
+        }"#;
+        let message: Response<Vec<HistoricalVolatility>> =
+            deserialize_slice(json.as_bytes()).unwrap();
+        let expected = vec![HistoricalVolatility {
+            period: 7,
+            value: n!(0.2754562),
+            time: 1688477400000,
+        }];
+        assert_eq!(message.result, expected);
+    }
+
+    #[test]
+    fn deserialize_response_insurance() {
+        let json = r#"{
+            "updatedTime": "1714003200000",
+            "list": [
+                {
+                    "coin": "USDT",
+                    "symbols": "MERLUSDT,10000000AIDOGEUSDT,ZEUSUSDT",
+                    "balance": "902178.57602476",
+                    "value": "902190.64"
+                },
+                {
+                    "coin": "BTC",
+                    "symbols": "",
+                    "balance": "1.5",
+                    "value": "95000.1"
+                }
+            ]
+        }"#;
+        let message: Insurance = deserialize_slice(json.as_bytes()).unwrap();
+        assert_eq!(message.updated_time, 1714003200000);
+        assert_eq!(
+            message.list[1],
+            InsuranceFund {
+                coin: String::from("BTC"),
+                symbols: String::new(),
+                balance: n!(1.5),
+                value: n!(95000.1),
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_response_risk_limit() {
+        let json = r#"{
+            "category": "linear",
+            "list": [
+                {
+                    "id": 1,
+                    "symbol": "BTCUSDT",
+                    "riskLimitValue": "2000000",
+                    "maintenanceMargin": "0.005",
+                    "initialMargin": "0.01",
+                    "isLowestRisk": 1,
+                    "maxLeverage": "100.00",
+                    "mmDeduction": ""
+                },
+                {
+                    "id": 2,
+                    "symbol": "BTCUSDT",
+                    "riskLimitValue": "2600000",
+                    "maintenanceMargin": "0.0055",
+                    "initialMargin": "0.011",
+                    "isLowestRisk": 0,
+                    "maxLeverage": "90.90",
+                    "mmDeduction": "1000"
+                }
+            ],
+            "nextPageCursor": "2%3A2"
+        }"#;
+        let message: RiskLimit = deserialize_slice(json.as_bytes()).unwrap();
+        let RiskLimit::Linear {
+            list,
+            next_page_cursor,
+        } = message
+        else {
+            panic!("unexpected category");
+        };
+        assert_eq!(next_page_cursor, "2%3A2");
+        assert_eq!(list[0].is_lowest_risk, 1);
+        assert_eq!(list[0].mm_deduction, None);
+        assert_eq!(
+            list[1],
+            RiskLimitTier {
+                id: 2,
+                symbol: String::from("BTCUSDT"),
+                risk_limit_value: n!(2600000.0),
+                maintenance_margin: n!(0.0055),
+                initial_margin: n!(0.011),
+                is_lowest_risk: 0,
+                max_leverage: n!(90.9),
+                mm_deduction: Some(n!(1000.0)),
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_response_delivery_price() {
+        let json = r#"{
+            "category": "option",
+            "nextPageCursor": "",
+            "list": [
+                {
+                    "symbol": "ETH-26DEC22-1400-C",
+                    "deliveryPrice": "1220.728198677",
+                    "deliveryTime": "1672041600000"
+                }
+            ]
+        }"#;
+        let message: DeliveryPrice = deserialize_slice(json.as_bytes()).unwrap();
+        let expected = DeliveryPrice::Option {
+            list: vec![DeliveryPriceRow {
+                symbol: String::from("ETH-26DEC22-1400-C"),
+                delivery_price: n!(1220.728198677),
+                delivery_time: 1672041600000,
+            }],
+            next_page_cursor: String::new(),
+        };
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn deserialize_response_collateral_info() {
+        let json = r#"{
+            "list": [
+                {
+                    "availableToBorrow": "3",
+                    "freeBorrowingAmount": "",
+                    "freeBorrowAmount": "0",
+                    "maxBorrowingAmount": "3",
+                    "hourlyBorrowRate": "0.0000015021220000",
+                    "borrowUsageRate": "0",
+                    "collateralSwitch": true,
+                    "borrowAmount": "0",
+                    "borrowable": true,
+                    "currency": "BTC",
+                    "otherBorrowAmount": "0",
+                    "marginCollateral": true,
+                    "freeBorrowingLimit": "",
+                    "collateralRatio": "0.95"
+                }
+            ]
+        }"#;
+        let message: CollateralInfo = deserialize_slice(json.as_bytes()).unwrap();
+        let coin = &message.list[0];
+        assert_eq!(coin.currency, "BTC");
+        assert_eq!(coin.hourly_borrow_rate, Some(n!(0.000001502122)));
+        assert_eq!(coin.free_borrowing_limit, None);
+        assert!(coin.borrowable);
+        assert_eq!(coin.collateral_ratio, Some(n!(0.95)));
+    }
+
+    #[test]
+    fn deserialize_response_borrow_history() {
+        let json = r#"{
+            "nextPageCursor": "2671153%3A1%2C2671153%3A1",
+            "list": [
+                {
+                    "borrowAmount": "1.06333265702840778",
+                    "costExemption": "0",
+                    "freeBorrowedAmount": "0",
+                    "createdTime": 1697439900204,
+                    "InterestBearingBorrowSize": "1.06333265702840778",
+                    "currency": "BTC",
+                    "unrealisedLoss": "0",
+                    "hourlyBorrowRate": "0.000001216904",
+                    "borrowCost": "0.00000129"
+                }
+            ]
+        }"#;
+        let message: BorrowHistory = deserialize_slice(json.as_bytes()).unwrap();
+        assert_eq!(message.next_page_cursor, "2671153%3A1%2C2671153%3A1");
+        let record = &message.list[0];
+        assert_eq!(record.created_time, 1697439900204);
+        assert_eq!(record.borrow_cost, n!(0.00000129));
+        assert_eq!(record.hourly_borrow_rate, n!(0.000001216904));
+    }
 }
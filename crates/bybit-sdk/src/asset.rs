@@ -0,0 +1,553 @@
+use futures_util::Stream;
+
+use crate::{
+    paginate::cursor,
+    url::{
+        PATH_ASSET_COIN_GREEKS, PATH_ASSET_COIN_QUERY_INFO, PATH_ASSET_DELIVERY_RECORD,
+        PATH_ASSET_DEPOSIT_QUERY_ADDRESS, PATH_ASSET_DEPOSIT_QUERY_ALLOWED_LIST,
+        PATH_ASSET_DEPOSIT_QUERY_RECORD, PATH_ASSET_DEPOSIT_QUERY_SUB_MEMBER_ADDRESS,
+        PATH_ASSET_DEPOSIT_QUERY_SUB_MEMBER_RECORD, PATH_ASSET_EXCHANGE_ORDER_RECORD,
+        PATH_ASSET_SETTLEMENT_RECORD, PATH_ASSET_TRANSFER_INTER_TRANSFER,
+        PATH_ASSET_TRANSFER_QUERY_ACCOUNT_COIN_BALANCE, PATH_ASSET_TRANSFER_QUERY_ASSET_INFO,
+        PATH_ASSET_TRANSFER_QUERY_INTER_TRANSFER_LIST, PATH_ASSET_TRANSFER_QUERY_SUB_MEMBER_LIST,
+        PATH_ASSET_TRANSFER_QUERY_TRANSFER_COIN_LIST,
+        PATH_ASSET_TRANSFER_QUERY_UNIVERSAL_TRANSFER_LIST,
+        PATH_ASSET_TRANSFER_SAVE_TRANSFER_SUB_MEMBER, PATH_ASSET_TRANSFER_UNIVERSAL_TRANSFER,
+        PATH_ASSET_WITHDRAW_CANCEL, PATH_ASSET_WITHDRAW_CREATE, PATH_ASSET_WITHDRAW_QUERY_RECORD,
+    },
+    AllowedDepositCoins, AssetInfo, CancelWithdraw, CancelWithdrawParams, Client, CoinGreeks,
+    CoinInfo, ConvertRecord, ConvertRecords, DeliveryRecord, DeliveryRecords, DepositAddress,
+    DepositRecord, DepositRecords, EnableUniversalTransferParams, GetAllowedDepositCoinsParams,
+    GetAssetInfoParams, GetCoinBalanceParams, GetCoinGreeksParams, GetCoinInfoParams,
+    GetConvertRecordsParams, GetDeliveryRecordParams, GetDepositAddressParams,
+    GetDepositRecordsParams, GetSettlementRecordParams, GetSubDepositAddressParams,
+    GetSubDepositRecordsParams, GetTransferRecordsParams, GetTransferableCoinsParams,
+    GetWithdrawRecordsParams, InterTransferParams, Page, PageParams, Pagination, Result,
+    SettlementRecord, SettlementRecords, SingleCoinBalance, SubDepositAddress, SubMembers,
+    TransferRecord, TransferRecords, TransferResult, TransferableCoins, UniversalTransferParams,
+    WithdrawId, WithdrawParams, WithdrawRecord, WithdrawRecords,
+};
+
+impl Client {
+    pub async fn get_delivery_record(
+        &self,
+        params: GetDeliveryRecordParams,
+    ) -> Result<DeliveryRecords> {
+        self.get_signed(PATH_ASSET_DELIVERY_RECORD, &params).await
+    }
+
+    pub fn delivery_records(
+        &self,
+        params: GetDeliveryRecordParams,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<DeliveryRecord>> + '_ {
+        self.paginate::<_, DeliveryRecords>(PATH_ASSET_DELIVERY_RECORD, params, pagination)
+    }
+
+    pub async fn get_settlement_record(
+        &self,
+        params: GetSettlementRecordParams,
+    ) -> Result<SettlementRecords> {
+        self.get_signed(PATH_ASSET_SETTLEMENT_RECORD, &params).await
+    }
+
+    pub fn settlement_records(
+        &self,
+        params: GetSettlementRecordParams,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<SettlementRecord>> + '_ {
+        self.paginate::<_, SettlementRecords>(PATH_ASSET_SETTLEMENT_RECORD, params, pagination)
+    }
+
+    pub async fn get_coin_greeks(&self, params: GetCoinGreeksParams) -> Result<CoinGreeks> {
+        self.get_signed(PATH_ASSET_COIN_GREEKS, &params).await
+    }
+
+    /// Safe to retry with the same `params`: the `transferId` is executed only once.
+    pub async fn create_internal_transfer(
+        &self,
+        params: &InterTransferParams,
+    ) -> Result<TransferResult> {
+        self.post_signed(PATH_ASSET_TRANSFER_INTER_TRANSFER, params)
+            .await
+    }
+
+    pub async fn get_internal_transfer_records(
+        &self,
+        params: GetTransferRecordsParams,
+    ) -> Result<TransferRecords> {
+        self.get_signed(PATH_ASSET_TRANSFER_QUERY_INTER_TRANSFER_LIST, &params)
+            .await
+    }
+
+    pub fn internal_transfers(
+        &self,
+        params: GetTransferRecordsParams,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<TransferRecord>> + '_ {
+        self.paginate::<_, TransferRecords>(
+            PATH_ASSET_TRANSFER_QUERY_INTER_TRANSFER_LIST,
+            params,
+            pagination,
+        )
+    }
+
+    pub async fn get_sub_uids(&self) -> Result<SubMembers> {
+        self.get_signed(PATH_ASSET_TRANSFER_QUERY_SUB_MEMBER_LIST, &())
+            .await
+    }
+
+    /// Master api key only.
+    pub async fn enable_universal_transfer(
+        &self,
+        params: EnableUniversalTransferParams,
+    ) -> Result<()> {
+        self.post_empty(PATH_ASSET_TRANSFER_SAVE_TRANSFER_SUB_MEMBER, &params)
+            .await
+    }
+
+    /// Safe to retry with the same `params`: the `transferId` is executed only once.
+    pub async fn create_universal_transfer(
+        &self,
+        params: &UniversalTransferParams,
+    ) -> Result<TransferResult> {
+        self.post_signed(PATH_ASSET_TRANSFER_UNIVERSAL_TRANSFER, params)
+            .await
+    }
+
+    pub async fn get_universal_transfer_records(
+        &self,
+        params: GetTransferRecordsParams,
+    ) -> Result<TransferRecords> {
+        self.get_signed(PATH_ASSET_TRANSFER_QUERY_UNIVERSAL_TRANSFER_LIST, &params)
+            .await
+    }
+
+    pub fn universal_transfers(
+        &self,
+        params: GetTransferRecordsParams,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<TransferRecord>> + '_ {
+        self.paginate::<_, TransferRecords>(
+            PATH_ASSET_TRANSFER_QUERY_UNIVERSAL_TRANSFER_LIST,
+            params,
+            pagination,
+        )
+    }
+
+    pub async fn get_transferable_coins(
+        &self,
+        params: GetTransferableCoinsParams,
+    ) -> Result<TransferableCoins> {
+        self.get_signed(PATH_ASSET_TRANSFER_QUERY_TRANSFER_COIN_LIST, &params)
+            .await
+    }
+
+    pub async fn get_coin_balance(
+        &self,
+        params: GetCoinBalanceParams,
+    ) -> Result<SingleCoinBalance> {
+        self.get_signed(PATH_ASSET_TRANSFER_QUERY_ACCOUNT_COIN_BALANCE, &params)
+            .await
+    }
+
+    pub async fn get_asset_info(&self, params: GetAssetInfoParams) -> Result<AssetInfo> {
+        self.get_signed(PATH_ASSET_TRANSFER_QUERY_ASSET_INFO, &params)
+            .await
+    }
+
+    pub async fn get_allowed_deposit_coins(
+        &self,
+        params: GetAllowedDepositCoinsParams,
+    ) -> Result<AllowedDepositCoins> {
+        self.get_signed(PATH_ASSET_DEPOSIT_QUERY_ALLOWED_LIST, &params)
+            .await
+    }
+
+    pub async fn get_deposit_records(
+        &self,
+        params: GetDepositRecordsParams,
+    ) -> Result<DepositRecords> {
+        self.get_signed(PATH_ASSET_DEPOSIT_QUERY_RECORD, &params)
+            .await
+    }
+
+    pub fn deposits(
+        &self,
+        params: GetDepositRecordsParams,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<DepositRecord>> + '_ {
+        self.paginate::<_, DepositRecords>(PATH_ASSET_DEPOSIT_QUERY_RECORD, params, pagination)
+    }
+
+    /// Master api key only.
+    pub async fn get_sub_deposit_records(
+        &self,
+        params: GetSubDepositRecordsParams,
+    ) -> Result<DepositRecords> {
+        self.get_signed(PATH_ASSET_DEPOSIT_QUERY_SUB_MEMBER_RECORD, &params)
+            .await
+    }
+
+    pub fn sub_deposits(
+        &self,
+        params: GetSubDepositRecordsParams,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<DepositRecord>> + '_ {
+        self.paginate::<_, DepositRecords>(
+            PATH_ASSET_DEPOSIT_QUERY_SUB_MEMBER_RECORD,
+            params,
+            pagination,
+        )
+    }
+
+    pub async fn get_withdraw_records(
+        &self,
+        params: GetWithdrawRecordsParams,
+    ) -> Result<WithdrawRecords> {
+        self.get_signed(PATH_ASSET_WITHDRAW_QUERY_RECORD, &params)
+            .await
+    }
+
+    pub fn withdrawals(
+        &self,
+        params: GetWithdrawRecordsParams,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<WithdrawRecord>> + '_ {
+        self.paginate::<_, WithdrawRecords>(PATH_ASSET_WITHDRAW_QUERY_RECORD, params, pagination)
+    }
+
+    pub async fn get_coin_info(&self, params: GetCoinInfoParams) -> Result<CoinInfo> {
+        self.get_signed(PATH_ASSET_COIN_QUERY_INFO, &params).await
+    }
+
+    /// Set [`WithdrawParams::request_id`] to make retries idempotent.
+    pub async fn withdraw(&self, params: &WithdrawParams) -> Result<WithdrawId> {
+        self.post_signed(PATH_ASSET_WITHDRAW_CREATE, params).await
+    }
+
+    pub async fn cancel_withdraw(&self, params: CancelWithdrawParams) -> Result<CancelWithdraw> {
+        self.post_signed(PATH_ASSET_WITHDRAW_CANCEL, &params).await
+    }
+
+    pub async fn get_deposit_address(
+        &self,
+        params: GetDepositAddressParams,
+    ) -> Result<DepositAddress> {
+        self.get_signed(PATH_ASSET_DEPOSIT_QUERY_ADDRESS, &params)
+            .await
+    }
+
+    /// Master api key only.
+    pub async fn get_sub_deposit_address(
+        &self,
+        params: GetSubDepositAddressParams,
+    ) -> Result<SubDepositAddress> {
+        self.get_signed(PATH_ASSET_DEPOSIT_QUERY_SUB_MEMBER_ADDRESS, &params)
+            .await
+    }
+
+    pub async fn get_convert_records(
+        &self,
+        params: GetConvertRecordsParams,
+    ) -> Result<ConvertRecords> {
+        self.get_signed(PATH_ASSET_EXCHANGE_ORDER_RECORD, &params)
+            .await
+    }
+
+    pub fn convert_history(
+        &self,
+        params: GetConvertRecordsParams,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<ConvertRecord>> + '_ {
+        self.paginate::<_, ConvertRecords>(PATH_ASSET_EXCHANGE_ORDER_RECORD, params, pagination)
+    }
+}
+
+impl PageParams for GetDeliveryRecordParams {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor);
+    }
+
+    fn set_time_window(&mut self, start_time: Option<u64>, end_time: Option<u64>) {
+        self.start_time = start_time;
+        self.end_time = end_time;
+    }
+}
+
+impl Page for DeliveryRecords {
+    type Item = DeliveryRecord;
+
+    fn next_page_cursor(&self) -> Option<&str> {
+        cursor(&self.next_page_cursor)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.list
+    }
+}
+
+impl PageParams for GetSettlementRecordParams {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor);
+    }
+
+    fn set_time_window(&mut self, start_time: Option<u64>, end_time: Option<u64>) {
+        self.start_time = start_time;
+        self.end_time = end_time;
+    }
+}
+
+impl Page for SettlementRecords {
+    type Item = SettlementRecord;
+
+    fn next_page_cursor(&self) -> Option<&str> {
+        cursor(&self.next_page_cursor)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.list
+    }
+}
+
+impl PageParams for GetTransferRecordsParams {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor);
+    }
+
+    fn set_time_window(&mut self, start_time: Option<u64>, end_time: Option<u64>) {
+        self.start_time = start_time;
+        self.end_time = end_time;
+    }
+}
+
+impl Page for TransferRecords {
+    type Item = TransferRecord;
+
+    fn next_page_cursor(&self) -> Option<&str> {
+        cursor(&self.next_page_cursor)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.list
+    }
+}
+
+impl PageParams for GetDepositRecordsParams {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor);
+    }
+
+    fn set_time_window(&mut self, start_time: Option<u64>, end_time: Option<u64>) {
+        self.start_time = start_time;
+        self.end_time = end_time;
+    }
+}
+
+impl Page for DepositRecords {
+    type Item = DepositRecord;
+
+    fn next_page_cursor(&self) -> Option<&str> {
+        cursor(&self.next_page_cursor)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.rows
+    }
+}
+
+impl PageParams for GetSubDepositRecordsParams {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor);
+    }
+
+    fn set_time_window(&mut self, start_time: Option<u64>, end_time: Option<u64>) {
+        self.start_time = start_time;
+        self.end_time = end_time;
+    }
+}
+
+impl PageParams for GetWithdrawRecordsParams {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor);
+    }
+
+    fn set_time_window(&mut self, start_time: Option<u64>, end_time: Option<u64>) {
+        self.start_time = start_time;
+        self.end_time = end_time;
+    }
+}
+
+impl Page for WithdrawRecords {
+    type Item = WithdrawRecord;
+
+    fn next_page_cursor(&self) -> Option<&str> {
+        cursor(&self.next_page_cursor)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.rows
+    }
+}
+
+/// The convert history has no time filter and ignores the window.
+impl PageParams for GetConvertRecordsParams {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor);
+    }
+
+    fn set_time_window(&mut self, _start_time: Option<u64>, _end_time: Option<u64>) {}
+}
+
+impl Page for ConvertRecords {
+    type Item = ConvertRecord;
+
+    fn next_page_cursor(&self) -> Option<&str> {
+        cursor(&self.next_page_cursor)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.order_body
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bybit_mock::MockServer;
+    use futures_util::TryStreamExt;
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::{
+        client::tests::signed_client, number::n, AccountType, DepositStatus, TransferStatus,
+    };
+
+    #[tokio::test]
+    async fn test_create_internal_transfer() {
+        let server = MockServer::start().await;
+        let params =
+            InterTransferParams::new("USDT", n!(10.5), AccountType::UNIFIED, AccountType::FUND);
+        assert!(uuid::Uuid::parse_str(&params.transfer_id).is_ok());
+        server.mock_rest(
+            PATH_ASSET_TRANSFER_INTER_TRANSFER,
+            json!({
+                "transferId": params.transfer_id,
+                "status": "SUCCESS"
+            }),
+        );
+
+        let client = signed_client(&server.url());
+        let result = client.create_internal_transfer(&params).await.unwrap();
+        assert_eq!(result.transfer_id, params.transfer_id);
+        assert_eq!(result.status, Some(TransferStatus::SUCCESS));
+
+        // A retry resends the same transfer id.
+        client.create_internal_transfer(&params).await.unwrap();
+        let requests = server.rest_requests();
+        assert_eq!(requests[0].body, requests[1].body);
+        let body: Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(body["transferId"], params.transfer_id.as_str());
+        assert_eq!(body["amount"], "10.5");
+        assert_eq!(body["fromAccountType"], "UNIFIED");
+        assert_eq!(body["toAccountType"], "FUND");
+        assert_ne!(
+            InterTransferParams::new("USDT", n!(10.5), AccountType::UNIFIED, AccountType::FUND)
+                .transfer_id,
+            params.transfer_id
+        );
+    }
+
+    #[tokio::test]
+    async fn test_deposits() {
+        let server = MockServer::start().await;
+        server.mock_rest(
+            PATH_ASSET_DEPOSIT_QUERY_RECORD,
+            json!({
+                "rows": [{
+                    "id": "160237231",
+                    "coin": "USDT",
+                    "chain": "ETH",
+                    "amount": "10000",
+                    "txID": "skip-notification-scene-test-amount-202212270944-533285-USDT",
+                    "status": 3,
+                    "toAddress": "test-amount-address",
+                    "tag": "",
+                    "depositFee": "",
+                    "successAt": "1672134274000",
+                    "confirmations": "10000",
+                    "txIndex": "",
+                    "blockHash": "",
+                    "batchReleaseLimit": "-1",
+                    "depositType": "0"
+                }, {
+                    "id": "160237232",
+                    "coin": "USDT",
+                    "chain": "TRX",
+                    "amount": "5",
+                    "txID": "0x1",
+                    "status": 10013,
+                    "toAddress": "test-amount-address",
+                    "tag": "",
+                    "depositFee": "1",
+                    "successAt": "1672134275000",
+                    "confirmations": "1",
+                    "txIndex": "0",
+                    "blockHash": "",
+                    "batchReleaseLimit": "-1",
+                    "depositType": "0"
+                }],
+                "nextPageCursor": ""
+            }),
+        );
+        let deposits: Vec<DepositRecord> = signed_client(&server.url())
+            .deposits(
+                GetDepositRecordsParams {
+                    coin: Some(String::from("USDT")),
+                    ..Default::default()
+                },
+                Pagination::default().time_window(1672000000000, 1672200000000),
+            )
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(deposits.len(), 2);
+        assert_eq!(deposits[0].status, DepositStatus::Success);
+        assert_eq!(deposits[0].amount, n!(10000.0));
+        assert_eq!(deposits[0].deposit_fee, None);
+        assert_eq!(deposits[0].success_at, 1672134274000);
+        assert_eq!(deposits[1].status, DepositStatus::Unrecognized(10013));
+        assert_eq!(deposits[1].deposit_fee, Some(n!(1.0)));
+        assert_eq!(
+            server.rest_requests()[0].query.as_deref(),
+            Some("coin=USDT&startTime=1672000000000&endTime=1672200000000")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_withdraw() {
+        let server = MockServer::start().await;
+        server.mock_rest(PATH_ASSET_WITHDRAW_CREATE, json!({ "id": "10195" }));
+        let params = WithdrawParams {
+            coin: String::from("USDT"),
+            chain: Some(String::from("ETH")),
+            address: String::from("0x99ced129603abc771c0dabe935c326ff6c86645d"),
+            tag: None,
+            amount: n!(24.0),
+            timestamp: 1672196561407,
+            force_chain: Some(0),
+            account_type: Some(AccountType::FUND),
+            fee_type: None,
+            request_id: Some(String::from("withdraw-1")),
+        };
+        let result = signed_client(&server.url())
+            .withdraw(&params)
+            .await
+            .unwrap();
+        assert_eq!(result.id, "10195");
+        let body: Value = serde_json::from_str(&server.rest_requests()[0].body).unwrap();
+        assert_eq!(body["amount"], "24");
+        assert_eq!(body["requestId"], "withdraw-1");
+        assert!(body.get("tag").is_none());
+    }
+}
@@ -0,0 +1,726 @@
+use reqwest::{
+    header::{HeaderValue, CONTENT_TYPE},
+    Method, Request, Url,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{from_value, Value};
+use std::{sync::Arc, time::Duration};
+use tokio::{task::JoinHandle, time::Instant};
+use tracing::Instrument;
+
+use crate::{
+    common::{deserialize_slice, timestamp},
+    url::{
+        HEADER_X_BAPI_API_KEY, HEADER_X_BAPI_RECV_WINDOW, HEADER_X_BAPI_SIGN,
+        HEADER_X_BAPI_TIMESTAMP, PATH_MARKET_DELIVERY_PRICE, PATH_MARKET_FUNDING_HISTORY,
+        PATH_MARKET_HISTORICAL_VOLATILITY, PATH_MARKET_INDEX_PRICE_KLINE,
+        PATH_MARKET_INSTRUMENTS_INFO, PATH_MARKET_INSURANCE, PATH_MARKET_KLINE,
+        PATH_MARKET_MARK_PRICE_KLINE, PATH_MARKET_OPEN_INTEREST, PATH_MARKET_ORDERBOOK,
+        PATH_MARKET_PREMIUM_INDEX_PRICE_KLINE, PATH_MARKET_RECENT_TRADE, PATH_MARKET_RISK_LIMIT,
+        PATH_MARKET_TICKERS, PATH_MARKET_TIME, PATH_ORDER_HISTORY, PATH_ORDER_REALTIME,
+        PATH_POSITION_LIST,
+    },
+    Clock, ClockSkew, Credentials, DeliveryPrice, EndpointKind, EndpointSet, Error, FundingHistory,
+    GetDeliveryPriceParams, GetFundingHistoryParams, GetHistoricalVolatilityParams,
+    GetInstrumentsInfoParams, GetInsuranceParams, GetKLinesParams, GetOpenClosedOrdersParams,
+    GetOpenInterestParams, GetOrderBookParams, GetPositionInfo, GetRiskLimitParams,
+    GetTickersParams, GetTradesParams, HistoricalVolatility, InstrumentsInfo, Insurance, KLine,
+    OpenClosedOrders, OpenInterest, OrderBookSnapshot, PositionInfo, PriceKLine, RateLimit,
+    RateLimiter, Reservation, Response, Result, RiskLimit, ServerTime, Ticker, Trade,
+};
+
+/// Default value of the `X-BAPI-RECV-WINDOW` header (ms).
+pub const DEFAULT_RECV_WINDOW: u64 = 5000;
+
+pub struct Client {
+    endpoints: Arc<EndpointSet>,
+    http: reqwest::Client,
+    credentials: Option<Credentials>,
+    recv_window: u64,
+    rate_limiter: RateLimiter,
+    throttle: bool,
+    clock: Arc<Clock>,
+}
+
+impl Client {
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            endpoints: Arc::new(EndpointSet::new(EndpointKind::Api, &[base_url])),
+            http: reqwest::Client::new(),
+            credentials: None,
+            recv_window: DEFAULT_RECV_WINDOW,
+            rate_limiter: RateLimiter::default(),
+            throttle: false,
+            clock: Arc::default(),
+        }
+    }
+
+    /// Sends requests to the current endpoint of the set instead of `base_url`, failing over
+    /// to another one on connection errors and 5xx responses. POST requests only fail over when
+    /// the connection was refused, since one that reached Bybit may have been applied.
+    pub fn with_endpoints(mut self, endpoints: Arc<EndpointSet>) -> Self {
+        self.endpoints = endpoints;
+        self
+    }
+
+    /// Credentials are required by every private endpoint.
+    /// The client adopts their [`Clock`], so [`Client::sync_time`] corrects it.
+    pub fn with_credentials(mut self, credentials: Credentials) -> Self {
+        self.clock = credentials.clock();
+        self.credentials = Some(credentials);
+        self
+    }
+
+    pub fn with_recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = recv_window;
+        self
+    }
+
+    /// Delays requests to endpoints whose rate limit is exhausted until the limit resets,
+    /// instead of letting Bybit reject them with 10006.
+    pub fn with_throttle(mut self) -> Self {
+        self.throttle = true;
+        self
+    }
+
+    /// Tracks the rate limit of `paths` together, for endpoints sharing one limit.
+    pub fn with_endpoint_group(mut self, group: &str, paths: &[&str]) -> Self {
+        self.rate_limiter.group(group, paths);
+        self
+    }
+
+    /// Rate limit reported by the last response of the endpoint (group).
+    pub fn rate_limit(&self, path: &str) -> Option<RateLimit> {
+        self.rate_limiter.get(path)
+    }
+
+    pub fn endpoints(&self) -> Arc<EndpointSet> {
+        self.endpoints.clone()
+    }
+
+    /// Clock used to sign requests.
+    pub fn clock(&self) -> Arc<Clock> {
+        self.clock.clone()
+    }
+
+    /// Skew measured by the last [`Client::sync_time`], e.g. to alert on a drifting host clock.
+    pub fn clock_skew(&self) -> Option<ClockSkew> {
+        self.clock.skew()
+    }
+
+    pub async fn get_server_time(&self) -> Result<ServerTime> {
+        self.get(PATH_MARKET_TIME, &()).await
+    }
+
+    /// Measures the offset of the local clock from Bybit's and applies it to signatures.
+    pub async fn sync_time(&self) -> Result<ClockSkew> {
+        let _reservation = self.throttle(PATH_MARKET_TIME).await;
+        sync_time(&self.http, &self.endpoints.current(), &self.clock).await
+    }
+
+    /// Calls [`Client::sync_time`] every `interval` until the task is aborted.
+    /// Failed measurements are logged and keep the previous skew.
+    pub fn spawn_time_sync(&self, interval: Duration) -> JoinHandle<()> {
+        let http = self.http.clone();
+        let endpoints = self.endpoints.clone();
+        let clock = self.clock.clone();
+        let span = tracing::info_span!("bybit.time_sync");
+        tokio::spawn(
+            async move {
+                loop {
+                    let base_url = endpoints.current();
+                    if let Err(e) = sync_time(&http, &base_url, &clock).await {
+                        tracing::warn!("Sync time failed: {e}");
+                    }
+                    tokio::time::sleep(interval).await;
+                }
+            }
+            .instrument(span),
+        )
+    }
+
+    pub async fn get_kline(&self, params: GetKLinesParams) -> Result<KLine> {
+        self.get(PATH_MARKET_KLINE, &params).await
+    }
+
+    pub async fn get_mark_price_kline(&self, params: GetKLinesParams) -> Result<PriceKLine> {
+        self.get(PATH_MARKET_MARK_PRICE_KLINE, &params).await
+    }
+
+    pub async fn get_index_price_kline(&self, params: GetKLinesParams) -> Result<PriceKLine> {
+        self.get(PATH_MARKET_INDEX_PRICE_KLINE, &params).await
+    }
+
+    /// Linear only.
+    pub async fn get_premium_index_price_kline(
+        &self,
+        params: GetKLinesParams,
+    ) -> Result<PriceKLine> {
+        self.get(PATH_MARKET_PREMIUM_INDEX_PRICE_KLINE, &params)
+            .await
+    }
+
+    pub async fn get_instruments_info(
+        &self,
+        params: GetInstrumentsInfoParams,
+    ) -> Result<InstrumentsInfo> {
+        self.get(PATH_MARKET_INSTRUMENTS_INFO, &params).await
+    }
+
+    pub async fn get_tickers(&self, params: GetTickersParams) -> Result<Ticker> {
+        self.get(PATH_MARKET_TICKERS, &params).await
+    }
+
+    pub async fn get_public_recent_trading_history(
+        &self,
+        params: GetTradesParams,
+    ) -> Result<Trade> {
+        self.get(PATH_MARKET_RECENT_TRADE, &params).await
+    }
+
+    pub async fn get_orderbook(&self, params: GetOrderBookParams) -> Result<OrderBookSnapshot> {
+        self.get(PATH_MARKET_ORDERBOOK, &params).await
+    }
+
+    pub async fn get_funding_rate_history(
+        &self,
+        params: GetFundingHistoryParams,
+    ) -> Result<FundingHistory> {
+        self.get(PATH_MARKET_FUNDING_HISTORY, &params).await
+    }
+
+    pub async fn get_open_interest(&self, params: GetOpenInterestParams) -> Result<OpenInterest> {
+        self.get(PATH_MARKET_OPEN_INTEREST, &params).await
+    }
+
+    pub async fn get_historical_volatility(
+        &self,
+        params: GetHistoricalVolatilityParams,
+    ) -> Result<Vec<HistoricalVolatility>> {
+        self.get(PATH_MARKET_HISTORICAL_VOLATILITY, &params).await
+    }
+
+    pub async fn get_insurance(&self, params: GetInsuranceParams) -> Result<Insurance> {
+        self.get(PATH_MARKET_INSURANCE, &params).await
+    }
+
+    pub async fn get_risk_limit(&self, params: GetRiskLimitParams) -> Result<RiskLimit> {
+        self.get(PATH_MARKET_RISK_LIMIT, &params).await
+    }
+
+    pub async fn get_delivery_price(
+        &self,
+        params: GetDeliveryPriceParams,
+    ) -> Result<DeliveryPrice> {
+        self.get(PATH_MARKET_DELIVERY_PRICE, &params).await
+    }
+
+    pub async fn get_open_closed_orders(
+        &self,
+        params: GetOpenClosedOrdersParams,
+    ) -> Result<OpenClosedOrders> {
+        self.get_signed(PATH_ORDER_REALTIME, &params).await
+    }
+
+    pub async fn get_order_history(
+        &self,
+        params: GetOpenClosedOrdersParams,
+    ) -> Result<OpenClosedOrders> {
+        self.get_signed(PATH_ORDER_HISTORY, &params).await
+    }
+
+    pub async fn get_position_info(&self, params: GetPositionInfo) -> Result<PositionInfo> {
+        self.get_signed(PATH_POSITION_LIST, &params).await
+    }
+
+    /// Public GET request. `params` are sent as the query string.
+    pub async fn get<P, T>(&self, path: &str, params: &P) -> Result<T>
+    where
+        P: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        let _reservation = self.throttle(path).await;
+        let url = format!("{}{}", self.endpoints.current(), path);
+        let request = self.http.request(Method::GET, url).query(params).build()?;
+        unwrap_response(self.execute(path, request).await?)
+    }
+
+    /// Private GET request. The query string is signed.
+    pub async fn get_signed<P, T>(&self, path: &str, params: &P) -> Result<T>
+    where
+        P: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        let _reservation = self.throttle(path).await;
+        let url = format!("{}{}", self.endpoints.current(), path);
+        let mut request = self.http.request(Method::GET, url).query(params).build()?;
+        let payload = request.url().query().unwrap_or_default().to_owned();
+        self.sign(&mut request, &payload)?;
+        unwrap_response(self.execute(path, request).await?)
+    }
+
+    /// Private POST request. The JSON body is signed.
+    pub async fn post_signed<B, T>(&self, path: &str, body: &B) -> Result<T>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        unwrap_response(self.post_signed_response(path, body).await?)
+    }
+
+    /// [`Client::post_signed`] for the endpoints answering an empty `result`.
+    pub(crate) async fn post_empty<B>(&self, path: &str, body: &B) -> Result<()>
+    where
+        B: Serialize + ?Sized,
+    {
+        let _: Value = self.post_signed(path, body).await?;
+        Ok(())
+    }
+
+    /// Like [`Client::post_signed`], but returns the whole response, e.g. for `retExtInfo`.
+    pub(crate) async fn post_signed_response<B, E>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<Response<Value, E>>
+    where
+        B: Serialize + ?Sized,
+        E: DeserializeOwned,
+    {
+        let _reservation = self.throttle(path).await;
+        let url = format!("{}{}", self.endpoints.current(), path);
+        let payload = serde_json::to_string(body).map_err(Error::Serialize)?;
+        let mut request = self
+            .http
+            .request(Method::POST, url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(payload.clone())
+            .build()?;
+        self.sign(&mut request, &payload)?;
+        self.execute(path, request).await
+    }
+
+    /// Private GET request of a paginated endpoint, always paced by the endpoint rate limit.
+    pub(crate) async fn get_signed_page<P, T>(&self, path: &str, params: &P) -> Result<T>
+    where
+        P: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        let _reservation = if self.throttle {
+            None
+        } else {
+            Some(self.rate_limiter.acquire(path).await)
+        };
+        self.get_signed(path, params).await
+    }
+
+    /// Must be called before signing, so a delayed request is still inside `recv_window`.
+    /// Keep the reservation until the response is received.
+    async fn throttle(&self, path: &str) -> Option<Reservation<'_>> {
+        if self.throttle {
+            Some(self.rate_limiter.acquire(path).await)
+        } else {
+            None
+        }
+    }
+
+    /// Adds the `X-BAPI-*` authentication headers.
+    /// Signature: HMAC-SHA256(timestamp + api_key + recv_window + payload).
+    fn sign(&self, request: &mut Request, payload: &str) -> Result<()> {
+        let Some(credentials) = &self.credentials else {
+            return Err(Error::Auth(String::from(
+                "credentials are required for private endpoints",
+            )));
+        };
+
+        let timestamp = credentials.timestamp().to_string();
+        let recv_window = self.recv_window.to_string();
+        let signature = credentials.sign(&format!(
+            "{timestamp}{}{recv_window}{payload}",
+            credentials.api_key
+        ));
+
+        let headers = request.headers_mut();
+        headers.insert(
+            HEADER_X_BAPI_API_KEY,
+            HeaderValue::from_str(&credentials.api_key)?,
+        );
+        headers.insert(HEADER_X_BAPI_TIMESTAMP, HeaderValue::from_str(&timestamp)?);
+        headers.insert(HEADER_X_BAPI_SIGN, HeaderValue::from_str(&signature)?);
+        headers.insert(
+            HEADER_X_BAPI_RECV_WINDOW,
+            HeaderValue::from_str(&recv_window)?,
+        );
+        Ok(())
+    }
+
+    /// Sends `request` to the current endpoint. On a connection error or 5xx response the
+    /// endpoint is reported and the request is repeated on the next one, at most once per
+    /// endpoint of the set. Non-idempotent POST requests are only repeated when the
+    /// connection failed, so an order is never placed twice.
+    async fn execute<R>(&self, path: &str, mut request: Request) -> Result<R>
+    where
+        R: DeserializeOwned,
+    {
+        let idempotent = request.method() == Method::GET;
+        let mut attempts = self.endpoints.len();
+        let mut base_url = self.endpoints.current();
+        let response = loop {
+            attempts -= 1;
+            let retry = match attempts {
+                0 => None,
+                _ => request.try_clone(),
+            };
+            let result = self.http.execute(request).await;
+            let failed = match &result {
+                Ok(response) => response.status().is_server_error(),
+                Err(e) => e.is_connect() || e.is_timeout(),
+            };
+            if !failed {
+                self.endpoints.report_success(&base_url, None);
+                break result?;
+            }
+            self.endpoints.report_failure(&base_url);
+            let repeatable = match &result {
+                Ok(_) => idempotent,
+                Err(e) => idempotent || e.is_connect(),
+            };
+            let Some(mut next) = retry.filter(|_| repeatable) else {
+                break result?;
+            };
+            let next_url = self.endpoints.current();
+            tracing::warn!(path, base_url, next_url, "Endpoint failed, failing over");
+            rebase(&mut next, &base_url, &next_url)?;
+            request = next;
+            base_url = next_url;
+        };
+        let rate_limit = RateLimit::from_headers(response.headers());
+        if let Some(rate_limit) = rate_limit {
+            self.rate_limiter.update(path, rate_limit);
+        }
+        let body = response.bytes().await?;
+        tracing::debug!(path, bytes = body.len(), ?rate_limit, "Bybit response");
+        deserialize_slice(&body).map_err(|e| Error::deserialize(e, String::from_utf8_lossy(&body)))
+    }
+}
+
+/// Moves `request` from the endpoint `from` to `to`, keeping the rest of the URL.
+fn rebase(request: &mut Request, from: &str, to: &str) -> Result<()> {
+    let Some(rest) = request.url().as_str().strip_prefix(from) else {
+        return Ok(());
+    };
+    let url = Url::parse(&format!("{to}{rest}")).map_err(|e| Error::Transport(Box::new(e)))?;
+    *request.url_mut() = url;
+    Ok(())
+}
+
+async fn sync_time(http: &reqwest::Client, base_url: &str, clock: &Clock) -> Result<ClockSkew> {
+    let sent = timestamp();
+    let start = Instant::now();
+    let response = http
+        .get(format!("{base_url}{PATH_MARKET_TIME}"))
+        .send()
+        .await?;
+    let round_trip = start.elapsed().as_millis() as u64;
+    let body = response.bytes().await?;
+    let response: Response<Value> = deserialize_slice(&body)
+        .map_err(|e| Error::deserialize(e, String::from_utf8_lossy(&body)))?;
+    let server_time: ServerTime = unwrap_response(response)?;
+
+    let skew = ClockSkew::estimate(sent, round_trip, server_time.time_nano / 1_000_000);
+    tracing::debug!(?skew, "Clock skew measured");
+    clock.update(skew);
+    Ok(skew)
+}
+
+fn unwrap_response<T>(response: Response<Value>) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    if response.ret_code != 0 {
+        return Err(Error::api(response.ret_code, response.ret_msg));
+    }
+
+    let payload = response.result.to_string();
+    from_value(response.result).map_err(|e| Error::deserialize(e, payload))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use bybit_mock::MockServer;
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::{Duration, Instant},
+    };
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    /// Client signing with test credentials.
+    pub(crate) fn signed_client(url: &str) -> Client {
+        Client::new(url).with_credentials(Credentials::new("api_key", "secret"))
+    }
+
+    fn mock_recent_trade(server: &MockServer, remaining: u32, reset_timestamp: u64) {
+        server.mock_rest(
+            PATH_MARKET_RECENT_TRADE,
+            serde_json::json!({ "category": "spot", "list": [] }),
+        );
+        server.set_rate_limit(
+            PATH_MARKET_RECENT_TRADE,
+            bybit_mock::RateLimit {
+                limit: 10,
+                remaining,
+                reset_timestamp,
+            },
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_from_response() {
+        let server = MockServer::start().await;
+        mock_recent_trade(&server, 0, 1672053055000);
+        let client = Client::new(&server.url());
+        let params = [("category", "spot"), ("symbol", "BTCUSDT")];
+
+        let _: Trade = client.get(PATH_MARKET_RECENT_TRADE, &params).await.unwrap();
+        assert_eq!(
+            client.rate_limit(PATH_MARKET_RECENT_TRADE),
+            Some(RateLimit {
+                limit: 10,
+                remaining: 0,
+                reset_timestamp: 1672053055000,
+            })
+        );
+
+        server.mock_rest_error(PATH_MARKET_RECENT_TRADE, 10006, "Too many visits!");
+        let error = client
+            .get::<_, Trade>(PATH_MARKET_RECENT_TRADE, &params)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::RateLimited {
+                ret_code: 10006,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_throttle_waits_for_reset() {
+        let server = MockServer::start().await;
+        let reset_timestamp = timestamp() + 200;
+        mock_recent_trade(&server, 0, reset_timestamp);
+        let client = Client::new(&server.url()).with_throttle();
+        let params = [("category", "spot"), ("symbol", "BTCUSDT")];
+
+        let _: Trade = client.get(PATH_MARKET_RECENT_TRADE, &params).await.unwrap();
+        let start = Instant::now();
+        let _: Trade = client.get(PATH_MARKET_RECENT_TRADE, &params).await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(100));
+        assert!(timestamp() >= reset_timestamp);
+    }
+
+    #[tokio::test]
+    async fn test_sync_time() {
+        let server = MockServer::start().await;
+        let server_time = timestamp() + 60_000;
+        server.mock_rest(
+            PATH_MARKET_TIME,
+            serde_json::json!({
+                "timeSecond": (server_time / 1000).to_string(),
+                "timeNano": (server_time * 1_000_000).to_string(),
+            }),
+        );
+        let credentials = Credentials::new("api_key", "secret");
+        let client = Client::new(&server.url()).with_credentials(credentials.clone());
+        assert_eq!(client.clock_skew(), None);
+
+        let skew = client.sync_time().await.unwrap();
+        assert!(skew.offset.abs_diff(60_000) < 1000);
+        assert_eq!(client.clock_skew(), Some(skew));
+        assert!(credentials.timestamp().abs_diff(timestamp() + 60_000) < 1000);
+        let crate::OutgoingMessage::Auth {
+            args: (_, expires, _),
+            ..
+        } = credentials.auth_message(None)
+        else {
+            unreachable!()
+        };
+        assert!((expires as u64).abs_diff(timestamp() + 70_000) < 1000);
+    }
+
+    /// Answers every request with 503. Returns the base url and the number of requests.
+    async fn serve_unavailable() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let count = requests.clone();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = [0; 1024];
+                let _ = socket.read(&mut buf).await;
+                count.fetch_add(1, Ordering::SeqCst);
+                let _ = socket
+                    .write_all(b"HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\n\r\n")
+                    .await;
+            }
+        });
+        (url, requests)
+    }
+
+    #[tokio::test]
+    async fn test_failover() {
+        let server = MockServer::start().await;
+        server.mock_rest(
+            PATH_MARKET_TIME,
+            serde_json::json!({"timeSecond": "1", "timeNano": "1000000000"}),
+        );
+
+        let (unavailable, _) = serve_unavailable().await;
+        let closed = crate::endpoint::tests::closed_url("http").await;
+
+        let endpoints = Arc::new(EndpointSet::new(
+            EndpointKind::Api,
+            &[&closed, &unavailable, &server.url()],
+        ));
+        let client = Client::new(&closed).with_endpoints(endpoints.clone());
+        let time = client.get_server_time().await.unwrap();
+        assert_eq!(time.time_second, 1);
+        let status = endpoints.status();
+        assert_eq!(
+            status.iter().map(|s| s.failures).collect::<Vec<_>>(),
+            vec![1, 1, 0]
+        );
+        assert_eq!(endpoints.current(), server.url());
+        assert_eq!(server.rest_requests().len(), 1);
+
+        // A set of one endpoint returns its error.
+        let client = Client::new(&unavailable);
+        assert!(matches!(
+            client.get_server_time().await,
+            Err(Error::Deserialize { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_post_not_repeated_after_server_error() {
+        let server = MockServer::start().await;
+        server.mock_rest(crate::url::PATH_ORDER_CREATE, serde_json::json!({}));
+        let (unavailable, requests) = serve_unavailable().await;
+        let endpoints = Arc::new(EndpointSet::new(
+            EndpointKind::Api,
+            &[&unavailable, &server.url()],
+        ));
+        let client = signed_client(&unavailable).with_endpoints(endpoints.clone());
+
+        let result: Result<Value> = client
+            .post_signed(crate::url::PATH_ORDER_CREATE, &serde_json::json!({}))
+            .await;
+        assert!(result.is_err());
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+        assert!(server.rest_requests().is_empty());
+        // The endpoint is still reported, so the next request goes elsewhere.
+        assert_eq!(endpoints.current(), server.url());
+    }
+
+    #[tokio::test]
+    async fn test_get_orderbook() {
+        let server = MockServer::start().await;
+        server.mock_rest(
+            PATH_MARKET_ORDERBOOK,
+            serde_json::json!({
+                "s": "BTCUSDT",
+                "a": [["65557.7", "16.606555"]],
+                "b": [["65485.47", "47.081829"]],
+                "ts": 1716863719031u64,
+                "u": 230704,
+                "seq": 1432604333,
+                "cts": 1716863718905u64,
+            }),
+        );
+        let client = Client::new(&server.url());
+        let orderbook = client
+            .get_orderbook(GetOrderBookParams {
+                category: crate::Category::Linear,
+                symbol: String::from("BTCUSDT"),
+                limit: Some(1),
+            })
+            .await
+            .unwrap();
+        assert_eq!(orderbook.update_id, 230704);
+
+        let requests = server.rest_requests();
+        assert_eq!(requests[0].path, PATH_MARKET_ORDERBOOK);
+        assert_eq!(
+            requests[0].query.as_deref(),
+            Some("category=linear&symbol=BTCUSDT&limit=1")
+        );
+    }
+
+    #[test]
+    fn test_unwrap_response_error() {
+        let json = r#"{
+            "retCode": 10001,
+            "retMsg": "params error: symbol invalid",
+            "result": {},
+            "retExtInfo": {},
+            "time": 1697684980172
+        }"#;
+        let response: Response<Value> = deserialize_slice(json.as_bytes()).unwrap();
+        let error = unwrap_response::<Ticker>(response).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Api { ret_code: 10001, ret_msg } if ret_msg == "params error: symbol invalid"
+        ));
+    }
+
+    #[test]
+    fn test_sign_get_request() {
+        let client = signed_client("https://api.bybit.com");
+        let mut request = client
+            .http
+            .get("https://api.bybit.com/v5/position/list")
+            .query(&GetPositionInfo {
+                category: crate::Category::Linear,
+                symbol: Some(String::from("BTCUSDT")),
+                base_coin: None,
+                settle_coin: None,
+                limit: None,
+                cursor: None,
+            })
+            .build()
+            .unwrap();
+        assert_eq!(
+            request.url().query(),
+            Some("category=linear&symbol=BTCUSDT")
+        );
+
+        client
+            .sign(&mut request, "category=linear&symbol=BTCUSDT")
+            .unwrap();
+        let headers = request.headers();
+        assert_eq!(headers[HEADER_X_BAPI_API_KEY], "api_key");
+        assert_eq!(headers[HEADER_X_BAPI_RECV_WINDOW], "5000");
+        let timestamp = headers[HEADER_X_BAPI_TIMESTAMP].to_str().unwrap();
+        let expected = Credentials::new("api_key", "secret").sign(&format!(
+            "{timestamp}api_key5000category=linear&symbol=BTCUSDT"
+        ));
+        assert_eq!(headers[HEADER_X_BAPI_SIGN], expected.as_str());
+    }
+
+    #[test]
+    fn test_sign_without_credentials() {
+        let client = Client::new("https://api.bybit.com");
+        let mut request = client
+            .http
+            .get("https://api.bybit.com/v5/position/list")
+            .build()
+            .unwrap();
+        assert!(matches!(client.sign(&mut request, ""), Err(Error::Auth(_))));
+    }
+}
@@ -0,0 +1,78 @@
+use std::sync::Mutex;
+
+use crate::common::timestamp;
+
+/// Offset of Bybit's clock from the local one, measured with `/v5/market/time`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClockSkew {
+    /// Server time minus local time (ms). Positive when the local clock is behind.
+    pub offset: i64,
+    /// Round trip of the measuring request (ms). The offset is accurate to half of it.
+    pub round_trip: u64,
+    /// Local UTC timestamp (ms) of the measurement.
+    pub measured_at: u64,
+}
+
+impl ClockSkew {
+    /// Assumes the server read its clock halfway through the round trip.
+    pub fn estimate(sent: u64, round_trip: u64, server_time: u64) -> Self {
+        let midpoint = sent + round_trip / 2;
+        Self {
+            offset: server_time as i64 - midpoint as i64,
+            round_trip,
+            measured_at: sent + round_trip,
+        }
+    }
+}
+
+/// Local clock corrected by the last [`ClockSkew`], used for signatures and `expires`.
+/// Without a measurement it is the local clock.
+#[derive(Debug, Default)]
+pub struct Clock {
+    skew: Mutex<Option<ClockSkew>>,
+}
+
+impl Clock {
+    /// Estimated server time, UTC timestamp in milliseconds.
+    pub fn now(&self) -> u64 {
+        let offset = self.skew().map(|skew| skew.offset).unwrap_or_default();
+        timestamp().saturating_add_signed(offset)
+    }
+
+    pub fn skew(&self) -> Option<ClockSkew> {
+        *self.skew.lock().unwrap()
+    }
+
+    pub fn update(&self, skew: ClockSkew) {
+        *self.skew.lock().unwrap() = Some(skew);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate() {
+        let skew = ClockSkew::estimate(1_000_000, 40, 1_001_520);
+        assert_eq!(
+            skew,
+            ClockSkew {
+                offset: 1500,
+                round_trip: 40,
+                measured_at: 1_000_040,
+            }
+        );
+        assert_eq!(ClockSkew::estimate(1_000_000, 40, 998_020).offset, -2000);
+    }
+
+    #[test]
+    fn test_clock_now() {
+        let clock = Clock::default();
+        assert!(clock.now().abs_diff(timestamp()) < 1000);
+
+        clock.update(ClockSkew::estimate(timestamp(), 0, timestamp() + 60_000));
+        assert!(clock.now().abs_diff(timestamp() + 60_000) < 1000);
+        assert!(clock.skew().unwrap().offset >= 59_000);
+    }
+}
@@ -0,0 +1,111 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::{fmt, sync::Arc};
+
+use crate::{Clock, OutgoingMessage};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Lifetime of the websocket `auth` signature (ms).
+const AUTH_EXPIRES_IN: u64 = 10_000;
+
+/// API key pair used to sign private REST requests and websocket `auth` requests.
+///
+/// Clones share the [`Clock`] of timestamps and `expires`, so syncing it with
+/// [`Client::sync_time`](crate::Client::sync_time) corrects every user of the key.
+#[derive(Clone)]
+pub struct Credentials {
+    pub api_key: String,
+    api_secret: String,
+    clock: Arc<Clock>,
+}
+
+impl Credentials {
+    pub fn new(api_key: &str, api_secret: &str) -> Self {
+        Self {
+            api_key: api_key.to_owned(),
+            api_secret: api_secret.to_owned(),
+            clock: Arc::default(),
+        }
+    }
+
+    pub fn with_clock(mut self, clock: Arc<Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub fn clock(&self) -> Arc<Clock> {
+        self.clock.clone()
+    }
+
+    /// Estimated server time (ms) for `X-BAPI-TIMESTAMP`.
+    pub fn timestamp(&self) -> u64 {
+        self.clock.now()
+    }
+
+    /// HMAC-SHA256 of the payload, hex encoded in lower case.
+    pub fn sign(&self, payload: &str) -> String {
+        let mut mac = HmacSha256::new_from_slice(self.api_secret.as_bytes())
+            .expect("HMAC can take key of any size");
+        mac.update(payload.as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    /// Signed `auth` request for private streams (`PATH_PRIVATE`, `PATH_TRADE`).
+    pub fn auth_message(&self, req_id: Option<String>) -> OutgoingMessage {
+        self.auth_message_expires(req_id, self.timestamp() + AUTH_EXPIRES_IN)
+    }
+
+    /// Signed `auth` request valid until `expires` (UTC timestamp in milliseconds).
+    pub fn auth_message_expires(&self, req_id: Option<String>, expires: u64) -> OutgoingMessage {
+        let signature = self.sign(&format!("GET/realtime{expires}"));
+        OutgoingMessage::Auth {
+            req_id,
+            args: (self.api_key.clone(), expires as i64, signature),
+        }
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("api_key", &self.api_key)
+            .field("api_secret", &"***")
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign() {
+        let credentials = Credentials::new("XXXXXXXXXX", "secret");
+        let payload = "1658384314791XXXXXXXXXX5000category=option&symbol=BTC-29JUL22-25000-C";
+        let expected = "02e9182e346177050f199ce1e0703d738589e3763805ed71590ced65539a73a7";
+        assert_eq!(credentials.sign(payload), expected);
+    }
+
+    #[test]
+    fn test_auth_message() {
+        let credentials = Credentials::new("api_key", "secret");
+        let message = credentials.auth_message_expires(None, 1662350400000);
+        let expected = OutgoingMessage::Auth {
+            req_id: None,
+            args: (
+                String::from("api_key"),
+                1662350400000,
+                String::from("d7ca36fea9ef1287007fd4b15af961e91d419a3d3f3ccbdf23585170ac116cd4"),
+            ),
+        };
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn test_debug_hides_secret() {
+        let credentials = Credentials::new("api_key", "topsecret");
+        let debug = format!("{credentials:?}");
+        assert!(!debug.contains("topsecret"));
+    }
+}
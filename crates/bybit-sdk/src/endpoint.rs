@@ -0,0 +1,340 @@
+use std::{
+    fmt,
+    str::FromStr,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tokio::{task::JoinHandle, time::timeout};
+use tokio_tungstenite::connect_async;
+use tracing::Instrument;
+
+use crate::url::{
+    PATH_MARKET_TIME, PATH_PUBLIC_SPOT, URL_BASE_API_DEMO_TRADING, URL_BASE_API_MAINNET_1,
+    URL_BASE_API_MAINNET_2, URL_BASE_API_MAINNET_3, URL_BASE_API_MAINNET_4, URL_BASE_API_MAINNET_5,
+    URL_BASE_API_MAINNET_6, URL_BASE_API_TESTNET, URL_BASE_STREAM_DEMO_TRADING,
+    URL_BASE_STREAM_MAINNET_1, URL_BASE_STREAM_MAINNET_2, URL_BASE_STREAM_MAINNET_3,
+    URL_BASE_STREAM_TESTNET,
+};
+
+/// How long a failed endpoint is skipped before it is tried again.
+const FAILURE_COOLDOWN: Duration = Duration::from_secs(30);
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Bybit site whose base URLs an [`EndpointSet`] uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Region {
+    Global,
+    Netherlands,
+    HongKong,
+    Turkey,
+    Kazakhstan,
+    Testnet,
+    Demo,
+}
+
+impl Region {
+    /// REST base URLs, preferred first.
+    pub fn api_urls(self) -> &'static [&'static str] {
+        match self {
+            Self::Global => &[URL_BASE_API_MAINNET_1, URL_BASE_API_MAINNET_2],
+            Self::Netherlands => &[URL_BASE_API_MAINNET_3],
+            Self::HongKong => &[URL_BASE_API_MAINNET_4],
+            Self::Turkey => &[URL_BASE_API_MAINNET_5],
+            Self::Kazakhstan => &[URL_BASE_API_MAINNET_6],
+            Self::Testnet => &[URL_BASE_API_TESTNET],
+            Self::Demo => &[URL_BASE_API_DEMO_TRADING],
+        }
+    }
+
+    /// Stream base URLs, preferred first.
+    pub fn stream_urls(self) -> &'static [&'static str] {
+        match self {
+            Self::Global | Self::Netherlands | Self::HongKong => &[URL_BASE_STREAM_MAINNET_1],
+            Self::Turkey => &[URL_BASE_STREAM_MAINNET_2],
+            Self::Kazakhstan => &[URL_BASE_STREAM_MAINNET_3],
+            Self::Testnet => &[URL_BASE_STREAM_TESTNET],
+            Self::Demo => &[URL_BASE_STREAM_DEMO_TRADING],
+        }
+    }
+}
+
+impl FromStr for Region {
+    type Err = String;
+
+    /// Accepts `global`, `nl`, `hk`, `tr`, `kz`, `testnet` and `demo`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "global" => Ok(Self::Global),
+            "nl" | "netherlands" => Ok(Self::Netherlands),
+            "hk" | "hongkong" => Ok(Self::HongKong),
+            "tr" | "turkey" => Ok(Self::Turkey),
+            "kz" | "kazakhstan" => Ok(Self::Kazakhstan),
+            "testnet" => Ok(Self::Testnet),
+            "demo" => Ok(Self::Demo),
+            _ => Err(format!("unknown region {s}")),
+        }
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match self {
+            Self::Global => "global",
+            Self::Netherlands => "nl",
+            Self::HongKong => "hk",
+            Self::Turkey => "tr",
+            Self::Kazakhstan => "kz",
+            Self::Testnet => "testnet",
+            Self::Demo => "demo",
+        };
+        write!(f, "{value}")
+    }
+}
+
+/// What an [`EndpointSet`] is health-checked with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndpointKind {
+    /// `GET /v5/market/time`.
+    Api,
+    /// Websocket handshake with the public spot stream.
+    Stream,
+}
+
+/// Last known state of one base URL.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndpointStatus {
+    pub url: String,
+    /// Round trip of the last successful health check.
+    pub latency: Option<Duration>,
+    /// Consecutive failures, reset by a success.
+    pub failures: u32,
+    last_failure: Option<Instant>,
+}
+
+impl EndpointStatus {
+    /// Not failed within the cooldown.
+    pub fn is_healthy(&self) -> bool {
+        self.last_failure
+            .is_none_or(|failed| failed.elapsed() >= FAILURE_COOLDOWN)
+    }
+}
+
+/// Interchangeable base URLs of one site. [`EndpointSet::current`] is the healthy one with
+/// the lowest latency; an endpoint that fails is skipped for a while, so the next request
+/// or reconnect fails over to another one.
+#[derive(Debug)]
+pub struct EndpointSet {
+    kind: EndpointKind,
+    state: Mutex<Vec<EndpointStatus>>,
+}
+
+impl EndpointSet {
+    /// `urls` are preferred in order until latencies are measured.
+    ///
+    /// # Panics
+    ///
+    /// If `urls` is empty.
+    pub fn new(kind: EndpointKind, urls: &[&str]) -> Self {
+        assert!(!urls.is_empty(), "an endpoint set needs at least one URL");
+        let state = urls
+            .iter()
+            .map(|url| EndpointStatus {
+                url: url.trim_end_matches('/').to_owned(),
+                latency: None,
+                failures: 0,
+                last_failure: None,
+            })
+            .collect();
+        Self {
+            kind,
+            state: Mutex::new(state),
+        }
+    }
+
+    pub fn api(region: Region) -> Self {
+        Self::new(EndpointKind::Api, region.api_urls())
+    }
+
+    pub fn stream(region: Region) -> Self {
+        Self::new(EndpointKind::Stream, region.stream_urls())
+    }
+
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Healthy endpoint with the lowest latency, unmeasured ones last. When every endpoint
+    /// failed recently, the one that failed first is tried again.
+    pub fn current(&self) -> String {
+        let state = self.state.lock().unwrap();
+        let healthy = state
+            .iter()
+            .enumerate()
+            .filter(|(_, status)| status.is_healthy())
+            .min_by_key(|(i, status)| (status.latency.unwrap_or(Duration::MAX), *i))
+            .map(|(_, status)| status);
+        let endpoint = healthy.or_else(|| state.iter().min_by_key(|status| status.last_failure));
+        endpoint
+            .map(|status| status.url.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn status(&self) -> Vec<EndpointStatus> {
+        self.state.lock().unwrap().clone()
+    }
+
+    /// `latency` of a health check; `None` keeps the previous one.
+    pub fn report_success(&self, url: &str, latency: Option<Duration>) {
+        self.update(url, |status| {
+            status.failures = 0;
+            status.last_failure = None;
+            if latency.is_some() {
+                status.latency = latency;
+            }
+        });
+    }
+
+    pub fn report_failure(&self, url: &str) {
+        self.update(url, |status| {
+            status.failures += 1;
+            status.last_failure = Some(Instant::now());
+        });
+    }
+
+    fn update(&self, url: &str, f: impl FnOnce(&mut EndpointStatus)) {
+        let mut state = self.state.lock().unwrap();
+        if let Some(status) = state.iter_mut().find(|status| status.url == url) {
+            f(status);
+        }
+    }
+
+    /// Probes every endpoint once and records its latency or failure.
+    pub async fn health_check(&self) {
+        let urls: Vec<String> = self.status().into_iter().map(|s| s.url).collect();
+        for url in urls {
+            let start = Instant::now();
+            match timeout(HEALTH_CHECK_TIMEOUT, probe(self.kind, &url)).await {
+                Ok(Ok(())) => self.report_success(&url, Some(start.elapsed())),
+                Ok(Err(e)) => {
+                    tracing::warn!(url, "Health check failed: {e}");
+                    self.report_failure(&url);
+                }
+                Err(_) => {
+                    tracing::warn!(url, "Health check timed out");
+                    self.report_failure(&url);
+                }
+            }
+        }
+    }
+
+    /// Calls [`EndpointSet::health_check`] every `interval` until the task is aborted.
+    pub fn spawn_health_check(self: &Arc<Self>, interval: Duration) -> JoinHandle<()> {
+        let endpoints = self.clone();
+        let span = tracing::info_span!("bybit.health_check", kind = ?self.kind);
+        tokio::spawn(
+            async move {
+                loop {
+                    endpoints.health_check().await;
+                    tracing::debug!(current = endpoints.current(), "Health check done");
+                    tokio::time::sleep(interval).await;
+                }
+            }
+            .instrument(span),
+        )
+    }
+}
+
+async fn probe(kind: EndpointKind, url: &str) -> crate::Result<()> {
+    match kind {
+        EndpointKind::Api => {
+            let response = reqwest::get(format!("{url}{PATH_MARKET_TIME}")).await?;
+            response.error_for_status()?;
+        }
+        EndpointKind::Stream => {
+            let (mut stream, _) = connect_async(format!("{url}{PATH_PUBLIC_SPOT}")).await?;
+            stream.close(None).await?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use bybit_mock::{MockServer, PATH_PUBLIC_SPOT as MOCK_PUBLIC_SPOT};
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    /// URL of a local port nothing listens on.
+    pub(crate) async fn closed_url(scheme: &str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        format!("{scheme}://{}", listener.local_addr().unwrap())
+    }
+
+    #[test]
+    fn test_region() {
+        assert_eq!("NL".parse::<Region>(), Ok(Region::Netherlands));
+        assert_eq!(
+            Region::Kazakhstan.to_string().parse(),
+            Ok(Region::Kazakhstan)
+        );
+        assert!("mars".parse::<Region>().is_err());
+        assert_eq!(
+            Region::Global.api_urls(),
+            &["https://api.bybit.com", "https://api.bytick.com"]
+        );
+        assert_eq!(Region::Turkey.api_urls(), &["https://api.bybit-tr.com"]);
+    }
+
+    #[test]
+    fn test_current() {
+        let endpoints = EndpointSet::new(EndpointKind::Api, &["http://a", "http://b/", "http://c"]);
+        assert_eq!(endpoints.current(), "http://a");
+
+        endpoints.report_success("http://c", Some(Duration::from_millis(20)));
+        endpoints.report_success("http://b", Some(Duration::from_millis(50)));
+        assert_eq!(endpoints.current(), "http://c");
+
+        endpoints.report_failure("http://c");
+        assert_eq!(endpoints.current(), "http://b");
+        endpoints.report_failure("http://b");
+        assert_eq!(endpoints.current(), "http://a");
+
+        // Every endpoint failed: the oldest failure is retried first.
+        endpoints.report_failure("http://a");
+        assert_eq!(endpoints.current(), "http://c");
+        assert_eq!(endpoints.status()[2].failures, 1);
+        assert!(!endpoints.status()[2].is_healthy());
+
+        endpoints.report_success("http://b", None);
+        assert_eq!(endpoints.current(), "http://b");
+        assert_eq!(
+            endpoints.status()[1].latency,
+            Some(Duration::from_millis(50))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_health_check() {
+        let server = MockServer::start().await;
+        server.mock_rest(PATH_MARKET_TIME, serde_json::json!({}));
+        let closed = closed_url("http").await;
+
+        let endpoints = EndpointSet::new(EndpointKind::Api, &[&closed, &server.url()]);
+        endpoints.health_check().await;
+        assert_eq!(endpoints.current(), server.url());
+        let status = endpoints.status();
+        assert_eq!(status[0].failures, 1);
+        assert!(status[1].latency.is_some());
+
+        assert_eq!(PATH_PUBLIC_SPOT, MOCK_PUBLIC_SPOT);
+        let ws_url = server.ws_url("");
+        let endpoints = EndpointSet::new(EndpointKind::Stream, &[&closed_url("ws").await, &ws_url]);
+        endpoints.health_check().await;
+        assert_eq!(endpoints.current(), ws_url);
+    }
+}
@@ -1,8 +1,9 @@
 use serde::{Deserialize, Serialize};
-use serde_repr::*;
 use std::fmt;
 
-#[derive(Debug, Deserialize, Serialize)]
+use crate::unrecognized::{unrecognized, Received};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone, Copy)]
 pub enum Locale {
     #[serde(rename = "de-DE")]
     DeDe,
@@ -44,7 +45,7 @@ pub enum Locale {
     FilPh,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
 pub enum AnnouncementType {
     #[serde(rename = "new_crypto")]
     NewCrypto,
@@ -62,11 +63,14 @@ pub enum AnnouncementType {
     NewFiatListings,
     #[serde(rename = "other")]
     Other,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<AnnouncementType, _>")]
+    Unrecognized(String),
 }
 
 // Unified Account: spot | linear | inverse | option
 // Classic Account: linear | inverse | spot
-#[derive(PartialEq, Debug, Deserialize, Serialize, Clone)]
+#[derive(PartialEq, Eq, Hash, Debug, Deserialize, Serialize, Clone)]
 pub enum Category {
     #[serde(rename = "inverse")]
     Inverse, // Inverse contract, including Inverse perp, Inverse futures.
@@ -76,6 +80,9 @@ pub enum Category {
     Option,
     #[serde(rename = "spot")]
     Spot,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<Category, _>")]
+    Unrecognized(String),
 }
 
 impl fmt::Display for Category {
@@ -85,6 +92,7 @@ impl fmt::Display for Category {
             Self::Linear => "linear",
             Self::Option => "option",
             Self::Spot => "spot",
+            Self::Unrecognized(value) => value,
         };
         write!(f, "{value}")
     }
@@ -103,6 +111,9 @@ pub enum OrderStatus {
     Cancelled,   // In derivatives, orders with this status may have an executed qty
     Triggered,   // instantaneous state for conditional orders from Untriggered to New
     Deactivated, // UTA: Spot tp/sl order, conditional order, OCO order are cancelled before they are triggered
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<OrderStatus, _>")]
+    Unrecognized(String),
 }
 
 impl OrderStatus {
@@ -129,11 +140,14 @@ pub enum TimeInForce {
     FOK, // FillOrKill
     PostOnly,
     RPI, // features:
-         // Exclusive Matching: Only match non-algorithmic users; no execution against orders from Open API.
-         // Post-Only Mechanism: Act as maker orders, adding liquidity
-         // Lower Priority: Execute after non-RPI orders at the same price level.
-         // Limited Access: Initially for select market makers across multiple pairs.
-         // Order Book Updates: Excluded from API but displayed on the GUI.
+    // Exclusive Matching: Only match non-algorithmic users; no execution against orders from Open API.
+    // Post-Only Mechanism: Act as maker orders, adding liquidity
+    // Lower Priority: Execute after non-RPI orders at the same price level.
+    // Limited Access: Initially for select market makers across multiple pairs.
+    // Order Book Updates: Excluded from API but displayed on the GUI.
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<TimeInForce, _>")]
+    Unrecognized(String),
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -168,6 +182,9 @@ pub enum CreateType {
     CreateByIceBerg,  // Order created by Ice berg strategy - web/app
     CreateByArbitrage, // Order created by arbitrage - web/app
     CreateByDdh,      // Option dynamic delta hedge order - web/app
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<CreateType, _>")]
+    Unrecognized(String),
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -182,6 +199,9 @@ pub enum ExecType {
     MovePosition,
     FutureSpread, // Spread leg execution
     UNKNOWN,      // May be returned by a classic account. Cannot query by this type
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<ExecType, _>")]
+    Unrecognized(String),
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -189,6 +209,9 @@ pub enum OrderType {
     Market,
     Limit,
     UNKNOWN, // is not a valid request parameter value. Is only used in some responses. Mainly, it is used when execType is Funding.
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<OrderType, _>")]
+    Unrecognized(String),
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -204,22 +227,29 @@ pub enum StopOrderType {
     OcoOrder,               // spot Oco order
     MmRateClose,            // On web or app can set MMR to close position
     BidirectionalTpslOrder, // Spot bidirectional tpsl order
+    UNKNOWN,                // Execution of an order without stop order type
     /// As Option::None
     /// Deprecated!
     /// TODO: write deserializer from ""
     #[serde(rename = "")]
     None,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<StopOrderType, _>")]
+    Unrecognized(String),
 }
 
-#[derive(Debug, PartialEq, Deserialize)]
+#[derive(Debug, PartialEq, Deserialize, Clone)]
 pub enum TickDirection {
     PlusTick,      // price rise
     ZeroPlusTick, // trade occurs at the same price as the previous trade, which occurred at a price higher than that for the trade preceding it
     MinusTick,    // price drop
     ZeroMinusTick, // trade occurs at the same price as the previous trade, which occurred at a price lower than that for the trade preceding it
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<TickDirection, _>")]
+    Unrecognized(String),
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Hash, Clone)]
 pub enum Interval {
     #[serde(rename = "1")]
     Minute1,
@@ -247,6 +277,9 @@ pub enum Interval {
     Week,
     #[serde(rename = "M")]
     Month,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<Interval, _>")]
+    Unrecognized(String),
 }
 
 impl fmt::Display for Interval {
@@ -265,6 +298,7 @@ impl fmt::Display for Interval {
             Self::Day => "D",
             Self::Week => "W",
             Self::Month => "M",
+            Self::Unrecognized(value) => value,
         };
         write!(f, "{value}")
     }
@@ -286,15 +320,45 @@ pub enum IntervalTime {
     Day1,
 }
 
-#[derive(Serialize_repr, Deserialize_repr, Debug, PartialEq)]
-#[repr(u8)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(from = "Received<PositionIdx>", into = "i64")]
 pub enum PositionIdx {
     /// 0:one-way mode position
-    OneWay = 0,
+    OneWay,
     /// 1:Buy side of hedge-mode position
-    Buy = 1,
+    Buy,
     /// 2:Sell side of hedge-mode position
-    Sell = 2,
+    Sell,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    Unrecognized(i64),
+}
+
+impl From<i64> for PositionIdx {
+    fn from(value: i64) -> Self {
+        match value {
+            0 => Self::OneWay,
+            1 => Self::Buy,
+            2 => Self::Sell,
+            _ => Self::Unrecognized(value),
+        }
+    }
+}
+
+impl From<Received<PositionIdx>> for PositionIdx {
+    fn from(received: Received<Self>) -> Self {
+        received.decode(Self::Unrecognized)
+    }
+}
+
+impl From<PositionIdx> for i64 {
+    fn from(value: PositionIdx) -> Self {
+        match value {
+            PositionIdx::OneWay => 0,
+            PositionIdx::Buy => 1,
+            PositionIdx::Sell => 2,
+            PositionIdx::Unrecognized(value) => value,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -302,6 +366,9 @@ pub enum PositionStatus {
     Normal,
     Liq, // in the liquidation progress
     Adl, // in the auto-deleverage progress
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<PositionStatus, _>")]
+    Unrecognized(String),
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -404,14 +471,36 @@ pub enum RejectReason {
     EcCancelByMatchValueZero,
     #[serde(rename = "EC_ReachMarketPriceLimit")]
     EcReachMarketPriceLimit,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<RejectReason, _>")]
+    Unrecognized(String),
+}
+
+impl RejectReason {
+    /// Maps a `retCode` of a rejected order request to the closest reject reason.
+    pub fn from_ret_code(ret_code: i32) -> Self {
+        match ret_code {
+            0 => Self::EcNoError,
+            110001 | 170213 => Self::EcOrderNotExist,
+            110008 | 110010 => Self::EcTooLateToCancel,
+            110072 | 170141 => Self::EcDuplicatedClOrdId,
+            170134 => Self::EcInvalidPriceScale,
+            170137 => Self::EcEcInvalidQty,
+            170218 => Self::EcPostOnlyWillTakeLiquidity,
+            _ => Self::EcOthers,
+        }
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
 pub enum AccountType {
     CONTRACT, // Inverse Derivatives Account | Derivatives Account
     UNIFIED,  // Unified Trading Account
     FUND,     // Funding Account
     SPOT,     // Spot Account
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<AccountType, _>")]
+    Unrecognized(String),
 }
 
 impl AccountType {
@@ -431,24 +520,67 @@ pub enum TransferStatus {
     SUCCESS,
     PENDING,
     FAILED,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<TransferStatus, _>")]
+    Unrecognized(String),
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// Sent as a number, e.g. `"status": 3`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(from = "Received<DepositStatus>", into = "i64")]
 pub enum DepositStatus {
-    #[serde(rename = "0")]
+    /// 0
     Unknown,
-    #[serde(rename = "1")]
+    /// 1
     ToBeConfirmed,
-    #[serde(rename = "2")]
+    /// 2
     Processing,
-    #[serde(rename = "3")]
-    Success, // (finalised status of a success deposit)
-    #[serde(rename = "4")]
+    /// 3: finalised status of a success deposit
+    Success,
+    /// 4
     DepositFailed,
-    #[serde(rename = "10011")]
+    /// 10011
     PendingToBeCreditedToFundingPool,
-    #[serde(rename = "10012")]
+    /// 10012
     CreditedToFundingPoolSuccessfully,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    Unrecognized(i64),
+}
+
+impl From<i64> for DepositStatus {
+    fn from(value: i64) -> Self {
+        match value {
+            0 => Self::Unknown,
+            1 => Self::ToBeConfirmed,
+            2 => Self::Processing,
+            3 => Self::Success,
+            4 => Self::DepositFailed,
+            10011 => Self::PendingToBeCreditedToFundingPool,
+            10012 => Self::CreditedToFundingPoolSuccessfully,
+            _ => Self::Unrecognized(value),
+        }
+    }
+}
+
+impl From<Received<DepositStatus>> for DepositStatus {
+    fn from(received: Received<Self>) -> Self {
+        received.decode(Self::Unrecognized)
+    }
+}
+
+impl From<DepositStatus> for i64 {
+    fn from(value: DepositStatus) -> Self {
+        match value {
+            DepositStatus::Unknown => 0,
+            DepositStatus::ToBeConfirmed => 1,
+            DepositStatus::Processing => 2,
+            DepositStatus::Success => 3,
+            DepositStatus::DepositFailed => 4,
+            DepositStatus::PendingToBeCreditedToFundingPool => 10011,
+            DepositStatus::CreditedToFundingPoolSuccessfully => 10012,
+            DepositStatus::Unrecognized(value) => value,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -463,6 +595,9 @@ pub enum WithdrawStatus {
     BlockchainConfirmed,
     MoreInformationRequired,
     Unknown, // a rare status
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<WithdrawStatus, _>")]
+    Unrecognized(String),
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -475,6 +610,9 @@ pub enum TriggerBy {
     /// TODO: write deserializer from ""
     #[serde(rename = "")]
     None,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<TriggerBy, _>")]
+    Unrecognized(String),
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -498,6 +636,9 @@ pub enum CancelType {
     CancelByCrossReachMaxTradeNum,
     CancelByDCP,
     UNKNOWN,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<CancelType, _>")]
+    Unrecognized(String),
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -542,17 +683,33 @@ pub enum ContractType {
     LinearPerpetual,
     LinearFutures, // USDT/USDC Futures
     InverseFutures,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<ContractType, _>")]
+    Unrecognized(String),
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
+pub enum OptionsType {
+    Call,
+    Put,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<OptionsType, _>")]
+    Unrecognized(String),
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
 pub enum Status {
     PreLaunch,
     Trading,
+    Settling,
     Delivering,
     Closed,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<Status, _>")]
+    Unrecognized(String),
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone)]
 pub enum CurAuctionPhase {
     NotStarted, // Pre-market trading is not started
     Finished,   // Pre-market trading is finished
@@ -572,8 +729,11 @@ pub enum CurAuctionPhase {
     // cannot create, modify and cancel the order at this stage
     // Candle data is released from this stage
     ContinuousTrading, // Continuous trading phase
-                       // There is no restriction to create, amend, cancel orders
-                       // orderbook, public trade data is released from this stage
+    // There is no restriction to create, amend, cancel orders
+    // orderbook, public trade data is released from this stage
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<CurAuctionPhase, _>")]
+    Unrecognized(String),
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -586,6 +746,9 @@ pub enum MarginTrading {
     UtaOnly, // Only for UTA account,this trading pair supports margin trading
     #[serde(rename = "normalSpotOnly")]
     NormalSpotOnly, // Only for normal account, this trading pair supports margin trading
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<MarginTrading, _>")]
+    Unrecognized(String),
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -599,6 +762,9 @@ pub enum CopyTrading {
     UtaOnly, // Only for UTA account,this trading pair supports copy trading
     #[serde(rename = "normalOnly")]
     NormalOnly, // Only for normal account, this trading pair supports copy trading
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<CopyTrading, _>")]
+    Unrecognized(String),
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -658,6 +824,9 @@ pub enum Type {
     PefProfitShare,
     #[serde(rename = "Others")]
     Others,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<Type, _>")]
+    Unrecognized(String),
 }
 
 impl Type {
@@ -738,18 +907,65 @@ impl Type {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// Sent as a number, e.g. `"unifiedMarginStatus": 5`.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(from = "Received<UnifiedMarginStatus>", into = "i64")]
 pub enum UnifiedMarginStatus {
-    #[serde(rename = "1")]
+    /// 1: classic account
     ClassicAccount,
-    #[serde(rename = "3")]
-    UnifiedTradingAccount1, // 1.0
-    #[serde(rename = "4")]
-    UnifiedTradingAccount1Pro, // 1.0 (pro version)
-    #[serde(rename = "5")]
-    UnifiedTradingAccount2, // 2.0
-    #[serde(rename = "6")]
-    UnifiedTradingAccount2Pro, // 2.0 (pro version)
+    /// 3: unified trading account 1.0
+    UnifiedTradingAccount1,
+    /// 4: unified trading account 1.0 (pro version)
+    UnifiedTradingAccount1Pro,
+    /// 5: unified trading account 2.0
+    UnifiedTradingAccount2,
+    /// 6: unified trading account 2.0 (pro version)
+    UnifiedTradingAccount2Pro,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    Unrecognized(i64),
+}
+
+impl From<i64> for UnifiedMarginStatus {
+    fn from(value: i64) -> Self {
+        match value {
+            1 => Self::ClassicAccount,
+            3 => Self::UnifiedTradingAccount1,
+            4 => Self::UnifiedTradingAccount1Pro,
+            5 => Self::UnifiedTradingAccount2,
+            6 => Self::UnifiedTradingAccount2Pro,
+            _ => Self::Unrecognized(value),
+        }
+    }
+}
+
+impl From<Received<UnifiedMarginStatus>> for UnifiedMarginStatus {
+    fn from(received: Received<Self>) -> Self {
+        received.decode(Self::Unrecognized)
+    }
+}
+
+impl From<UnifiedMarginStatus> for i64 {
+    fn from(value: UnifiedMarginStatus) -> Self {
+        match value {
+            UnifiedMarginStatus::ClassicAccount => 1,
+            UnifiedMarginStatus::UnifiedTradingAccount1 => 3,
+            UnifiedMarginStatus::UnifiedTradingAccount1Pro => 4,
+            UnifiedMarginStatus::UnifiedTradingAccount2 => 5,
+            UnifiedMarginStatus::UnifiedTradingAccount2Pro => 6,
+            UnifiedMarginStatus::Unrecognized(value) => value,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MarginMode {
+    IsolatedMargin,
+    RegularMargin, // Cross margin
+    PortfolioMargin,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<MarginMode, _>")]
+    Unrecognized(String),
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -764,6 +980,71 @@ pub enum LtStatus {
     LTCannotBePurchasedNorRedeemed,
     #[serde(rename = "5")]
     AdjustingPosition,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<LtStatus, _>")]
+    Unrecognized(String),
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(from = "Received<LtOrderType>", into = "i64")]
+pub enum LtOrderType {
+    /// 1
+    Purchase,
+    /// 2
+    Redeem,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    Unrecognized(i64),
+}
+
+impl From<i64> for LtOrderType {
+    fn from(value: i64) -> Self {
+        match value {
+            1 => Self::Purchase,
+            2 => Self::Redeem,
+            _ => Self::Unrecognized(value),
+        }
+    }
+}
+
+impl From<Received<LtOrderType>> for LtOrderType {
+    fn from(received: Received<Self>) -> Self {
+        received.decode(Self::Unrecognized)
+    }
+}
+
+impl From<LtOrderType> for i64 {
+    fn from(value: LtOrderType) -> Self {
+        match value {
+            LtOrderType::Purchase => 1,
+            LtOrderType::Redeem => 2,
+            LtOrderType::Unrecognized(value) => value,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub enum LtOrderStatus {
+    #[serde(rename = "1")]
+    Completed,
+    #[serde(rename = "2")]
+    InProgress,
+    #[serde(rename = "3")]
+    Failed,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<LtOrderStatus, _>")]
+    Unrecognized(String),
+}
+
+/// Spot margin trading of a unified account.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub enum SpotMarginMode {
+    #[serde(rename = "1")]
+    On,
+    #[serde(rename = "0")]
+    Off,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<SpotMarginMode, _>")]
+    Unrecognized(String),
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -778,6 +1059,9 @@ pub enum ConvertAccountType {
     Spot, // Spot Account
     #[serde(rename = "eb_convert_contract")]
     Contract, // Derivatives Account (contain USDT in this wallet)
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<ConvertAccountType, _>")]
+    Unrecognized(String),
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -806,6 +1090,9 @@ pub enum VipLevel {
     PRO4,
     #[serde(rename = "PRO-5")]
     PRO5,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<VipLevel, _>")]
+    Unrecognized(String),
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -814,6 +1101,9 @@ pub enum SmpType {
     CancelMaker,
     CancelTaker,
     CancelBoth,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<SmpType, _>")]
+    Unrecognized(String),
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -825,6 +1115,9 @@ pub enum TpslMode {
     /// TODO: write deserializer from ""
     #[serde(rename = "")]
     None,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<TpslMode, _>")]
+    Unrecognized(String),
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -835,14 +1128,47 @@ pub enum OcoTriggerBy {
     Tp,
     #[serde(rename = "OcoTriggerByBySl")]
     BySl,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<OcoTriggerBy, _>")]
+    Unrecognized(String),
 }
 
-#[derive(Serialize_repr, Deserialize_repr, Debug, PartialEq)]
-#[repr(u8)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(from = "Received<TriggerDirection>", into = "i64")]
 pub enum TriggerDirection {
-    UNKNOWN = 0,
-    Rise = 1,
-    Fall = 2,
+    UNKNOWN,
+    Rise,
+    Fall,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    Unrecognized(i64),
+}
+
+impl From<i64> for TriggerDirection {
+    fn from(value: i64) -> Self {
+        match value {
+            0 => Self::UNKNOWN,
+            1 => Self::Rise,
+            2 => Self::Fall,
+            _ => Self::Unrecognized(value),
+        }
+    }
+}
+
+impl From<Received<TriggerDirection>> for TriggerDirection {
+    fn from(received: Received<Self>) -> Self {
+        received.decode(Self::Unrecognized)
+    }
+}
+
+impl From<TriggerDirection> for i64 {
+    fn from(value: TriggerDirection) -> Self {
+        match value {
+            TriggerDirection::UNKNOWN => 0,
+            TriggerDirection::Rise => 1,
+            TriggerDirection::Fall => 2,
+            TriggerDirection::Unrecognized(value) => value,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -851,6 +1177,9 @@ pub enum Innovation {
     False,
     #[serde(rename = "1")]
     True,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<Innovation, _>")]
+    Unrecognized(String),
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -861,12 +1190,18 @@ pub enum PlaceType {
     Iv,
     #[serde(rename = "price")]
     Price,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<PlaceType, _>")]
+    Unrecognized(String),
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
 pub enum Side {
     Buy,
     Sell,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(untagged, deserialize_with = "unrecognized::<Side, _>")]
+    Unrecognized(String),
 }
 
 #[derive(Debug, PartialEq)]
@@ -881,18 +1216,118 @@ pub enum SlippageToleranceType {
     TickSize,
     Percent,
     UNKNOWN, // default
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    #[serde(
+        untagged,
+        deserialize_with = "unrecognized::<SlippageToleranceType, _>"
+    )]
+    Unrecognized(String),
 }
 
-#[derive(Serialize_repr, Deserialize_repr, Debug, PartialEq)]
-#[repr(u8)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(from = "Received<TradeMode>", into = "i64")]
 pub enum TradeMode {
-    CrossMargin = 0,
-    IsolatedMargin = 1,
+    CrossMargin,
+    IsolatedMargin,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    Unrecognized(i64),
+}
+
+impl From<i64> for TradeMode {
+    fn from(value: i64) -> Self {
+        match value {
+            0 => Self::CrossMargin,
+            1 => Self::IsolatedMargin,
+            _ => Self::Unrecognized(value),
+        }
+    }
+}
+
+impl From<Received<TradeMode>> for TradeMode {
+    fn from(received: Received<Self>) -> Self {
+        received.decode(Self::Unrecognized)
+    }
 }
 
-#[derive(Serialize_repr, Deserialize_repr, Debug, PartialEq)]
-#[repr(u8)]
+impl From<TradeMode> for i64 {
+    fn from(value: TradeMode) -> Self {
+        match value {
+            TradeMode::CrossMargin => 0,
+            TradeMode::IsolatedMargin => 1,
+            TradeMode::Unrecognized(value) => value,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(from = "Received<AutoAddMargin>", into = "i64")]
 pub enum AutoAddMargin {
-    False = 0,
-    True = 1,
+    False,
+    True,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    Unrecognized(i64),
+}
+
+impl From<i64> for AutoAddMargin {
+    fn from(value: i64) -> Self {
+        match value {
+            0 => Self::False,
+            1 => Self::True,
+            _ => Self::Unrecognized(value),
+        }
+    }
+}
+
+impl From<Received<AutoAddMargin>> for AutoAddMargin {
+    fn from(received: Received<Self>) -> Self {
+        received.decode(Self::Unrecognized)
+    }
+}
+
+impl From<AutoAddMargin> for i64 {
+    fn from(value: AutoAddMargin) -> Self {
+        match value {
+            AutoAddMargin::False => 0,
+            AutoAddMargin::True => 1,
+            AutoAddMargin::Unrecognized(value) => value,
+        }
+    }
+}
+
+/// Position mode of USDT perpetual and inverse futures.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(from = "Received<PositionMode>", into = "i64")]
+pub enum PositionMode {
+    /// 0: one-way mode
+    MergedSingle,
+    /// 3: hedge mode
+    BothSides,
+    /// Value unknown to this release, see [`UnrecognizedValue`](crate::UnrecognizedValue).
+    Unrecognized(i64),
+}
+
+impl From<i64> for PositionMode {
+    fn from(value: i64) -> Self {
+        match value {
+            0 => Self::MergedSingle,
+            3 => Self::BothSides,
+            _ => Self::Unrecognized(value),
+        }
+    }
+}
+
+impl From<Received<PositionMode>> for PositionMode {
+    fn from(received: Received<Self>) -> Self {
+        received.decode(Self::Unrecognized)
+    }
+}
+
+impl From<PositionMode> for i64 {
+    fn from(value: PositionMode) -> Self {
+        match value {
+            PositionMode::MergedSingle => 0,
+            PositionMode::BothSides => 3,
+            PositionMode::Unrecognized(value) => value,
+        }
+    }
 }
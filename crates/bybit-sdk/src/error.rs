@@ -0,0 +1,176 @@
+use std::fmt;
+use tokio_tungstenite::tungstenite::{self, protocol::CloseFrame};
+
+use crate::{OrderValidationError, TradeError};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// `retCode` values Bybit returns when a rate limit is exceeded.
+const RATE_LIMIT_RET_CODES: [i32; 2] = [10006, 10018];
+
+#[derive(Debug)]
+pub enum Error {
+    /// HTTP or websocket connection failed.
+    Transport(Box<dyn std::error::Error + Send + Sync>),
+    /// Recording file could not be read or written.
+    Io(std::io::Error),
+    /// Request could not be serialized.
+    Serialize(serde_json::Error),
+    /// Message could not be deserialized. `payload` is the raw message.
+    Deserialize {
+        source: serde_json::Error,
+        payload: String,
+    },
+    /// Bybit answered with `retCode != 0`.
+    Api { ret_code: i32, ret_msg: String },
+    /// Bybit answered with one of the "too many visits" codes.
+    RateLimited { ret_code: i32, ret_msg: String },
+    /// Order request rejected on the trade stream.
+    Rejected(TradeError),
+    /// Order request rejected locally by the instrument filters.
+    Validation(OrderValidationError),
+    /// Missing credentials or rejected `auth` request.
+    Auth(String),
+    /// No response in time.
+    Timeout(String),
+    /// Connection was closed, with the close frame when the server sent one.
+    Closed(Option<CloseFrame>),
+}
+
+impl Error {
+    /// Maps a `retCode != 0` response.
+    pub fn api(ret_code: i32, ret_msg: String) -> Self {
+        if RATE_LIMIT_RET_CODES.contains(&ret_code) {
+            Self::RateLimited { ret_code, ret_msg }
+        } else {
+            Self::Api { ret_code, ret_msg }
+        }
+    }
+
+    pub fn deserialize(source: serde_json::Error, payload: impl Into<String>) -> Self {
+        Self::Deserialize {
+            source,
+            payload: payload.into(),
+        }
+    }
+
+    /// `retCode` of API, rate limit and order rejection errors.
+    pub fn ret_code(&self) -> Option<i32> {
+        match self {
+            Self::Api { ret_code, .. } | Self::RateLimited { ret_code, .. } => Some(*ret_code),
+            Self::Rejected(error) => Some(error.ret_code),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Transport(e) => write!(f, "transport error: {e}"),
+            Self::Io(e) => write!(f, "I/O error: {e}"),
+            Self::Serialize(e) => write!(f, "serialize failed: {e}"),
+            Self::Deserialize { source, payload } => {
+                write!(f, "deserialize failed: {source}, payload: {payload}")
+            }
+            Self::Api { ret_code, ret_msg } => write!(f, "Bybit API error {ret_code}: {ret_msg}"),
+            Self::RateLimited { ret_code, ret_msg } => {
+                write!(f, "Bybit rate limit exceeded {ret_code}: {ret_msg}")
+            }
+            Self::Rejected(e) => write!(f, "{e}"),
+            Self::Validation(e) => write!(f, "invalid order: {e}"),
+            Self::Auth(message) => write!(f, "authentication failed: {message}"),
+            Self::Timeout(message) => write!(f, "timed out: {message}"),
+            Self::Closed(Some(frame)) => {
+                write!(
+                    f,
+                    "connection closed with code {}: {}",
+                    frame.code, frame.reason
+                )
+            }
+            Self::Closed(None) => write!(f, "connection closed"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Transport(e) => Some(e.as_ref()),
+            Self::Io(e) => Some(e),
+            Self::Serialize(e) => Some(e),
+            Self::Deserialize { source, .. } => Some(source),
+            Self::Rejected(e) => Some(e),
+            Self::Validation(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(e: reqwest::Error) -> Self {
+        Self::Transport(Box::new(e))
+    }
+}
+
+impl From<tungstenite::Error> for Error {
+    fn from(e: tungstenite::Error) -> Self {
+        match e {
+            tungstenite::Error::ConnectionClosed | tungstenite::Error::AlreadyClosed => {
+                Self::Closed(None)
+            }
+            e => Self::Transport(Box::new(e)),
+        }
+    }
+}
+
+impl From<reqwest::header::InvalidHeaderValue> for Error {
+    fn from(e: reqwest::header::InvalidHeaderValue) -> Self {
+        Self::Transport(Box::new(e))
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<TradeError> for Error {
+    fn from(e: TradeError) -> Self {
+        Self::Rejected(e)
+    }
+}
+
+impl From<OrderValidationError> for Error {
+    fn from(e: OrderValidationError) -> Self {
+        Self::Validation(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_error_rate_limited() {
+        let error = Error::api(10006, String::from("Too many visits!"));
+        assert!(matches!(
+            error,
+            Error::RateLimited {
+                ret_code: 10006,
+                ..
+            }
+        ));
+        assert_eq!(error.ret_code(), Some(10006));
+
+        let error = Error::api(10001, String::from("params error"));
+        assert!(matches!(
+            error,
+            Error::Api {
+                ret_code: 10001,
+                ..
+            }
+        ));
+    }
+}
@@ -1,26 +1,68 @@
-use serde::Deserialize;
+use serde::{de, Deserialize, Deserializer};
 use serde_aux::prelude::{
-    deserialize_number_from_string as number,
+    deserialize_bool_from_anything as bool_from_anything, deserialize_number_from_string as number,
     deserialize_option_number_from_string as option_number,
 };
 
 use crate::{
-    AutoAddMargin, CancelType, Category, CreateType, Interval, OcoTriggerBy, OrderStatus,
-    OrderType, PlaceType, PositionIdx, PositionStatus, RejectReason, Side, SlippageToleranceType,
-    SmpType, StopOrderType, TickDirection, TimeInForce, TpslMode, TradeMode, TriggerBy,
-    TriggerDirection,
+    common::deserialize_slice, AccountType, AutoAddMargin, CancelType, Category, CreateType, Error,
+    ExecType, Interval, Number, OcoTriggerBy, OrderStatus, OrderType, PlaceType, PositionIdx,
+    PositionStatus, RejectReason, Side, SlippageToleranceType, SmpType, StopOrderType,
+    TickDirection, TimeInForce, Topic, TpslMode, TradeMode, TriggerBy, TriggerDirection,
 };
 
-#[derive(PartialEq, Deserialize, Debug)]
+#[derive(Deserialize, Debug)]
 #[serde(untagged)]
 pub enum IncomingMessage {
     Command(CommandMsg),
     Ticker(Box<TickerMsg>),
+    OptionTicker(Box<OptionTickerMsg>),
+    OrderBook(OrderBookMsg),
     Trade(TradeMsg),
     KLine(KLineMsg),
     AllLiquidation(AllLiquidationMsg),
     Order(OrderMsg),
     Position(PositionMsg),
+    Execution(ExecutionMsg),
+    Wallet(WalletMsg),
+    Greeks(GreeksMsg),
+    /// Problem passed on by [`stream_async`](crate::stream_async) instead of being dropped:
+    /// a text frame matching none of the messages above ([`Error::Deserialize`]), or the
+    /// close frame ([`Error::Closed`]) or receive failure ([`Error::Transport`]) that ended
+    /// the stream. Never deserialized.
+    #[serde(skip)]
+    Error(Error),
+}
+
+impl IncomingMessage {
+    /// Deserializes a text frame, falling back to [`IncomingMessage::Error`].
+    pub(crate) fn decode(frame: &str) -> Self {
+        deserialize_slice(frame.as_bytes()).unwrap_or_else(|e| {
+            tracing::warn!(payload = %frame, "Deserialize IncomingMessage failed: {e}");
+            Self::Error(Error::deserialize(e, frame))
+        })
+    }
+}
+
+// Not derived since `Error` cannot be compared: two errors are never equal.
+impl PartialEq for IncomingMessage {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Command(a), Self::Command(b)) => a == b,
+            (Self::Ticker(a), Self::Ticker(b)) => a == b,
+            (Self::OptionTicker(a), Self::OptionTicker(b)) => a == b,
+            (Self::OrderBook(a), Self::OrderBook(b)) => a == b,
+            (Self::Trade(a), Self::Trade(b)) => a == b,
+            (Self::KLine(a), Self::KLine(b)) => a == b,
+            (Self::AllLiquidation(a), Self::AllLiquidation(b)) => a == b,
+            (Self::Order(a), Self::Order(b)) => a == b,
+            (Self::Position(a), Self::Position(b)) => a == b,
+            (Self::Execution(a), Self::Execution(b)) => a == b,
+            (Self::Wallet(a), Self::Wallet(b)) => a == b,
+            (Self::Greeks(a), Self::Greeks(b)) => a == b,
+            _ => false,
+        }
+    }
 }
 
 #[derive(PartialEq, Deserialize, Debug)]
@@ -70,7 +112,7 @@ pub enum CommandMsg {
 pub enum TickerMsg {
     #[serde(rename = "snapshot")]
     Snapshot {
-        topic: String,
+        topic: Topic,
         #[serde(default, deserialize_with = "option_number")]
         cs: Option<u64>,
         ts: u64,
@@ -78,7 +120,7 @@ pub enum TickerMsg {
     },
     #[serde(rename = "delta")]
     Delta {
-        topic: String,
+        topic: Topic,
         #[serde(default, deserialize_with = "option_number")]
         cs: Option<u64>,
         ts: u64,
@@ -86,60 +128,60 @@ pub enum TickerMsg {
     },
 }
 
-#[derive(PartialEq, Deserialize, Debug)]
+#[derive(PartialEq, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct TickerSnapshotMsg {
     pub symbol: String,
     pub tick_direction: TickDirection,
     #[serde(deserialize_with = "number")]
-    pub last_price: f64,
+    pub last_price: Number,
     #[serde(default, deserialize_with = "option_number")]
-    pub pre_open_price: Option<f64>,
+    pub pre_open_price: Option<Number>,
     #[serde(default, deserialize_with = "option_number")]
-    pub pre_qty: Option<f64>,
+    pub pre_qty: Option<Number>,
     pub cur_pre_listing_phase: Option<String>,
     #[serde(deserialize_with = "number")]
-    pub prev_price24h: f64,
+    pub prev_price24h: Number,
     #[serde(deserialize_with = "number")]
-    pub price24h_pcnt: f64,
+    pub price24h_pcnt: Number,
     #[serde(deserialize_with = "number")]
-    pub high_price24h: f64,
+    pub high_price24h: Number,
     #[serde(deserialize_with = "number")]
-    pub low_price24h: f64,
+    pub low_price24h: Number,
     #[serde(deserialize_with = "number")]
-    pub prev_price1h: f64,
+    pub prev_price1h: Number,
     #[serde(deserialize_with = "number")]
-    pub mark_price: f64,
+    pub mark_price: Number,
     #[serde(deserialize_with = "number")]
-    pub index_price: f64,
+    pub index_price: Number,
     #[serde(deserialize_with = "number")]
-    pub open_interest: f64,
+    pub open_interest: Number,
     #[serde(deserialize_with = "number")]
-    pub open_interest_value: f64,
+    pub open_interest_value: Number,
     #[serde(deserialize_with = "number")]
-    pub turnover24h: f64,
+    pub turnover24h: Number,
     #[serde(deserialize_with = "number")]
-    pub volume24h: f64,
+    pub volume24h: Number,
     #[serde(deserialize_with = "number")]
-    pub funding_rate: f64,
+    pub funding_rate: Number,
     #[serde(deserialize_with = "number")]
     pub next_funding_time: u64,
     #[serde(deserialize_with = "number")]
-    pub bid1_price: f64,
+    pub bid1_price: Number,
     #[serde(deserialize_with = "number")]
-    pub bid1_size: f64,
+    pub bid1_size: Number,
     #[serde(deserialize_with = "number")]
-    pub ask1_price: f64,
+    pub ask1_price: Number,
     #[serde(deserialize_with = "number")]
-    pub ask1_size: f64,
+    pub ask1_size: Number,
     #[serde(default, deserialize_with = "option_number")]
     pub delivery_time: Option<u64>,
     #[serde(default, deserialize_with = "option_number")]
-    pub basis_rate: Option<f64>,
+    pub basis_rate: Option<Number>,
     #[serde(default, deserialize_with = "option_number")]
-    pub delivery_fee_rate: Option<f64>,
+    pub delivery_fee_rate: Option<Number>,
     #[serde(default, deserialize_with = "option_number")]
-    pub predicted_delivery_price: Option<f64>,
+    pub predicted_delivery_price: Option<Number>,
 }
 
 #[derive(PartialEq, Deserialize, Debug)]
@@ -148,63 +190,172 @@ pub struct TickerDeltaMsg {
     pub symbol: String,
     pub tick_direction: Option<TickDirection>,
     #[serde(default, deserialize_with = "option_number")]
-    pub last_price: Option<f64>,
+    pub last_price: Option<Number>,
     #[serde(default, deserialize_with = "option_number")]
-    pub pre_open_price: Option<f64>,
+    pub pre_open_price: Option<Number>,
     #[serde(default, deserialize_with = "option_number")]
-    pub pre_qty: Option<f64>,
+    pub pre_qty: Option<Number>,
     pub cur_pre_listing_phase: Option<String>,
     #[serde(default, deserialize_with = "option_number")]
-    pub prev_price24h: Option<f64>,
+    pub prev_price24h: Option<Number>,
     #[serde(default, deserialize_with = "option_number")]
-    pub price24h_pcnt: Option<f64>,
+    pub price24h_pcnt: Option<Number>,
     #[serde(default, deserialize_with = "option_number")]
-    pub high_price24h: Option<f64>,
+    pub high_price24h: Option<Number>,
     #[serde(default, deserialize_with = "option_number")]
-    pub low_price24h: Option<f64>,
+    pub low_price24h: Option<Number>,
     #[serde(default, deserialize_with = "option_number")]
-    pub prev_price1h: Option<f64>,
+    pub prev_price1h: Option<Number>,
     #[serde(default, deserialize_with = "option_number")]
-    pub mark_price: Option<f64>,
+    pub mark_price: Option<Number>,
     #[serde(default, deserialize_with = "option_number")]
-    pub index_price: Option<f64>,
+    pub index_price: Option<Number>,
     #[serde(default, deserialize_with = "option_number")]
-    pub open_interest: Option<f64>,
+    pub open_interest: Option<Number>,
     #[serde(default, deserialize_with = "option_number")]
-    pub open_interest_value: Option<f64>,
+    pub open_interest_value: Option<Number>,
     #[serde(default, deserialize_with = "option_number")]
-    pub turnover24h: Option<f64>,
+    pub turnover24h: Option<Number>,
     #[serde(default, deserialize_with = "option_number")]
-    pub volume24h: Option<f64>,
+    pub volume24h: Option<Number>,
     #[serde(default, deserialize_with = "option_number")]
-    pub funding_rate: Option<f64>,
+    pub funding_rate: Option<Number>,
     #[serde(default, deserialize_with = "option_number")]
     pub next_funding_time: Option<u64>,
     #[serde(default, deserialize_with = "option_number")]
-    pub bid1_price: Option<f64>,
+    pub bid1_price: Option<Number>,
     #[serde(default, deserialize_with = "option_number")]
-    pub bid1_size: Option<f64>,
+    pub bid1_size: Option<Number>,
     #[serde(default, deserialize_with = "option_number")]
-    pub ask1_price: Option<f64>,
+    pub ask1_price: Option<Number>,
     #[serde(default, deserialize_with = "option_number")]
-    pub ask1_size: Option<f64>,
+    pub ask1_size: Option<Number>,
     #[serde(default, deserialize_with = "option_number")]
     pub delivery_time: Option<u64>,
     #[serde(default, deserialize_with = "option_number")]
-    pub basis_rate: Option<f64>,
+    pub basis_rate: Option<Number>,
     #[serde(default, deserialize_with = "option_number")]
-    pub delivery_fee_rate: Option<f64>,
+    pub delivery_fee_rate: Option<Number>,
     #[serde(default, deserialize_with = "option_number")]
-    pub predicted_delivery_price: Option<f64>,
+    pub predicted_delivery_price: Option<Number>,
+}
+
+/// Option tickers are always snapshots.
+#[derive(PartialEq, Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum OptionTickerMsg {
+    #[serde(rename = "snapshot")]
+    Snapshot {
+        topic: Topic,
+        ts: u64,
+        data: OptionTickerSnapshotMsg,
+    },
+}
+
+#[derive(PartialEq, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionTickerSnapshotMsg {
+    pub symbol: String,
+    #[serde(deserialize_with = "number")]
+    pub bid_price: Number,
+    #[serde(deserialize_with = "number")]
+    pub bid_size: Number,
+    #[serde(deserialize_with = "number")]
+    pub bid_iv: Number,
+    #[serde(deserialize_with = "number")]
+    pub ask_price: Number,
+    #[serde(deserialize_with = "number")]
+    pub ask_size: Number,
+    #[serde(deserialize_with = "number")]
+    pub ask_iv: Number,
+    #[serde(deserialize_with = "number")]
+    pub last_price: Number,
+    #[serde(deserialize_with = "number")]
+    pub high_price24h: Number,
+    #[serde(deserialize_with = "number")]
+    pub low_price24h: Number,
+    #[serde(deserialize_with = "number")]
+    pub mark_price: Number,
+    #[serde(deserialize_with = "number")]
+    pub index_price: Number,
+    #[serde(deserialize_with = "number")]
+    pub mark_price_iv: Number,
+    #[serde(deserialize_with = "number")]
+    pub underlying_price: Number,
+    #[serde(deserialize_with = "number")]
+    pub open_interest: Number,
+    #[serde(deserialize_with = "number")]
+    pub turnover24h: Number,
+    #[serde(deserialize_with = "number")]
+    pub volume24h: Number,
+    #[serde(deserialize_with = "number")]
+    pub total_volume: Number,
+    #[serde(deserialize_with = "number")]
+    pub total_turnover: Number,
+    #[serde(deserialize_with = "number")]
+    pub delta: Number,
+    #[serde(deserialize_with = "number")]
+    pub gamma: Number,
+    #[serde(deserialize_with = "number")]
+    pub vega: Number,
+    #[serde(deserialize_with = "number")]
+    pub theta: Number,
+    #[serde(deserialize_with = "number")]
+    pub predicted_delivery_price: Number,
+    #[serde(deserialize_with = "number")]
+    pub change24h: Number,
 }
 
+#[derive(PartialEq, Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum OrderBookMsg {
+    #[serde(rename = "snapshot")]
+    Snapshot {
+        topic: Topic,
+        ts: u64,
+        data: OrderBookDataMsg,
+        cts: Option<u64>,
+    },
+    #[serde(rename = "delta")]
+    Delta {
+        topic: Topic,
+        ts: u64,
+        data: OrderBookDataMsg,
+        cts: Option<u64>,
+    },
+}
+
+#[derive(PartialEq, Deserialize, Debug)]
+pub struct OrderBookDataMsg {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// Bids. For snapshot stream, the element is sorted by price in descending order
+    #[serde(rename = "b")]
+    pub bids: Vec<OrderBookLevelMsg>,
+    /// Asks. For snapshot stream, the element is sorted by price in ascending order
+    #[serde(rename = "a")]
+    pub asks: Vec<OrderBookLevelMsg>,
+    /// Update ID. Occasionally, you'll receive "u"=1, which is a snapshot data due to the restart of the service
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    /// Cross sequence
+    pub seq: u64,
+}
+
+/// Price and size. The delta data has size=0, which means that all quotations for this price have been filled or cancelled
+#[derive(PartialEq, Deserialize, Debug)]
+pub struct OrderBookLevelMsg(
+    #[serde(deserialize_with = "number")] pub Number,
+    #[serde(deserialize_with = "number")] pub Number,
+);
+
 #[derive(PartialEq, Deserialize, Debug)]
 #[serde(tag = "type")]
 pub enum TradeMsg {
     #[serde(rename = "snapshot")]
     Snapshot {
         id: Option<String>,
-        topic: String,
+        topic: Topic,
         ts: u64,
         data: Vec<TradeSnapshotMsg>,
     },
@@ -219,9 +370,9 @@ pub struct TradeSnapshotMsg {
     #[serde(rename = "S")]
     pub side: Side,
     #[serde(rename = "v", deserialize_with = "number")]
-    pub size: f64,
+    pub size: Number,
     #[serde(rename = "p", deserialize_with = "number")]
-    pub price: f64,
+    pub price: Number,
     #[serde(rename = "L")]
     pub tick_direction: TickDirection,
     #[serde(rename = "i")]
@@ -245,7 +396,7 @@ pub struct TradeSnapshotMsg {
 pub enum KLineMsg {
     #[serde(rename = "snapshot")]
     Snapshot {
-        topic: String,
+        topic: Topic,
         ts: u64,
         data: Vec<KLineSnapshotMsg>,
     },
@@ -257,17 +408,17 @@ pub struct KLineSnapshotMsg {
     pub end: u64,
     pub interval: Interval,
     #[serde(deserialize_with = "number")]
-    pub open: f64,
+    pub open: Number,
     #[serde(deserialize_with = "number")]
-    pub close: f64,
+    pub close: Number,
     #[serde(deserialize_with = "number")]
-    pub high: f64,
+    pub high: Number,
     #[serde(deserialize_with = "number")]
-    pub low: f64,
+    pub low: Number,
     #[serde(deserialize_with = "number")]
-    pub volume: f64,
+    pub volume: Number,
     #[serde(deserialize_with = "number")]
-    pub turnover: f64,
+    pub turnover: Number,
     pub confirm: bool,
     pub timestamp: u64,
 }
@@ -277,7 +428,7 @@ pub struct KLineSnapshotMsg {
 pub enum AllLiquidationMsg {
     #[serde(rename = "snapshot")]
     Snapshot {
-        topic: String,
+        topic: Topic,
         ts: u64,
         data: Vec<AllLiquidationSnapshotMsg>,
     },
@@ -292,20 +443,22 @@ pub struct AllLiquidationSnapshotMsg {
     #[serde(rename = "S")]
     pub side: Side, // When you receive a Buy update, this means that a long position has been liquidated
     #[serde(rename = "v", deserialize_with = "number")]
-    pub size: f64,
+    pub size: Number,
     #[serde(rename = "p", deserialize_with = "number")]
-    pub price: f64,
+    pub price: Number,
 }
 
 #[derive(PartialEq, Deserialize, Debug)]
-#[serde(tag = "topic")]
-pub enum OrderMsg {
-    #[serde(rename = "order", rename_all = "camelCase")]
-    Update {
-        id: String,
-        creation_time: u64,
-        data: Vec<OrderUpdateMsg>,
-    },
+#[serde(rename_all = "camelCase")]
+pub struct OrderMsg {
+    /// Message ID
+    pub id: String,
+    /// Topic name, `order` or `order.{category}`
+    #[serde(deserialize_with = "order_topic")]
+    pub topic: Topic,
+    /// Data created timestamp (ms)
+    pub creation_time: u64,
+    pub data: Vec<OrderUpdateMsg>,
 }
 
 #[derive(PartialEq, Deserialize, Debug)]
@@ -329,10 +482,10 @@ pub struct OrderUpdateMsg {
     pub symbol: String,
     /// Order price
     #[serde(deserialize_with = "number")]
-    pub price: f64,
+    pub price: Number,
     /// Order qty
     #[serde(deserialize_with = "number")]
-    pub qty: f64,
+    pub qty: Number,
     /// Side. Buy,Sell
     pub side: Side,
     /// Position index. Used to identify positions in different position modes.
@@ -351,30 +504,30 @@ pub struct OrderUpdateMsg {
     /// returns "" for those orders without avg price, and also for those classic account orders have partilly filled but cancelled at the end
     /// Classic Spot: not supported, always ""
     #[serde(deserialize_with = "number")]
-    pub avg_price: f64,
+    pub avg_price: Number,
     /// The remaining qty not executed. Classic spot is not supported
     #[serde(deserialize_with = "option_number")]
-    pub leaves_qty: Option<f64>,
+    pub leaves_qty: Option<Number>,
     /// The estimated value not executed. Classic spot is not supported
     #[serde(deserialize_with = "option_number")]
-    pub leaves_value: Option<f64>,
+    pub leaves_value: Option<Number>,
     /// Cumulative executed order qty
     #[serde(deserialize_with = "number")]
-    pub cum_exec_qty: f64,
+    pub cum_exec_qty: Number,
     /// Cumulative executed order value.
     #[serde(deserialize_with = "number")]
-    pub cum_exec_value: f64,
+    pub cum_exec_value: Number,
     /// Cumulative executed trading fee.
     /// Classic spot: it is the latest execution fee for order.
     /// After upgraded to the Unified account, you can use execFee for each fill in Execution topic
     #[serde(deserialize_with = "number")]
-    pub cum_exec_fee: f64,
+    pub cum_exec_fee: Number,
     /// Closed profit and loss for each close position order. The figure is the same as "closedPnl" from Get Closed PnL
     #[serde(deserialize_with = "number")]
-    pub closed_pnl: f64,
+    pub closed_pnl: Number,
     /// Trading fee currency for Spot only. Please understand Spot trading fee currency here
     #[serde(deserialize_with = "option_number")]
-    pub fee_currency: Option<f64>,
+    pub fee_currency: Option<Number>,
     /// Time in force
     pub time_in_force: TimeInForce,
     /// Order type. Market,Limit. For TP/SL order, it means the order type after triggered
@@ -385,30 +538,30 @@ pub struct OrderUpdateMsg {
     pub oco_trigger_by: Option<OcoTriggerBy>,
     /// Implied volatility
     #[serde(deserialize_with = "option_number")]
-    pub order_iv: Option<f64>,
+    pub order_iv: Option<Number>,
     /// The unit for qty when create Spot market orders for UTA account. baseCoin, quoteCoin
     pub market_unit: Option<String>,
     /// Spot and Futures market order slippage tolerance type TickSize, Percent, UNKNOWN(default)
     pub slippage_tolerance_type: Option<SlippageToleranceType>,
     /// Slippage tolerance value
-    pub slippage_tolerance: Option<String>, // TODO: parse Option<f64> from "{}"
+    pub slippage_tolerance: Option<String>, // TODO: parse Option<Number> from "{}"
     /// Trigger price. If stopOrderType=TrailingStop, it is activate price. Otherwise, it is trigger price
     #[serde(deserialize_with = "option_number")]
-    pub trigger_price: Option<f64>,
+    pub trigger_price: Option<Number>,
     /// Take profit price
     #[serde(deserialize_with = "option_number")]
-    pub take_profit: Option<f64>,
+    pub take_profit: Option<Number>,
     /// Stop loss price
     #[serde(deserialize_with = "option_number")]
-    pub stop_loss: Option<f64>,
+    pub stop_loss: Option<Number>,
     /// TP/SL mode, Full: entire position for TP/SL. Partial: partial position tp/sl. Spot does not have this field, and Option returns always ""
     pub tpsl_mode: TpslMode,
     /// The limit order price when take profit price is triggered
     #[serde(deserialize_with = "option_number")]
-    pub tp_limit_price: Option<f64>,
+    pub tp_limit_price: Option<Number>,
     /// The limit order price when stop loss price is triggered
     #[serde(deserialize_with = "option_number")]
-    pub sl_limit_price: Option<f64>,
+    pub sl_limit_price: Option<Number>,
     /// The price type to trigger take profit
     pub tp_trigger_by: Option<TriggerBy>,
     /// The price type to trigger stop loss
@@ -419,7 +572,7 @@ pub struct OrderUpdateMsg {
     pub trigger_by: Option<TriggerBy>,
     /// Last price when place the order, Spot is not applicable
     #[serde(deserialize_with = "option_number")]
-    pub last_price_on_created: Option<f64>,
+    pub last_price_on_created: Option<Number>,
     /// Reduce only. true means reduce position size
     pub reduce_only: bool,
     /// Close on trigger.
@@ -445,12 +598,13 @@ pub struct OrderUpdateMsg {
 #[serde(rename_all = "camelCase")]
 pub struct PositionMsg {
     /// Message ID
-    id: String,
-    /// Topic name
-    topic: String,
+    pub id: String,
+    /// Topic name, `position` or `position.{category}`
+    #[serde(deserialize_with = "position_topic")]
+    pub topic: Topic,
     /// Data created timestamp (ms)
-    creation_time: u64,
-    data: Vec<PositionUpdateMsg>,
+    pub creation_time: u64,
+    pub data: Vec<PositionUpdateMsg>,
 }
 
 #[derive(PartialEq, Deserialize, Debug)]
@@ -466,7 +620,7 @@ pub struct PositionUpdateMsg {
     // pub side: Option<Side>,
     /// Position size
     #[serde(deserialize_with = "number")]
-    pub size: f64,
+    pub size: Number,
     /// Used to identify positions in different position modes
     pub position_idx: PositionIdx,
     /// Trade mode
@@ -475,7 +629,7 @@ pub struct PositionUpdateMsg {
     pub trade_mode: TradeMode,
     /// Position value
     #[serde(deserialize_with = "number")]
-    pub position_value: f64,
+    pub position_value: Number,
     /// Risk tier ID
     /// for portfolio margin mode, this field returns 0, which means risk limit rules are invalid
     #[serde(deserialize_with = "number")]
@@ -483,63 +637,63 @@ pub struct PositionUpdateMsg {
     /// Risk limit value
     /// for portfolio margin mode, this field returns 0, which means risk limit rules are invalid
     #[serde(deserialize_with = "number")]
-    pub risk_limit_value: f64,
+    pub risk_limit_value: Number,
     /// Entry price
     #[serde(deserialize_with = "number")]
-    pub entry_price: f64,
+    pub entry_price: Number,
     /// Mark price
     #[serde(deserialize_with = "number")]
-    pub mark_price: f64,
+    pub mark_price: Number,
     /// Position leverage
     /// for portfolio margin mode, this field returns "", which means leverage rules are invalid
     #[serde(deserialize_with = "number")]
-    pub leverage: f64,
+    pub leverage: Number,
     /// Position margin
     /// Classic & UTA1.0(inverse) can refer to this field to get the position initial margin
     #[serde(deserialize_with = "number")]
-    pub position_balance: f64,
+    pub position_balance: Number,
     /// Whether to add margin automatically. 0: false, 1: true. For UTA, it is meaningful only when UTA enables ISOLATED_MARGIN
     pub auto_add_margin: AutoAddMargin,
     /// Initial margin
     /// Classic & UTA1.0(inverse): ignore this field
     /// UTA portfolio margin mode, it returns ""
     #[serde(deserialize_with = "number")]
-    pub position_i_m: f64,
+    pub position_i_m: Number,
     /// Maintenance margin
     /// Classic & UTA1.0(inverse): ignore this field
     /// UTA portfolio margin mode, it returns ""
     #[serde(deserialize_with = "number")]
-    pub position_m_m: f64,
+    pub position_m_m: Number,
     /// Position liquidation price
     /// UTA1.0(inverse) & UTA(isolated margin enabled) & Classic account: it is the real price for isolated and cross positions, and keeps "" when liqPrice <= minPrice or liqPrice >= maxPrice
     /// UTA (Cross margin mode): it is an estimated price for cross positions(because the unified mode controls the risk rate according to the account), and keeps "" when liqPrice <= minPrice or liqPrice >= maxPrice
     /// However, this field is empty for Portfolio Margin Mode, and no liquidation price will be provided
     #[serde(deserialize_with = "number")]
-    pub liq_price: f64,
+    pub liq_price: Number,
     /// Bankruptcy price
     /// Unified mode returns "", no position bankruptcy price (except UTA1.0(inverse))
     #[serde(deserialize_with = "option_number")]
-    pub bust_price: Option<f64>,
+    pub bust_price: Option<Number>,
     /// deprecated, meaningless here, always "Full"
     pub tpsl_mode: TpslMode,
     /// Take profit price
     #[serde(deserialize_with = "number")]
-    pub take_profit: f64,
+    pub take_profit: Number,
     /// Stop loss price
     #[serde(deserialize_with = "number")]
-    pub stop_loss: f64,
+    pub stop_loss: Number,
     /// Trailing stop
     #[serde(deserialize_with = "number")]
-    pub trailing_stop: f64,
+    pub trailing_stop: Number,
     /// Unrealised profit and loss
     // #[serde(deserialize_with = "option_number")]
-    // pub unrealized_pnl: Option<f64>,
+    // pub unrealized_pnl: Option<Number>,
     /// The realised PnL for the current holding position
     #[serde(deserialize_with = "number")]
-    pub cur_realised_pnl: f64,
+    pub cur_realised_pnl: Number,
     /// USDC contract session avg price, it is the same figure as avg entry price shown in the web UI
     #[serde(deserialize_with = "number")]
-    pub session_avg_price: f64,
+    pub session_avg_price: Number,
     /// Delta
     pub delta: Option<String>,
     /// Gamma
@@ -552,7 +706,7 @@ pub struct PositionUpdateMsg {
     /// Futures & Perp: it is the all time cumulative realised P&L
     /// Option: it is the realised P&L when you hold that position
     #[serde(deserialize_with = "number")]
-    pub cum_realised_pnl: f64,
+    pub cum_realised_pnl: Number,
     /// Position status. Normal, Liq, Adl
     pub position_status: PositionStatus,
     /// Auto-deleverage rank indicator. What is Auto-Deleveraging?
@@ -592,9 +746,281 @@ pub struct PositionUpdateMsg {
     pub seq: i64,
 }
 
+#[derive(PartialEq, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionMsg {
+    /// Message ID
+    pub id: String,
+    /// Topic name, `execution` or `execution.{category}`
+    #[serde(deserialize_with = "execution_topic")]
+    pub topic: Topic,
+    /// Data created timestamp (ms)
+    pub creation_time: u64,
+    pub data: Vec<ExecutionUpdateMsg>,
+}
+
+#[derive(PartialEq, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionUpdateMsg {
+    /// Product type
+    pub category: Category,
+    /// Symbol name
+    pub symbol: String,
+    /// Whether to borrow. Valid for Unified spot only. 0: false, 1: true
+    #[serde(deserialize_with = "bool_from_anything")]
+    pub is_leverage: bool,
+    /// Order ID
+    pub order_id: String,
+    /// User customized order ID
+    pub order_link_id: String,
+    /// Side. Buy,Sell
+    pub side: Side,
+    /// Order price
+    #[serde(deserialize_with = "number")]
+    pub order_price: Number,
+    /// Order qty
+    #[serde(deserialize_with = "number")]
+    pub order_qty: Number,
+    /// The remaining qty not executed
+    #[serde(deserialize_with = "number")]
+    pub leaves_qty: Number,
+    /// Order create type
+    /// Spot, Option do not have this key
+    pub create_type: Option<CreateType>,
+    /// Order type. Market,Limit
+    pub order_type: OrderType,
+    /// Stop order type. If the order is not stop order, any type is not returned
+    pub stop_order_type: StopOrderType,
+    /// Executed trading fee
+    #[serde(deserialize_with = "number")]
+    pub exec_fee: Number,
+    /// Execution ID
+    pub exec_id: String,
+    /// Execution price
+    #[serde(deserialize_with = "number")]
+    pub exec_price: Number,
+    /// Execution qty
+    #[serde(deserialize_with = "number")]
+    pub exec_qty: Number,
+    /// Executed type
+    pub exec_type: ExecType,
+    /// Executed order value
+    #[serde(deserialize_with = "number")]
+    pub exec_value: Number,
+    /// Executed timestamp (ms)
+    #[serde(deserialize_with = "number")]
+    pub exec_time: u64,
+    /// Is maker order. true: maker, false: taker
+    pub is_maker: bool,
+    /// Trading fee rate
+    #[serde(deserialize_with = "option_number")]
+    pub fee_rate: Option<Number>,
+    /// Implied volatility. Valid for option
+    #[serde(deserialize_with = "option_number")]
+    pub trade_iv: Option<Number>,
+    /// Implied volatility of mark price. Valid for option
+    #[serde(deserialize_with = "option_number")]
+    pub mark_iv: Option<Number>,
+    /// The mark price of the symbol when executing
+    #[serde(deserialize_with = "option_number")]
+    pub mark_price: Option<Number>,
+    /// The index price of the symbol when executing. Valid for option only
+    #[serde(deserialize_with = "option_number")]
+    pub index_price: Option<Number>,
+    /// The underlying price of the symbol when executing. Valid for option
+    #[serde(deserialize_with = "option_number")]
+    pub underlying_price: Option<Number>,
+    /// Paradigm block trade ID
+    pub block_trade_id: Option<String>,
+    /// Closed position size
+    #[serde(deserialize_with = "option_number")]
+    pub closed_size: Option<Number>,
+    /// Cross sequence, used to associate each fill and each position update
+    pub seq: i64,
+    /// The unit for qty when create Spot market orders for UTA account. baseCoin, quoteCoin
+    pub market_unit: Option<String>,
+}
+
+#[derive(PartialEq, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletMsg {
+    /// Message ID
+    pub id: String,
+    /// Topic name, `wallet`
+    #[serde(deserialize_with = "wallet_topic")]
+    pub topic: Topic,
+    /// Data created timestamp (ms)
+    pub creation_time: u64,
+    pub data: Vec<WalletUpdateMsg>,
+}
+
+#[derive(PartialEq, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletUpdateMsg {
+    /// Account type
+    pub account_type: AccountType,
+    /// Account IM rate
+    #[serde(rename = "accountIMRate", deserialize_with = "option_number")]
+    pub account_im_rate: Option<Number>,
+    /// Account MM rate
+    #[serde(rename = "accountMMRate", deserialize_with = "option_number")]
+    pub account_mm_rate: Option<Number>,
+    /// Account LTV: account total borrowed size / (account total equity + account total borrowed size)
+    #[serde(rename = "accountLTV", deserialize_with = "option_number")]
+    pub account_ltv: Option<Number>,
+    /// Equity of account converted to usd
+    #[serde(deserialize_with = "option_number")]
+    pub total_equity: Option<Number>,
+    /// Wallet Balance of account converted to usd
+    #[serde(deserialize_with = "option_number")]
+    pub total_wallet_balance: Option<Number>,
+    /// Margin Balance of account converted to usd
+    #[serde(deserialize_with = "option_number")]
+    pub total_margin_balance: Option<Number>,
+    /// Available Balance of account converted to usd
+    #[serde(deserialize_with = "option_number")]
+    pub total_available_balance: Option<Number>,
+    /// Unrealised P&L of perpetuals and futures of account converted to usd
+    #[serde(rename = "totalPerpUPL", deserialize_with = "option_number")]
+    pub total_perp_upl: Option<Number>,
+    /// Initial Margin of account converted to usd
+    #[serde(deserialize_with = "option_number")]
+    pub total_initial_margin: Option<Number>,
+    /// Maintenance Margin of account converted to usd
+    #[serde(deserialize_with = "option_number")]
+    pub total_maintenance_margin: Option<Number>,
+    /// Coin
+    pub coin: Vec<WalletCoinMsg>,
+}
+
+#[derive(PartialEq, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletCoinMsg {
+    /// Coin name, such as BTC, ETH, USDT, USDC
+    pub coin: String,
+    /// Equity of coin
+    #[serde(deserialize_with = "number")]
+    pub equity: Number,
+    /// USD value of coin
+    #[serde(deserialize_with = "option_number")]
+    pub usd_value: Option<Number>,
+    /// Wallet balance of coin
+    #[serde(deserialize_with = "number")]
+    pub wallet_balance: Number,
+    /// Available amount to withdraw of current coin
+    #[serde(default, deserialize_with = "option_number")]
+    pub available_to_withdraw: Option<Number>,
+    /// Available amount to borrow of current coin
+    #[serde(default, deserialize_with = "option_number")]
+    pub available_to_borrow: Option<Number>,
+    /// Borrow amount of current coin
+    #[serde(deserialize_with = "option_number")]
+    pub borrow_amount: Option<Number>,
+    /// Accrued interest
+    #[serde(deserialize_with = "option_number")]
+    pub accrued_interest: Option<Number>,
+    /// Pre-occupied margin for order
+    #[serde(rename = "totalOrderIM", deserialize_with = "option_number")]
+    pub total_order_im: Option<Number>,
+    /// Sum of initial margin of all positions + Pre-occupied liquidation fee
+    #[serde(rename = "totalPositionIM", deserialize_with = "option_number")]
+    pub total_position_im: Option<Number>,
+    /// Sum of maintenance margin for all positions
+    #[serde(rename = "totalPositionMM", deserialize_with = "option_number")]
+    pub total_position_mm: Option<Number>,
+    /// Unrealised P&L
+    #[serde(deserialize_with = "option_number")]
+    pub unrealised_pnl: Option<Number>,
+    /// Cumulative Realised P&L
+    #[serde(deserialize_with = "option_number")]
+    pub cum_realised_pnl: Option<Number>,
+    /// Bonus. This is a unique field for UNIFIED account
+    #[serde(deserialize_with = "option_number")]
+    pub bonus: Option<Number>,
+    /// Whether it can be used as a margin collateral currency (platform)
+    pub margin_collateral: bool,
+    /// Whether the collateral is turned on by user (user)
+    pub collateral_switch: bool,
+    /// Locked balance due to the Spot open order
+    #[serde(deserialize_with = "option_number")]
+    pub locked: Option<Number>,
+    /// The spot asset qty that is used to hedge in the portfolio margin, truncate to 8 decimals and "0" by default
+    #[serde(default, deserialize_with = "option_number")]
+    pub spot_hedging_qty: Option<Number>,
+}
+
+#[derive(PartialEq, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GreeksMsg {
+    /// Message ID
+    pub id: String,
+    /// Topic name, `greeks`
+    #[serde(deserialize_with = "greeks_topic")]
+    pub topic: Topic,
+    /// Data created timestamp (ms)
+    pub creation_time: u64,
+    pub data: Vec<GreeksUpdateMsg>,
+}
+
+#[derive(PartialEq, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GreeksUpdateMsg {
+    /// Base coin
+    pub base_coin: String,
+    /// Delta value
+    #[serde(deserialize_with = "number")]
+    pub total_delta: Number,
+    /// Gamma value
+    #[serde(deserialize_with = "number")]
+    pub total_gamma: Number,
+    /// Vega value
+    #[serde(deserialize_with = "number")]
+    pub total_vega: Number,
+    /// Theta value
+    #[serde(deserialize_with = "number")]
+    pub total_theta: Number,
+}
+
+// The private messages are told apart by topic, since `IncomingMessage` is untagged.
+
+fn order_topic<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Topic, D::Error> {
+    expect_topic(deserializer, |topic| matches!(topic, Topic::Order { .. }))
+}
+
+fn position_topic<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Topic, D::Error> {
+    expect_topic(deserializer, |topic| {
+        matches!(topic, Topic::Position { .. })
+    })
+}
+
+fn execution_topic<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Topic, D::Error> {
+    expect_topic(deserializer, |topic| {
+        matches!(topic, Topic::Execution { .. })
+    })
+}
+
+fn wallet_topic<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Topic, D::Error> {
+    expect_topic(deserializer, |topic| *topic == Topic::Wallet)
+}
+
+fn greeks_topic<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Topic, D::Error> {
+    expect_topic(deserializer, |topic| *topic == Topic::Greeks)
+}
+
+fn expect_topic<'de, D>(deserializer: D, expected: fn(&Topic) -> bool) -> Result<Topic, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let topic = Topic::deserialize(deserializer)?;
+    if !expected(&topic) {
+        return Err(de::Error::custom(format!("unexpected topic `{topic}`")));
+    }
+    Ok(topic)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::common::deserialize_slice;
+    use crate::number::n;
 
     use super::*;
 
@@ -646,18 +1072,18 @@ mod tests {
 		}"#;
         let message: IncomingMessage = deserialize_slice(json.as_bytes()).unwrap();
         let ticker_delta = TickerMsg::Delta {
-            topic: String::from("tickers.BTCUSDT"),
+            topic: Topic::ticker("BTCUSDT"),
             cs: Some(195377749067),
             ts: 1718995014034,
             data: TickerDeltaMsg {
                 symbol: String::from("BTCUSDT"),
                 tick_direction: Some(TickDirection::PlusTick),
-                last_price: Some(63948.5),
+                last_price: Some(n!(63948.5)),
                 pre_open_price: None,
                 pre_qty: None,
                 cur_pre_listing_phase: None,
                 prev_price24h: None,
-                price24h_pcnt: Some(-0.015895),
+                price24h_pcnt: Some(n!(-0.015895)),
                 high_price24h: None,
                 low_price24h: None,
                 prev_price1h: None,
@@ -665,14 +1091,14 @@ mod tests {
                 index_price: None,
                 open_interest: None,
                 open_interest_value: None,
-                turnover24h: Some(6793884423.5518),
-                volume24h: Some(105991.376),
+                turnover24h: Some(n!(6793884423.5518)),
+                volume24h: Some(n!(105991.376)),
                 funding_rate: None,
                 next_funding_time: None,
-                bid1_price: Some(63948.4),
-                bid1_size: Some(3.439),
-                ask1_price: Some(63948.5),
-                ask1_size: Some(2.566),
+                bid1_price: Some(n!(63948.4)),
+                bid1_size: Some(n!(3.439)),
+                ask1_price: Some(n!(63948.5)),
+                ask1_size: Some(n!(2.566)),
                 delivery_time: None,
                 basis_rate: None,
                 delivery_fee_rate: None,
@@ -683,6 +1109,23 @@ mod tests {
         assert_eq!(message, expected);
     }
 
+    #[test]
+    fn deserialize_incoming_message_option_ticker() {
+        let json = r#"{"id":"tickers.BTC-6JAN23-17500-C-2480334983-1672917511074","topic":"tickers.BTC-6JAN23-17500-C","ts":1672917511074,"data":{"symbol":"BTC-6JAN23-17500-C","bidPrice":"0","bidSize":"0","bidIv":"0","askPrice":"10","askSize":"5.1","askIv":"0.514","lastPrice":"10","highPrice24h":"25","lowPrice24h":"5","markPrice":"7.86976724","indexPrice":"16823.73","markPriceIv":"0.4896","underlyingPrice":"16815.1","openInterest":"49.85","turnover24h":"446802.8473","volume24h":"26.55","totalVolume":"86","totalTurnover":"1437431","delta":"0.047831","gamma":"0.00021453","vega":"0.81351067","theta":"-19.9115368","predictedDeliveryPrice":"0","change24h":"-0.33333334"},"type":"snapshot"}"#;
+        let message: IncomingMessage = deserialize_slice(json.as_bytes()).unwrap();
+        let IncomingMessage::OptionTicker(message) = message else {
+            panic!("not an option ticker: {message:?}");
+        };
+        let OptionTickerMsg::Snapshot { topic, ts, data } = *message;
+        assert_eq!(topic, Topic::ticker("BTC-6JAN23-17500-C"));
+        assert_eq!(ts, 1672917511074);
+        assert_eq!(data.symbol, "BTC-6JAN23-17500-C");
+        assert_eq!(data.ask_iv, n!(0.514));
+        assert_eq!(data.mark_price_iv, n!(0.4896));
+        assert_eq!(data.index_price, n!(16823.73));
+        assert_eq!(data.theta, n!(-19.9115368));
+    }
+
     #[test]
     fn deserialize_incoming_message_ticker_snapshot() {
         // Category: linear.
@@ -719,33 +1162,33 @@ mod tests {
 		}"#;
         let message: IncomingMessage = deserialize_slice(json.as_bytes()).unwrap();
         let ticker_snapshot = TickerMsg::Snapshot {
-            topic: String::from("tickers.BTCUSDT"),
+            topic: Topic::ticker("BTCUSDT"),
             cs: Some(337149693308),
             ts: 1740622194359,
             data: TickerSnapshotMsg {
                 symbol: String::from("BTCUSDT"),
                 tick_direction: TickDirection::ZeroPlusTick,
-                last_price: 84594.40,
+                last_price: n!(84594.40),
                 pre_open_price: None,
                 pre_qty: None,
                 cur_pre_listing_phase: Some(String::from("")),
-                prev_price24h: 88539.30,
-                price24h_pcnt: -0.044555,
-                high_price24h: 89389.90,
-                low_price24h: 82055.60,
-                prev_price1h: 84307.20,
-                mark_price: 84594.00,
-                index_price: 84650.47,
-                open_interest: 52903.75,
-                open_interest_value: 4475339827.50,
-                turnover24h: 17166562011.6514,
-                volume24h: 200176.9910,
-                funding_rate: -0.00016974,
+                prev_price24h: n!(88539.30),
+                price24h_pcnt: n!(-0.044555),
+                high_price24h: n!(89389.90),
+                low_price24h: n!(82055.60),
+                prev_price1h: n!(84307.20),
+                mark_price: n!(84594.00),
+                index_price: n!(84650.47),
+                open_interest: n!(52903.75),
+                open_interest_value: n!(4475339827.50),
+                turnover24h: n!(17166562011.6514),
+                volume24h: n!(200176.9910),
+                funding_rate: n!(-0.00016974),
                 next_funding_time: 1740643200000,
-                bid1_price: 84594.30,
-                bid1_size: 6.777,
-                ask1_price: 84594.40,
-                ask1_size: 0.660,
+                bid1_price: n!(84594.30),
+                bid1_size: n!(6.777),
+                ask1_price: n!(84594.40),
+                ask1_size: n!(0.660),
                 delivery_time: None,
                 basis_rate: None,
                 delivery_fee_rate: None,
@@ -756,6 +1199,86 @@ mod tests {
         assert_eq!(message, expected);
     }
 
+    #[test]
+    fn deserialize_incoming_message_orderbook_snapshot() {
+        let json = r#"{
+            "topic": "orderbook.50.BTCUSDT",
+            "type": "snapshot",
+            "ts": 1672304484978,
+            "data": {
+                "s": "BTCUSDT",
+                "b": [
+                    ["16493.50", "0.006"],
+                    ["16493.00", "0.100"]
+                ],
+                "a": [
+                    ["16611.00", "0.029"],
+                    ["16612.00", "0.213"]
+                ],
+                "u": 18521288,
+                "seq": 7961638724
+            },
+            "cts": 1672304484976
+        }"#;
+        let message: IncomingMessage = deserialize_slice(json.as_bytes()).unwrap();
+        let expected = IncomingMessage::OrderBook(OrderBookMsg::Snapshot {
+            topic: Topic::orderbook("BTCUSDT", 50),
+            ts: 1672304484978,
+            data: OrderBookDataMsg {
+                symbol: String::from("BTCUSDT"),
+                bids: vec![
+                    OrderBookLevelMsg(n!(16493.50), n!(0.006)),
+                    OrderBookLevelMsg(n!(16493.00), n!(0.100)),
+                ],
+                asks: vec![
+                    OrderBookLevelMsg(n!(16611.00), n!(0.029)),
+                    OrderBookLevelMsg(n!(16612.00), n!(0.213)),
+                ],
+                update_id: 18521288,
+                seq: 7961638724,
+            },
+            cts: Some(1672304484976),
+        });
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn deserialize_incoming_message_orderbook_delta() {
+        let json = r#"{
+            "topic": "orderbook.50.BTCUSDT",
+            "type": "delta",
+            "ts": 1687940967466,
+            "data": {
+                "s": "BTCUSDT",
+                "b": [
+                    ["30247.20", "30.028"],
+                    ["30245.40", "0"]
+                ],
+                "a": [],
+                "u": 177400507,
+                "seq": 66544703342
+            },
+            "cts": 1687940967464
+        }"#;
+        let message: IncomingMessage = deserialize_slice(json.as_bytes()).unwrap();
+        let expected = IncomingMessage::OrderBook(OrderBookMsg::Delta {
+            topic: Topic::orderbook("BTCUSDT", 50),
+            ts: 1687940967466,
+            data: OrderBookDataMsg {
+                symbol: String::from("BTCUSDT"),
+                bids: vec![
+                    OrderBookLevelMsg(n!(30247.20), n!(30.028)),
+                    OrderBookLevelMsg(n!(30245.40), n!(0.0)),
+                ],
+                asks: vec![],
+                update_id: 177400507,
+                seq: 66544703342,
+            },
+            cts: Some(1687940967464),
+        });
+        assert_eq!(message, expected);
+    }
+
     #[test]
     fn deserialize_incoming_message_trade_snapshot() {
         // Category: linear.
@@ -779,14 +1302,14 @@ mod tests {
         let message: IncomingMessage = deserialize_slice(json.as_bytes()).unwrap();
         let expected = IncomingMessage::Trade(TradeMsg::Snapshot {
             id: None,
-            topic: String::from("publicTrade.BTCUSDT"),
+            topic: Topic::trade("BTCUSDT"),
             ts: 1741433245359,
             data: vec![TradeSnapshotMsg {
                 time: 1741433245357,
                 symbol: String::from("BTCUSDT"),
                 side: Side::Buy,
-                size: 0.007,
-                price: 85821.00,
+                size: n!(0.007),
+                price: n!(85821.00),
                 tick_direction: TickDirection::PlusTick,
                 trade_id: String::from("485eaa70-df6e-5260-bbef-4f7324e3c5d9"),
                 block_trade: false,
@@ -819,14 +1342,14 @@ mod tests {
         }"#;
         let message: IncomingMessage = deserialize_slice(json.as_bytes()).unwrap();
         let expected = IncomingMessage::AllLiquidation(AllLiquidationMsg::Snapshot {
-            topic: String::from("allLiquidation.BTCUSDT"),
+            topic: Topic::all_liquidation("BTCUSDT"),
             ts: 1741450605553,
             data: vec![AllLiquidationSnapshotMsg {
                 time: 1741450605236,
                 symbol: String::from("BTCUSDT"),
                 side: Side::Buy,
-                size: 0.001,
-                price: 85823.60,
+                size: n!(0.001),
+                price: n!(85823.60),
             }],
         });
         assert_eq!(message, expected);
@@ -887,8 +1410,9 @@ mod tests {
             ]
         }"#;
         let message: IncomingMessage = deserialize_slice(json.as_bytes()).unwrap();
-        let expected = IncomingMessage::Order(OrderMsg::Update {
+        let expected = IncomingMessage::Order(OrderMsg {
             id: String::from("5923240c6880ab-c59f-420b-9adb-3639adc9dd90"),
+            topic: Topic::Order { category: None },
             creation_time: 1672364262474,
             data: vec![OrderUpdateMsg {
                 category: Category::Option,
@@ -897,21 +1421,21 @@ mod tests {
                 is_leverage: None,
                 block_trade_id: Some(String::new()),
                 symbol: String::from("ETH-30DEC22-1400-C"),
-                price: 72.5,
-                qty: 1.0,
+                price: n!(72.5),
+                qty: n!(1.0),
                 side: Side::Sell,
                 position_idx: PositionIdx::OneWay,
                 order_status: OrderStatus::Filled,
                 create_type: None,
                 cancel_type: CancelType::UNKNOWN,
                 reject_reason: RejectReason::EcNoError,
-                avg_price: 75.0,
+                avg_price: n!(75.0),
                 leaves_qty: None,
                 leaves_value: None,
-                cum_exec_qty: 1.0,
-                cum_exec_value: 75.0,
-                cum_exec_fee: 0.358635,
-                closed_pnl: 0.0,
+                cum_exec_qty: n!(1.0),
+                cum_exec_value: n!(75.0),
+                cum_exec_fee: n!(0.358635),
+                closed_pnl: n!(0.0),
                 fee_currency: None,
                 time_in_force: TimeInForce::IOC,
                 order_type: OrderType::Market,
@@ -945,6 +1469,82 @@ mod tests {
         assert_eq!(message, expected);
     }
 
+    #[test]
+    fn deserialize_incoming_message_order_unrecognized_values() {
+        let json = r#"{
+            "id": "5923240c6880ab-c59f-420b-9adb-3639adc9dd90",
+            "topic": "order.option",
+            "creationTime": 1672364262474,
+            "data": [
+                {
+                    "symbol": "ETH-30DEC22-1400-C",
+                    "orderId": "5cf98598-39a7-459e-97bf-76ca765ee020",
+                    "side": "Sell",
+                    "orderType": "Market",
+                    "cancelType": "UNKNOWN",
+                    "price": "72.5",
+                    "qty": "1",
+                    "orderIv": "",
+                    "timeInForce": "IOC",
+                    "orderStatus": "Frozen",
+                    "orderLinkId": "",
+                    "lastPriceOnCreated": "",
+                    "reduceOnly": false,
+                    "leavesQty": "",
+                    "leavesValue": "",
+                    "cumExecQty": "1",
+                    "cumExecValue": "75",
+                    "avgPrice": "75",
+                    "blockTradeId": "",
+                    "positionIdx": 3,
+                    "cumExecFee": "0.358635",
+                    "closedPnl": "0",
+                    "createdTime": "1672364262444",
+                    "updatedTime": "1672364262457",
+                    "rejectReason": "EC_NewReason",
+                    "stopOrderType": "",
+                    "tpslMode": "",
+                    "triggerPrice": "",
+                    "takeProfit": "",
+                    "stopLoss": "",
+                    "tpTriggerBy": "",
+                    "slTriggerBy": "",
+                    "tpLimitPrice": "",
+                    "slLimitPrice": "",
+                    "triggerDirection": 0,
+                    "triggerBy": "",
+                    "closeOnTrigger": false,
+                    "category": "option",
+                    "placeType": "price",
+                    "smpType": "None",
+                    "smpGroup": 0,
+                    "smpOrderId": "",
+                    "feeCurrency": ""
+                }
+            ]
+        }"#;
+        let message: IncomingMessage = deserialize_slice(json.as_bytes()).unwrap();
+        let IncomingMessage::Order(OrderMsg { topic, data, .. }) = message else {
+            panic!("unexpected message {message:?}");
+        };
+        assert_eq!(
+            topic,
+            Topic::Order {
+                category: Some(Category::Option)
+            }
+        );
+        assert_eq!(
+            data[0].order_status,
+            OrderStatus::Unrecognized(String::from("Frozen"))
+        );
+        assert_eq!(
+            data[0].reject_reason,
+            RejectReason::Unrecognized(String::from("EC_NewReason"))
+        );
+        assert_eq!(data[0].position_idx, PositionIdx::Unrecognized(3));
+        assert_eq!(data[0].side, Side::Sell);
+    }
+
     #[test]
     fn deserialize_incoming_message_position() {
         let json = r#"{
@@ -993,41 +1593,41 @@ mod tests {
         let message: IncomingMessage = deserialize_slice(json.as_bytes()).unwrap();
         let expected = IncomingMessage::Position(PositionMsg {
             id: String::from("1003076014fb7eedb-c7e6-45d6-a8c1-270f0169171a"),
-            topic: String::from("position"),
+            topic: Topic::Position { category: None },
             creation_time: 1697682317044,
             data: vec![PositionUpdateMsg {
                 category: Category::Linear,
                 symbol: String::from("BTCUSDT"),
                 // TODO: write deserializer from ""
                 // side: None,
-                size: 0.0,
+                size: n!(0.0),
                 position_idx: PositionIdx::Sell,
                 trade_mode: TradeMode::CrossMargin,
-                position_value: 0.0,
+                position_value: n!(0.0),
                 risk_id: 1,
-                risk_limit_value: 2000000.0,
-                entry_price: 0.0,
-                mark_price: 28184.5,
-                leverage: 10.0,
-                position_balance: 0.0,
+                risk_limit_value: n!(2000000.0),
+                entry_price: n!(0.0),
+                mark_price: n!(28184.5),
+                leverage: n!(10.0),
+                position_balance: n!(0.0),
                 auto_add_margin: AutoAddMargin::False,
-                position_i_m: 0.0,
-                position_m_m: 0.0,
-                liq_price: 0.0,
+                position_i_m: n!(0.0),
+                position_m_m: n!(0.0),
+                liq_price: n!(0.0),
                 bust_price: None,
                 tpsl_mode: TpslMode::Full,
-                take_profit: 0.0,
-                stop_loss: 0.0,
-                trailing_stop: 0.0,
+                take_profit: n!(0.0),
+                stop_loss: n!(0.0),
+                trailing_stop: n!(0.0),
                 // TODO: write deserializer from undefined
                 // unrealized_pnl: None,
-                cur_realised_pnl: 1.26,
-                session_avg_price: 0.0,
+                cur_realised_pnl: n!(1.26),
+                session_avg_price: n!(0.0),
                 delta: None,
                 gamma: None,
                 vega: None,
                 theta: None,
-                cum_realised_pnl: -25.06579337,
+                cum_realised_pnl: n!(-25.06579337),
                 position_status: PositionStatus::Normal,
                 adl_rank_indicator: 0,
                 is_reduce_only: false,
@@ -1040,4 +1640,218 @@ mod tests {
         });
         assert_eq!(message, expected);
     }
+
+    #[test]
+    fn deserialize_incoming_message_command_auth() {
+        let json =
+            r#"{"success":true,"ret_msg":"","op":"auth","conn_id":"cejreaspqfh3sjdnldmg-p"}"#;
+        let message: IncomingMessage = deserialize_slice(json.as_bytes()).unwrap();
+        let expected = IncomingMessage::Command(CommandMsg::Auth {
+            req_id: None,
+            ret_msg: Some(String::new()),
+            conn_id: String::from("cejreaspqfh3sjdnldmg-p"),
+            success: true,
+        });
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn deserialize_incoming_message_execution() {
+        let json = r#"{
+            "id": "592324803b2785-26fa-4214-9963-bdd4727f07be",
+            "topic": "execution",
+            "creationTime": 1672364174455,
+            "data": [
+                {
+                    "category": "linear",
+                    "symbol": "XRPUSDT",
+                    "execFee": "0.005061",
+                    "execId": "7e2ae69c-4edf-5800-a352-893d52b446aa",
+                    "execPrice": "0.3374",
+                    "execQty": "25",
+                    "execType": "Trade",
+                    "execValue": "8.435",
+                    "isMaker": false,
+                    "feeRate": "0.0006",
+                    "tradeIv": "",
+                    "markIv": "",
+                    "blockTradeId": "",
+                    "markPrice": "0.3391",
+                    "indexPrice": "",
+                    "underlyingPrice": "",
+                    "leavesQty": "0",
+                    "orderId": "f6e324ff-99c2-4e89-9739-3086e47f9381",
+                    "orderLinkId": "",
+                    "orderPrice": "0.3207",
+                    "orderQty": "25",
+                    "orderType": "Market",
+                    "stopOrderType": "UNKNOWN",
+                    "side": "Sell",
+                    "execTime": "1672364174443",
+                    "isLeverage": "0",
+                    "closedSize": "",
+                    "seq": 4688002127,
+                    "createType": "CreateByUser",
+                    "marketUnit": ""
+                }
+            ]
+        }"#;
+        let message: IncomingMessage = deserialize_slice(json.as_bytes()).unwrap();
+        let expected = IncomingMessage::Execution(ExecutionMsg {
+            id: String::from("592324803b2785-26fa-4214-9963-bdd4727f07be"),
+            topic: Topic::Execution { category: None },
+            creation_time: 1672364174455,
+            data: vec![ExecutionUpdateMsg {
+                category: Category::Linear,
+                symbol: String::from("XRPUSDT"),
+                is_leverage: false,
+                order_id: String::from("f6e324ff-99c2-4e89-9739-3086e47f9381"),
+                order_link_id: String::new(),
+                side: Side::Sell,
+                order_price: n!(0.3207),
+                order_qty: n!(25.0),
+                leaves_qty: n!(0.0),
+                create_type: Some(CreateType::CreateByUser),
+                order_type: OrderType::Market,
+                stop_order_type: StopOrderType::UNKNOWN,
+                exec_fee: n!(0.005061),
+                exec_id: String::from("7e2ae69c-4edf-5800-a352-893d52b446aa"),
+                exec_price: n!(0.3374),
+                exec_qty: n!(25.0),
+                exec_type: ExecType::Trade,
+                exec_value: n!(8.435),
+                exec_time: 1672364174443,
+                is_maker: false,
+                fee_rate: Some(n!(0.0006)),
+                trade_iv: None,
+                mark_iv: None,
+                mark_price: Some(n!(0.3391)),
+                index_price: None,
+                underlying_price: None,
+                block_trade_id: Some(String::new()),
+                closed_size: None,
+                seq: 4688002127,
+                market_unit: Some(String::new()),
+            }],
+        });
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn deserialize_incoming_message_wallet() {
+        let json = r#"{
+            "id": "592324d2bce751-ad38-48eb-8f42-4671d1fb4d4e",
+            "topic": "wallet",
+            "creationTime": 1700034722104,
+            "data": [
+                {
+                    "accountIMRate": "0",
+                    "accountMMRate": "0",
+                    "totalEquity": "10262.91335023",
+                    "totalWalletBalance": "9684.46297164",
+                    "totalMarginBalance": "9684.46297164",
+                    "totalAvailableBalance": "9556.6056555",
+                    "totalPerpUPL": "0",
+                    "totalInitialMargin": "0",
+                    "totalMaintenanceMargin": "0",
+                    "coin": [
+                        {
+                            "coin": "BTC",
+                            "equity": "0.00102964",
+                            "usdValue": "36.70759517",
+                            "walletBalance": "0.00102964",
+                            "availableToWithdraw": "0.00102964",
+                            "availableToBorrow": "",
+                            "borrowAmount": "0",
+                            "accruedInterest": "0",
+                            "totalOrderIM": "",
+                            "totalPositionIM": "",
+                            "totalPositionMM": "",
+                            "unrealisedPnl": "0",
+                            "cumRealisedPnl": "-0.00000973",
+                            "bonus": "0",
+                            "collateralSwitch": true,
+                            "marginCollateral": true,
+                            "locked": "0",
+                            "spotHedgingQty": "0.01592413"
+                        }
+                    ],
+                    "accountLTV": "0",
+                    "accountType": "UNIFIED"
+                }
+            ]
+        }"#;
+        let message: IncomingMessage = deserialize_slice(json.as_bytes()).unwrap();
+        let expected = IncomingMessage::Wallet(WalletMsg {
+            id: String::from("592324d2bce751-ad38-48eb-8f42-4671d1fb4d4e"),
+            topic: Topic::Wallet,
+            creation_time: 1700034722104,
+            data: vec![WalletUpdateMsg {
+                account_type: AccountType::UNIFIED,
+                account_im_rate: Some(n!(0.0)),
+                account_mm_rate: Some(n!(0.0)),
+                account_ltv: Some(n!(0.0)),
+                total_equity: Some(n!(10262.91335023)),
+                total_wallet_balance: Some(n!(9684.46297164)),
+                total_margin_balance: Some(n!(9684.46297164)),
+                total_available_balance: Some(n!(9556.6056555)),
+                total_perp_upl: Some(n!(0.0)),
+                total_initial_margin: Some(n!(0.0)),
+                total_maintenance_margin: Some(n!(0.0)),
+                coin: vec![WalletCoinMsg {
+                    coin: String::from("BTC"),
+                    equity: n!(0.00102964),
+                    usd_value: Some(n!(36.70759517)),
+                    wallet_balance: n!(0.00102964),
+                    available_to_withdraw: Some(n!(0.00102964)),
+                    available_to_borrow: None,
+                    borrow_amount: Some(n!(0.0)),
+                    accrued_interest: Some(n!(0.0)),
+                    total_order_im: None,
+                    total_position_im: None,
+                    total_position_mm: None,
+                    unrealised_pnl: Some(n!(0.0)),
+                    cum_realised_pnl: Some(n!(-0.00000973)),
+                    bonus: Some(n!(0.0)),
+                    margin_collateral: true,
+                    collateral_switch: true,
+                    locked: Some(n!(0.0)),
+                    spot_hedging_qty: Some(n!(0.01592413)),
+                }],
+            }],
+        });
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn deserialize_incoming_message_greeks() {
+        let json = r#"{
+            "id": "592324fa945a30-2603-49a5-b865-21668c29f2a6",
+            "topic": "greeks",
+            "creationTime": 1672364262482,
+            "data": [
+                {
+                    "baseCoin": "ETH",
+                    "totalDelta": "0.06999986",
+                    "totalGamma": "-0.00000001",
+                    "totalVega": "-0.00000024",
+                    "totalTheta": "0.00001314"
+                }
+            ]
+        }"#;
+        let message: IncomingMessage = deserialize_slice(json.as_bytes()).unwrap();
+        let expected = IncomingMessage::Greeks(GreeksMsg {
+            id: String::from("592324fa945a30-2603-49a5-b865-21668c29f2a6"),
+            topic: Topic::Greeks,
+            creation_time: 1672364262482,
+            data: vec![GreeksUpdateMsg {
+                base_coin: String::from("ETH"),
+                total_delta: n!(0.06999986),
+                total_gamma: n!(-0.00000001),
+                total_vega: n!(-0.00000024),
+                total_theta: n!(0.00001314),
+            }],
+        });
+        assert_eq!(message, expected);
+    }
 }
@@ -0,0 +1,682 @@
+use futures_util::TryStreamExt;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::{sync::broadcast, task::JoinHandle};
+use tracing::Instrument as _;
+
+use crate::{
+    paginate::cursor, url::PATH_MARKET_INSTRUMENTS_INFO, AllCategoriesInstrumentsInfo, Category,
+    Client, CurAuctionPhase, GetInstrumentsInfoParams, InstrumentFilters, InstrumentsInfo, Number,
+    OptionInstrumentsInfo, Page, PageParams, Pagination, Result, SpotInstrumentsInfo, Status,
+};
+
+/// Largest page of instruments info.
+const PAGE_LIMIT: i64 = 1000;
+const EVENTS_CAPACITY: usize = 1024;
+/// Bybit only returns the options of one base coin per request, BTC without `baseCoin`.
+const OPTION_BASE_COINS: [&str; 3] = ["BTC", "ETH", "SOL"];
+
+/// `status` filters of a category. Without one, Bybit only returns the `Trading` spot and
+/// contract symbols, so the other statuses are requested too. Options are not filtered.
+fn status_filters(category: &Category) -> Vec<Option<Status>> {
+    match category {
+        Category::Spot => vec![None, Some(Status::PreLaunch)],
+        Category::Linear | Category::Inverse => vec![
+            None,
+            Some(Status::PreLaunch),
+            Some(Status::Delivering),
+            Some(Status::Closed),
+        ],
+        _ => vec![None],
+    }
+}
+
+/// Instruments info of one symbol.
+#[derive(Debug, PartialEq)]
+pub enum Instrument {
+    Spot(Box<SpotInstrumentsInfo>),
+    /// Linear or inverse contract.
+    Contract {
+        category: Category,
+        info: Box<AllCategoriesInstrumentsInfo>,
+    },
+    Option(Box<OptionInstrumentsInfo>),
+}
+
+impl Instrument {
+    pub fn category(&self) -> Category {
+        match self {
+            Self::Spot(_) => Category::Spot,
+            Self::Contract { category, .. } => category.clone(),
+            Self::Option(_) => Category::Option,
+        }
+    }
+
+    pub fn symbol(&self) -> &str {
+        match self {
+            Self::Spot(info) => &info.symbol,
+            Self::Contract { info, .. } => &info.symbol,
+            Self::Option(info) => &info.symbol,
+        }
+    }
+
+    pub fn status(&self) -> &Status {
+        match self {
+            Self::Spot(info) => &info.status,
+            Self::Contract { info, .. } => &info.status,
+            Self::Option(info) => &info.status,
+        }
+    }
+
+    /// Phase of a pre-listed contract.
+    pub fn auction_phase(&self) -> Option<&CurAuctionPhase> {
+        match self {
+            Self::Spot(_) | Self::Option(_) => None,
+            Self::Contract { info, .. } => info
+                .pre_listing_info
+                .as_ref()
+                .map(|pre_listing| &pre_listing.cur_auction_phase),
+        }
+    }
+
+    pub fn filters(&self) -> InstrumentFilters {
+        match self {
+            Self::Spot(info) => info.as_ref().into(),
+            Self::Contract { info, .. } => info.as_ref().into(),
+            Self::Option(info) => info.as_ref().into(),
+        }
+    }
+}
+
+/// Change found by [`InstrumentRegistry::refresh`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum InstrumentEvent {
+    Listed(Arc<Instrument>),
+    StatusChanged {
+        category: Category,
+        symbol: String,
+        from: Status,
+        to: Status,
+    },
+    AuctionPhaseChanged {
+        category: Category,
+        symbol: String,
+        from: Option<CurAuctionPhase>,
+        to: Option<CurAuctionPhase>,
+    },
+    /// No longer returned by Bybit.
+    Delisted {
+        category: Category,
+        symbol: String,
+    },
+}
+
+type Key = (Category, String);
+
+/// Cache of the instruments info of all categories, keyed by category and symbol.
+///
+/// [`InstrumentRegistry::refresh`] reloads every page of every category and reports the
+/// differences as [`InstrumentEvent`]s, to the caller and to [`InstrumentRegistry::subscribe`].
+/// The first refresh only fills the cache.
+#[derive(Debug)]
+pub struct InstrumentRegistry {
+    categories: Vec<Category>,
+    option_base_coins: Vec<String>,
+    instruments: Mutex<HashMap<Key, Arc<Instrument>>>,
+    loaded: Mutex<bool>,
+    events: broadcast::Sender<InstrumentEvent>,
+}
+
+impl Default for InstrumentRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InstrumentRegistry {
+    /// Spot, linear, inverse, and the options of BTC, ETH and SOL.
+    pub fn new() -> Self {
+        Self {
+            categories: vec![
+                Category::Spot,
+                Category::Linear,
+                Category::Inverse,
+                Category::Option,
+            ],
+            option_base_coins: OPTION_BASE_COINS.map(String::from).to_vec(),
+            instruments: Mutex::new(HashMap::new()),
+            loaded: Mutex::new(false),
+            events: broadcast::channel(EVENTS_CAPACITY).0,
+        }
+    }
+
+    pub fn with_categories(mut self, categories: &[Category]) -> Self {
+        self.categories = categories.to_vec();
+        self
+    }
+
+    /// Base coins whose options are loaded, one request per coin.
+    pub fn with_option_base_coins(mut self, base_coins: &[&str]) -> Self {
+        self.option_base_coins = base_coins.iter().map(|&coin| coin.to_owned()).collect();
+        self
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<InstrumentEvent> {
+        self.events.subscribe()
+    }
+
+    pub fn get(&self, category: &Category, symbol: &str) -> Option<Arc<Instrument>> {
+        let key = (category.clone(), symbol.to_owned());
+        self.instruments.lock().unwrap().get(&key).cloned()
+    }
+
+    /// Whether `symbol` is listed and open for trading.
+    pub fn is_trading(&self, category: &Category, symbol: &str) -> bool {
+        self.get(category, symbol)
+            .is_some_and(|instrument| *instrument.status() == Status::Trading)
+    }
+
+    /// Symbols of `category`, sorted.
+    pub fn symbols(&self, category: &Category) -> Vec<String> {
+        let instruments = self.instruments.lock().unwrap();
+        let mut symbols: Vec<String> = instruments
+            .keys()
+            .filter(|(c, _)| c == category)
+            .map(|(_, symbol)| symbol.clone())
+            .collect();
+        symbols.sort();
+        symbols
+    }
+
+    pub fn len(&self) -> usize {
+        self.instruments.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn filters(&self, category: &Category, symbol: &str) -> Option<InstrumentFilters> {
+        self.get(category, symbol)
+            .map(|instrument| instrument.filters())
+    }
+
+    /// `price` rounded to the nearest tick of `symbol`.
+    pub fn round_price(&self, category: &Category, symbol: &str, price: Number) -> Option<Number> {
+        self.filters(category, symbol)
+            .map(|filters| filters.round_price(price))
+    }
+
+    /// `qty` rounded down to the qty step of `symbol`.
+    pub fn round_qty(&self, category: &Category, symbol: &str, qty: Number) -> Option<Number> {
+        self.filters(category, symbol)
+            .map(|filters| filters.round_qty(qty))
+    }
+
+    /// Reloads all categories, with the symbols of every status. On an error the cache is
+    /// left unchanged.
+    pub async fn refresh(&self, client: &Client) -> Result<Vec<InstrumentEvent>> {
+        let mut fresh = HashMap::new();
+        for category in &self.categories {
+            let base_coins = match category {
+                Category::Option => self
+                    .option_base_coins
+                    .iter()
+                    .map(|coin| Some(coin.as_str()))
+                    .collect(),
+                _ => vec![None],
+            };
+            for base_coin in base_coins {
+                for status in status_filters(category) {
+                    for instrument in load(client, category, base_coin, status).await? {
+                        let key = (instrument.category(), instrument.symbol().to_owned());
+                        fresh.insert(key, Arc::new(instrument));
+                    }
+                }
+            }
+        }
+
+        let mut loaded = self.loaded.lock().unwrap();
+        let mut instruments = self.instruments.lock().unwrap();
+        let events = if *loaded {
+            diff(&instruments, &fresh)
+        } else {
+            Vec::new()
+        };
+        *instruments = fresh;
+        *loaded = true;
+        drop(instruments);
+        drop(loaded);
+
+        for event in &events {
+            tracing::info!(?event, "Instrument changed");
+            // No subscribers is fine.
+            let _ = self.events.send(event.clone());
+        }
+        Ok(events)
+    }
+
+    /// Calls [`InstrumentRegistry::refresh`] every `interval` until the task is aborted.
+    /// Failed refreshes are logged and keep the previous instruments.
+    pub fn spawn_refresh(
+        self: &Arc<Self>,
+        client: Arc<Client>,
+        interval: Duration,
+    ) -> JoinHandle<()> {
+        let registry = self.clone();
+        let span = tracing::info_span!("bybit.instrument_refresh");
+        tokio::spawn(
+            async move {
+                loop {
+                    match registry.refresh(&client).await {
+                        Ok(events) => {
+                            tracing::debug!(
+                                instruments = registry.len(),
+                                events = events.len(),
+                                "Instruments refreshed"
+                            );
+                        }
+                        Err(e) => tracing::warn!("Refresh instruments failed: {e}"),
+                    }
+                    tokio::time::sleep(interval).await;
+                }
+            }
+            .instrument(span),
+        )
+    }
+}
+
+/// Every page of `category`, only of `base_coin` and `status` if given.
+async fn load(
+    client: &Client,
+    category: &Category,
+    base_coin: Option<&str>,
+    status: Option<Status>,
+) -> Result<Vec<Instrument>> {
+    let params = GetInstrumentsInfoParams {
+        category: category.clone(),
+        symbol: None,
+        status,
+        base_coin: base_coin.map(String::from),
+        limit: Some(PAGE_LIMIT),
+        cursor: None,
+    };
+    client
+        .paginate_public::<_, InstrumentsInfo>(
+            PATH_MARKET_INSTRUMENTS_INFO,
+            params,
+            Pagination::default(),
+        )
+        .try_collect()
+        .await
+}
+
+impl PageParams for GetInstrumentsInfoParams {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor);
+    }
+
+    fn set_time_window(&mut self, _start_time: Option<u64>, _end_time: Option<u64>) {}
+}
+
+impl Page for InstrumentsInfo {
+    type Item = Instrument;
+
+    fn next_page_cursor(&self) -> Option<&str> {
+        match self {
+            Self::Spot {
+                next_page_cursor, ..
+            } => next_page_cursor.as_deref().and_then(cursor),
+            Self::Linear {
+                next_page_cursor, ..
+            }
+            | Self::Inverse {
+                next_page_cursor, ..
+            }
+            | Self::Option {
+                next_page_cursor, ..
+            } => cursor(next_page_cursor),
+        }
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        match self {
+            Self::Spot { list, .. } => list
+                .into_iter()
+                .map(|info| Instrument::Spot(Box::new(info)))
+                .collect(),
+            Self::Linear { list, .. } => contracts(Category::Linear, list),
+            Self::Inverse { list, .. } => contracts(Category::Inverse, list),
+            Self::Option { list, .. } => list
+                .into_iter()
+                .map(|info| Instrument::Option(Box::new(info)))
+                .collect(),
+        }
+    }
+}
+
+fn contracts(category: Category, list: Vec<AllCategoriesInstrumentsInfo>) -> Vec<Instrument> {
+    list.into_iter()
+        .map(|info| Instrument::Contract {
+            category: category.clone(),
+            info: Box::new(info),
+        })
+        .collect()
+}
+
+fn diff(
+    old: &HashMap<Key, Arc<Instrument>>,
+    new: &HashMap<Key, Arc<Instrument>>,
+) -> Vec<InstrumentEvent> {
+    let mut events = Vec::new();
+    for ((category, symbol), instrument) in new {
+        let Some(previous) = old.get(&(category.clone(), symbol.clone())) else {
+            events.push(InstrumentEvent::Listed(instrument.clone()));
+            continue;
+        };
+        if previous.status() != instrument.status() {
+            events.push(InstrumentEvent::StatusChanged {
+                category: category.clone(),
+                symbol: symbol.clone(),
+                from: previous.status().clone(),
+                to: instrument.status().clone(),
+            });
+        }
+        if previous.auction_phase() != instrument.auction_phase() {
+            events.push(InstrumentEvent::AuctionPhaseChanged {
+                category: category.clone(),
+                symbol: symbol.clone(),
+                from: previous.auction_phase().cloned(),
+                to: instrument.auction_phase().cloned(),
+            });
+        }
+    }
+    for (category, symbol) in old.keys() {
+        if !new.contains_key(&(category.clone(), symbol.clone())) {
+            events.push(InstrumentEvent::Delisted {
+                category: category.clone(),
+                symbol: symbol.clone(),
+            });
+        }
+    }
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use bybit_mock::MockServer;
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::number::n;
+
+    fn linear(symbol: &str, status: &str) -> Value {
+        json!({
+            "symbol": symbol,
+            "contractType": "LinearPerpetual",
+            "status": status,
+            "baseCoin": "BTC",
+            "quoteCoin": "USDT",
+            "launchTime": "1585526400000",
+            "deliveryTime": "0",
+            "deliveryFeeRate": "",
+            "priceScale": "2",
+            "leverageFilter": {
+                "minLeverage": "1",
+                "maxLeverage": "100.00",
+                "leverageStep": "0.01"
+            },
+            "priceFilter": {
+                "minPrice": "0.10",
+                "maxPrice": "1999999.80",
+                "tickSize": "0.10"
+            },
+            "lotSizeFilter": {
+                "maxOrderQty": "1190.000",
+                "minOrderQty": "0.001",
+                "qtyStep": "0.001",
+                "postOnlyMaxOrderQty": "1190.000",
+                "maxMktOrderQty": "500.000",
+                "minNotionalValue": "5"
+            },
+            "unifiedMarginTrade": true,
+            "fundingInterval": 480,
+            "settleCoin": "USDT",
+            "copyTrading": "both",
+            "upperFundingRate": "0.00375",
+            "lowerFundingRate": "-0.00375",
+            "isPreListing": false,
+            "preListingInfo": null,
+            "riskParameters": {
+                "priceLimitRatioX": "0.01",
+                "priceLimitRatioY": "0.02"
+            }
+        })
+    }
+
+    /// Answers the linear instruments of `status`, or the `Trading` ones without a filter.
+    fn mock_linear(server: &MockServer, status: Option<&str>, list: Vec<Value>) {
+        let result = json!({ "category": "linear", "list": list, "nextPageCursor": "" });
+        match status {
+            Some(status) => {
+                server.mock_rest_query(PATH_MARKET_INSTRUMENTS_INFO, "status", status, result)
+            }
+            None => server.mock_rest(PATH_MARKET_INSTRUMENTS_INFO, result),
+        }
+    }
+
+    fn queries(server: &MockServer) -> Vec<Option<String>> {
+        server
+            .rest_requests()
+            .into_iter()
+            .map(|request| request.query)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_refresh() {
+        let server = MockServer::start().await;
+        mock_linear(&server, None, vec![linear("BTCUSDT", "Trading")]);
+        mock_linear(
+            &server,
+            Some("PreLaunch"),
+            vec![linear("NEWUSDT", "PreLaunch")],
+        );
+        mock_linear(&server, Some("Delivering"), vec![]);
+        mock_linear(&server, Some("Closed"), vec![]);
+        let client = Client::new(&server.url());
+        let registry = InstrumentRegistry::new().with_categories(&[Category::Linear]);
+        let mut events = registry.subscribe();
+
+        assert!(registry.refresh(&client).await.unwrap().is_empty());
+        assert_eq!(
+            queries(&server),
+            [
+                Some(String::from("category=linear&limit=1000")),
+                Some(String::from("category=linear&status=PreLaunch&limit=1000")),
+                Some(String::from("category=linear&status=Delivering&limit=1000")),
+                Some(String::from("category=linear&status=Closed&limit=1000")),
+            ]
+        );
+        assert_eq!(registry.symbols(&Category::Linear), ["BTCUSDT", "NEWUSDT"]);
+        assert!(registry.is_trading(&Category::Linear, "BTCUSDT"));
+        assert!(!registry.is_trading(&Category::Linear, "NEWUSDT"));
+        assert!(registry.get(&Category::Spot, "BTCUSDT").is_none());
+        assert_eq!(
+            registry.round_price(&Category::Linear, "BTCUSDT", n!(28000.34)),
+            Some(n!(28000.3))
+        );
+        assert_eq!(
+            registry.round_qty(&Category::Linear, "BTCUSDT", n!(0.0199)),
+            Some(n!(0.019))
+        );
+        assert_eq!(
+            registry.round_price(&Category::Linear, "ETHUSDT", n!(1.0)),
+            None
+        );
+
+        mock_linear(
+            &server,
+            None,
+            vec![linear("NEWUSDT", "Trading"), linear("ETHUSDT", "Trading")],
+        );
+        mock_linear(&server, Some("PreLaunch"), vec![]);
+        mock_linear(&server, Some("Closed"), vec![linear("BTCUSDT", "Closed")]);
+        let mut changes = registry.refresh(&client).await.unwrap();
+        changes.sort_by_key(|event| format!("{event:?}"));
+        assert_eq!(changes.len(), 3);
+        assert!(changes.contains(&InstrumentEvent::StatusChanged {
+            category: Category::Linear,
+            symbol: String::from("NEWUSDT"),
+            from: Status::PreLaunch,
+            to: Status::Trading,
+        }));
+        assert!(changes.contains(&InstrumentEvent::StatusChanged {
+            category: Category::Linear,
+            symbol: String::from("BTCUSDT"),
+            from: Status::Trading,
+            to: Status::Closed,
+        }));
+        assert!(changes.iter().any(|event| matches!(
+            event,
+            InstrumentEvent::Listed(instrument) if instrument.symbol() == "ETHUSDT"
+        )));
+        assert!(registry.is_trading(&Category::Linear, "NEWUSDT"));
+
+        mock_linear(&server, Some("Closed"), vec![]);
+        assert_eq!(
+            registry.refresh(&client).await.unwrap(),
+            [InstrumentEvent::Delisted {
+                category: Category::Linear,
+                symbol: String::from("BTCUSDT"),
+            }]
+        );
+
+        let mut received = Vec::new();
+        while let Ok(event) = events.try_recv() {
+            received.push(event);
+        }
+        assert_eq!(received.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_error_keeps_instruments() {
+        let server = MockServer::start().await;
+        mock_linear(&server, None, vec![]);
+        mock_linear(
+            &server,
+            Some("Delivering"),
+            vec![linear("BTCUSDT", "Delivering")],
+        );
+        let client = Client::new(&server.url());
+        let registry = InstrumentRegistry::new().with_categories(&[Category::Linear]);
+        registry.refresh(&client).await.unwrap();
+        assert_eq!(
+            *registry.get(&Category::Linear, "BTCUSDT").unwrap().status(),
+            Status::Delivering
+        );
+
+        server.mock_rest_error(PATH_MARKET_INSTRUMENTS_INFO, 10006, "Too many visits");
+        assert!(registry.refresh(&client).await.is_err());
+        assert_eq!(registry.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_options() {
+        let server = MockServer::start().await;
+        server.mock_rest(
+            PATH_MARKET_INSTRUMENTS_INFO,
+            json!({
+                "category": "option",
+                "nextPageCursor": "",
+                "list": [{
+                    "symbol": "ETH-3JAN23-1250-P",
+                    "status": "Trading",
+                    "baseCoin": "ETH",
+                    "quoteCoin": "USD",
+                    "settleCoin": "USDC",
+                    "optionsType": "Put",
+                    "launchTime": "1672560000000",
+                    "deliveryTime": "1672732800000",
+                    "deliveryFeeRate": "0.00015",
+                    "priceFilter": {
+                        "minPrice": "0.1",
+                        "maxPrice": "10000000",
+                        "tickSize": "0.1"
+                    },
+                    "lotSizeFilter": {
+                        "maxOrderQty": "1500",
+                        "minOrderQty": "0.1",
+                        "qtyStep": "0.1"
+                    },
+                    "displayName": "ETHUSDT-3JAN23-1250-P"
+                }]
+            }),
+        );
+        let client = Client::new(&server.url());
+        let registry = InstrumentRegistry::new()
+            .with_categories(&[Category::Option])
+            .with_option_base_coins(&["BTC", "ETH"]);
+        registry.refresh(&client).await.unwrap();
+
+        assert_eq!(
+            queries(&server),
+            [
+                Some(String::from("category=option&baseCoin=BTC&limit=1000")),
+                Some(String::from("category=option&baseCoin=ETH&limit=1000")),
+            ]
+        );
+        let instrument = registry
+            .get(&Category::Option, "ETH-3JAN23-1250-P")
+            .unwrap();
+        assert!(matches!(*instrument, Instrument::Option(_)));
+        assert!(registry.is_trading(&Category::Option, "ETH-3JAN23-1250-P"));
+        assert_eq!(
+            registry.round_qty(&Category::Option, "ETH-3JAN23-1250-P", n!(1.25)),
+            Some(n!(1.2))
+        );
+        assert_eq!(
+            registry.round_price(&Category::Option, "ETH-3JAN23-1250-P", n!(12.34)),
+            Some(n!(12.3))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_refresh_stops_on_repeated_cursor() {
+        let server = MockServer::start().await;
+        server.mock_rest(
+            PATH_MARKET_INSTRUMENTS_INFO,
+            json!({
+                "category": "linear",
+                "list": [linear("BTCUSDT", "Trading")],
+                "nextPageCursor": "page-2"
+            }),
+        );
+        let client = Client::new(&server.url());
+        let registry = InstrumentRegistry::new().with_categories(&[Category::Linear]);
+        registry.refresh(&client).await.unwrap();
+
+        let queries = queries(&server);
+        assert_eq!(
+            queries[..2],
+            [
+                Some(String::from("category=linear&limit=1000")),
+                Some(String::from("category=linear&limit=1000&cursor=page-2")),
+            ]
+        );
+        // Each status filter stops after its second page.
+        assert_eq!(queries.len(), 8);
+        assert_eq!(registry.len(), 1);
+
+        server.mock_rest(
+            PATH_MARKET_INSTRUMENTS_INFO,
+            json!({ "category": "linear", "list": [], "nextPageCursor": "page-2" }),
+        );
+        registry.refresh(&client).await.unwrap();
+        assert_eq!(server.rest_requests().len(), 12);
+        assert!(registry.is_empty());
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{
+    url::{
+        PATH_SPOT_LEVER_TOKEN_INFO, PATH_SPOT_LEVER_TOKEN_ORDER_RECORD,
+        PATH_SPOT_LEVER_TOKEN_PURCHASE, PATH_SPOT_LEVER_TOKEN_REDEEM,
+        PATH_SPOT_LEVER_TOKEN_REFERENCE,
+    },
+    Client, GetLeveragedTokenInfoParams, GetLeveragedTokenOrdersParams,
+    GetLeveragedTokenReferenceParams, LeveragedTokenInfo, LeveragedTokenOrders,
+    LeveragedTokenPurchase, LeveragedTokenRedemption, LeveragedTokenReference,
+    PurchaseLeveragedTokenParams, RedeemLeveragedTokenParams, Result,
+};
+
+impl Client {
+    pub async fn get_leveraged_token_info(
+        &self,
+        params: GetLeveragedTokenInfoParams,
+    ) -> Result<LeveragedTokenInfo> {
+        self.get(PATH_SPOT_LEVER_TOKEN_INFO, &params).await
+    }
+
+    /// Net asset value, basket and real leverage of a leveraged token.
+    pub async fn get_leveraged_token_reference(
+        &self,
+        params: GetLeveragedTokenReferenceParams,
+    ) -> Result<LeveragedTokenReference> {
+        self.get(PATH_SPOT_LEVER_TOKEN_REFERENCE, &params).await
+    }
+
+    pub async fn purchase_leveraged_token(
+        &self,
+        params: PurchaseLeveragedTokenParams,
+    ) -> Result<LeveragedTokenPurchase> {
+        self.post_signed(PATH_SPOT_LEVER_TOKEN_PURCHASE, &params)
+            .await
+    }
+
+    pub async fn redeem_leveraged_token(
+        &self,
+        params: RedeemLeveragedTokenParams,
+    ) -> Result<LeveragedTokenRedemption> {
+        self.post_signed(PATH_SPOT_LEVER_TOKEN_REDEEM, &params)
+            .await
+    }
+
+    pub async fn get_leveraged_token_orders(
+        &self,
+        params: GetLeveragedTokenOrdersParams,
+    ) -> Result<LeveragedTokenOrders> {
+        self.get_signed(PATH_SPOT_LEVER_TOKEN_ORDER_RECORD, &params)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bybit_mock::MockServer;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{client::tests::signed_client, number::n, LtOrderStatus, LtOrderType, LtStatus};
+
+    #[tokio::test]
+    async fn test_get_leveraged_token_info() {
+        let server = MockServer::start().await;
+        server.mock_rest(
+            PATH_SPOT_LEVER_TOKEN_INFO,
+            json!({
+                "list": [{
+                    "ltCoin": "BTC3L",
+                    "ltName": "3X Long",
+                    "maxPurchase": "200000",
+                    "minPurchase": "1",
+                    "maxPurchaseDaily": "10000000",
+                    "maxRedeem": "200000",
+                    "minRedeem": "1",
+                    "maxRedeemDaily": "10000000",
+                    "purchaseFeeRate": "0.0005",
+                    "redeemFeeRate": "0.0005",
+                    "ltStatus": "1",
+                    "fundFee": "",
+                    "fundFeeTime": "1672387200000",
+                    "manageFeeRate": "0.00005",
+                    "manageFeeTime": "1672387200000",
+                    "value": "",
+                    "netValue": "5.011",
+                    "total": "10000000"
+                }]
+            }),
+        );
+        let info = signed_client(&server.url())
+            .get_leveraged_token_info(GetLeveragedTokenInfoParams {
+                lt_coin: Some(String::from("BTC3L")),
+            })
+            .await
+            .unwrap();
+        let token = &info.list[0];
+        assert_eq!(token.lt_status, LtStatus::LTCanBePurchasedAndRedeemed);
+        assert_eq!(token.fund_fee, None);
+        assert_eq!(token.net_value, n!(5.011));
+        assert_eq!(token.fund_fee_time, 1672387200000);
+        let requests = server.rest_requests();
+        assert_eq!(requests[0].query.as_deref(), Some("ltCoin=BTC3L"));
+        assert_eq!(requests[0].api_key, None);
+    }
+
+    #[tokio::test]
+    async fn test_purchase_leveraged_token() {
+        let server = MockServer::start().await;
+        server.mock_rest(
+            PATH_SPOT_LEVER_TOKEN_PURCHASE,
+            json!({
+                "ltCoin": "EOS3L",
+                "ltOrderStatus": "1",
+                "execQty": "2",
+                "execAmt": "",
+                "amount": "200",
+                "purchaseId": "2611",
+                "serialNo": "purchase-001",
+                "valueCoin": "USDT"
+            }),
+        );
+        server.mock_rest(
+            PATH_SPOT_LEVER_TOKEN_ORDER_RECORD,
+            json!({
+                "list": [{
+                    "ltCoin": "EOS3L",
+                    "orderId": "2611",
+                    "ltOrderType": 1,
+                    "orderTime": "1672294191000",
+                    "updateTime": "1672294191000",
+                    "ltOrderStatus": "2",
+                    "fee": "0.1",
+                    "amount": "200",
+                    "value": "",
+                    "valueCoin": "USDT",
+                    "serialNo": "purchase-001"
+                }]
+            }),
+        );
+        let client = signed_client(&server.url());
+        let purchase = client
+            .purchase_leveraged_token(PurchaseLeveragedTokenParams {
+                lt_coin: String::from("EOS3L"),
+                amount: n!(200.0),
+                serial_no: Some(String::from("purchase-001")),
+            })
+            .await
+            .unwrap();
+        assert_eq!(purchase.lt_order_status, LtOrderStatus::Completed);
+        assert_eq!(purchase.exec_qty, Some(n!(2.0)));
+        assert_eq!(purchase.exec_amt, None);
+
+        let orders = client
+            .get_leveraged_token_orders(GetLeveragedTokenOrdersParams {
+                lt_order_type: Some(LtOrderType::Purchase),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let order = &orders.list[0];
+        assert_eq!(order.lt_order_type, LtOrderType::Purchase);
+        assert_eq!(order.lt_order_status, LtOrderStatus::InProgress);
+        assert_eq!(order.fee, Some(n!(0.1)));
+
+        let requests = server.rest_requests();
+        assert_eq!(
+            requests[0].body,
+            r#"{"ltCoin":"EOS3L","amount":"200","serialNo":"purchase-001"}"#
+        );
+        assert_eq!(requests[1].query.as_deref(), Some("ltOrderType=1"));
+    }
+}
@@ -1,22 +1,62 @@
+mod account;
+mod announcement;
 mod api;
+mod asset;
+mod client;
+mod clock;
 mod common;
+mod credentials;
+mod endpoint;
 mod enums;
+mod error;
 mod incoming_message;
+mod instrument;
+mod leveraged_token;
+mod number;
+mod option_chain;
+mod order;
+mod orderbook;
 mod outgoing_message;
+mod paginate;
+mod position;
+mod rate_limit;
+mod record;
+mod spot_margin;
 mod stream;
+mod ticker;
 mod topic;
+mod trade;
+mod unrecognized;
 mod url;
 
+pub use announcement::AnnouncementPoller;
 pub use api::*;
+pub use client::*;
+pub use clock::*;
+pub use credentials::*;
+pub use endpoint::*;
 pub use enums::*;
+pub use error::*;
 pub use incoming_message::*;
+pub use instrument::*;
+pub use number::Number;
+pub use option_chain::*;
+pub use order::*;
+pub use orderbook::*;
 pub use outgoing_message::*;
+pub use paginate::*;
+pub use rate_limit::*;
+pub use record::*;
 pub use stream::*;
+pub use ticker::*;
 pub use topic::*;
+pub use trade::*;
+pub use unrecognized::{set_unrecognized_hook, unrecognized_values, UnrecognizedValue};
 pub use url::{
-    PATH_PRIVATE, PATH_PUBLIC_INVERSE, PATH_PUBLIC_LINEAR, PATH_PUBLIC_OPTION, PATH_PUBLIC_SPOT,
-    PATH_TRADE, URL_BASE_API_DEMO_TRADING, URL_BASE_API_MAINNET_1, URL_BASE_API_MAINNET_2,
-    URL_BASE_API_MAINNET_3, URL_BASE_API_MAINNET_4, URL_BASE_API_MAINNET_5, URL_BASE_API_MAINNET_6,
-    URL_BASE_API_TESTNET, URL_BASE_STREAM_DEMO_TRADING, URL_BASE_STREAM_MAINNET_1,
-    URL_BASE_STREAM_MAINNET_2, URL_BASE_STREAM_MAINNET_3, URL_BASE_STREAM_TESTNET,
+    PATH_MARKET_TICKERS, PATH_PRIVATE, PATH_PUBLIC_INVERSE, PATH_PUBLIC_LINEAR, PATH_PUBLIC_OPTION,
+    PATH_PUBLIC_SPOT, PATH_TRADE, URL_BASE_API_DEMO_TRADING, URL_BASE_API_MAINNET_1,
+    URL_BASE_API_MAINNET_2, URL_BASE_API_MAINNET_3, URL_BASE_API_MAINNET_4, URL_BASE_API_MAINNET_5,
+    URL_BASE_API_MAINNET_6, URL_BASE_API_TESTNET, URL_BASE_STREAM_DEMO_TRADING,
+    URL_BASE_STREAM_MAINNET_1, URL_BASE_STREAM_MAINNET_2, URL_BASE_STREAM_MAINNET_3,
+    URL_BASE_STREAM_TESTNET,
 };
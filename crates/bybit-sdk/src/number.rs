@@ -0,0 +1,178 @@
+use std::cmp::Ordering;
+
+/// Prices, quantities and rates. `f64` by default; with the `decimal` feature
+/// `rust_decimal::Decimal`, which keeps the exact value of Bybit's decimal strings.
+#[cfg(not(feature = "decimal"))]
+pub type Number = f64;
+#[cfg(feature = "decimal")]
+pub type Number = rust_decimal::Decimal;
+
+#[cfg(not(feature = "decimal"))]
+pub(crate) const ZERO: Number = 0.0;
+#[cfg(feature = "decimal")]
+pub(crate) const ZERO: Number = rust_decimal::Decimal::ZERO;
+
+/// Upper bound of filters without a limit.
+#[cfg(not(feature = "decimal"))]
+pub(crate) const UNLIMITED: Number = f64::INFINITY;
+#[cfg(feature = "decimal")]
+pub(crate) const UNLIMITED: Number = rust_decimal::Decimal::MAX;
+
+/// Total order, so prices can be map keys.
+#[cfg(not(feature = "decimal"))]
+pub(crate) fn total_cmp(a: &Number, b: &Number) -> Ordering {
+    a.total_cmp(b)
+}
+#[cfg(feature = "decimal")]
+pub(crate) fn total_cmp(a: &Number, b: &Number) -> Ordering {
+    a.cmp(b)
+}
+
+/// Whether `value` is a whole number of `step`s. Any value passes a non-positive step.
+/// Tolerates the float error of values parsed from decimal strings.
+#[cfg(not(feature = "decimal"))]
+pub(crate) fn is_multiple(value: Number, step: Number) -> bool {
+    if step <= ZERO {
+        return true;
+    }
+    let steps = value / step;
+    (steps - steps.round()).abs() < 1e-6
+}
+/// Whether `value` is a whole number of `step`s. Any value passes a non-positive step.
+#[cfg(feature = "decimal")]
+pub(crate) fn is_multiple(value: Number, step: Number) -> bool {
+    step <= ZERO || (value % step).is_zero()
+}
+
+/// `value` rounded to the nearest multiple of `step`, halves away from zero. A
+/// non-positive step keeps the value.
+#[cfg(not(feature = "decimal"))]
+pub(crate) fn round_to_step(value: Number, step: Number) -> Number {
+    if step <= ZERO {
+        return value;
+    }
+    // As in `floor_to_step`, a half step parsed from a decimal string can be a hair short.
+    let steps = value / step;
+    trim_float_error((steps + steps.signum() * 1e-9).round() * step, step)
+}
+/// `value` rounded to the nearest multiple of `step`, halves away from zero. A
+/// non-positive step keeps the value.
+#[cfg(feature = "decimal")]
+pub(crate) fn round_to_step(value: Number, step: Number) -> Number {
+    if step <= ZERO {
+        return value;
+    }
+    let steps = (value / step)
+        .round_dp_with_strategy(0, rust_decimal::RoundingStrategy::MidpointAwayFromZero);
+    (steps * step).normalize()
+}
+
+/// `value` rounded down to a multiple of `step`. A non-positive step keeps the value.
+#[cfg(not(feature = "decimal"))]
+pub(crate) fn floor_to_step(value: Number, step: Number) -> Number {
+    if step <= ZERO {
+        return value;
+    }
+    // Values parsed from decimal strings can be a hair below a whole number of steps.
+    trim_float_error(((value / step) + 1e-6).floor() * step, step)
+}
+/// `value` rounded down to a multiple of `step`. A non-positive step keeps the value.
+#[cfg(feature = "decimal")]
+pub(crate) fn floor_to_step(value: Number, step: Number) -> Number {
+    if step <= ZERO {
+        return value;
+    }
+    ((value / step).floor() * step).normalize()
+}
+
+/// Rounds `value` to the decimals of `step`, e.g. 0.30000000000000004 to 0.3.
+#[cfg(not(feature = "decimal"))]
+fn trim_float_error(value: Number, step: Number) -> Number {
+    let mut scale = 1.0;
+    while (step * scale - (step * scale).round()).abs() > 1e-9 && scale < 1e16 {
+        scale *= 10.0;
+    }
+    (value * scale).round() / scale
+}
+
+/// [`Number`] from a float literal in tests, exact with the `decimal` feature.
+/// Trailing zeros are dropped, so the literal formats like `f64`.
+#[cfg(all(test, not(feature = "decimal")))]
+macro_rules! n {
+    ($value:expr) => {{
+        let value: $crate::Number = $value;
+        value
+    }};
+}
+#[cfg(all(test, feature = "decimal"))]
+macro_rules! n {
+    ($value:expr) => {
+        stringify!($value)
+            .replace(' ', "")
+            .parse::<$crate::Number>()
+            .unwrap()
+            .normalize()
+    };
+}
+#[cfg(test)]
+pub(crate) use n;
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+    use serde_aux::prelude::{
+        deserialize_number_from_string as number,
+        deserialize_option_number_from_string as option_number,
+    };
+
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct Level {
+        #[serde(deserialize_with = "number")]
+        price: Number,
+        #[serde(deserialize_with = "option_number")]
+        size: Option<Number>,
+    }
+
+    #[test]
+    fn test_deserialize_number() {
+        let level: Level = serde_json::from_str(r#"{"price":"0.00001234","size":""}"#).unwrap();
+        assert_eq!(level.price, n!(0.00001234));
+        assert_eq!(level.size, None);
+
+        let level: Level = serde_json::from_str(r#"{"price":"-0.5","size":"12.30"}"#).unwrap();
+        assert_eq!(level.price, n!(-0.5));
+        assert_eq!(level.size, Some(n!(12.3)));
+    }
+
+    #[test]
+    fn test_round_to_step() {
+        assert_eq!(round_to_step(n!(28000.34), n!(0.1)), n!(28000.3));
+        assert_eq!(round_to_step(n!(0.35), n!(0.1)), n!(0.4));
+        assert_eq!(round_to_step(n!(0.8), n!(0.25)), n!(0.75));
+        assert_eq!(round_to_step(n!(0.01051), n!(0.0001)), n!(0.0105));
+        assert_eq!(round_to_step(n!(1.5), ZERO), n!(1.5));
+        assert_eq!(floor_to_step(n!(0.3), n!(0.1)), n!(0.3));
+        assert_eq!(floor_to_step(n!(0.0199), n!(0.001)), n!(0.019));
+        assert_eq!(floor_to_step(n!(17.0), n!(5.0)), n!(15.0));
+    }
+
+    #[test]
+    fn test_is_multiple() {
+        assert!(is_multiple(n!(28000.3), n!(0.1)));
+        assert!(is_multiple(n!(0.0105), n!(0.0001)));
+        assert!(!is_multiple(n!(0.0105), n!(0.001)));
+        assert!(is_multiple(n!(1.5), ZERO));
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_decimal_keeps_exact_value() {
+        let level: Level = serde_json::from_str(r#"{"price":"0.1","size":"0.2"}"#).unwrap();
+        assert_eq!(
+            (level.price + level.size.unwrap()).to_string(),
+            String::from("0.3")
+        );
+    }
+}
@@ -0,0 +1,631 @@
+use std::{cmp::Ordering, collections::BTreeMap, fmt, str::FromStr};
+
+use crate::{
+    number::total_cmp, Category, Client, GetTickersParams, Number, OptionTicker, OptionTickerMsg,
+    OptionTickerSnapshotMsg, Result, Ticker, Topic,
+};
+
+const MONTHS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+/// Options are delivered at 08:00 UTC.
+const DELIVERY_HOUR_MS: u64 = 8 * 60 * 60 * 1000;
+const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+
+/// Errors of symbols and tickers applied to an [`OptionChain`]. Kept apart from
+/// [`Error`](crate::Error), since they come from local bookkeeping, not from a request, and
+/// `Error::Validation` only covers orders checked against instrument filters.
+#[derive(Debug, PartialEq)]
+pub enum OptionChainError {
+    /// Not an option symbol like `BTC-27DEC24-100000-C`.
+    InvalidSymbol(String),
+    /// Option of another base coin or settle coin.
+    UnexpectedSymbol(String),
+    /// Message is older than the last applied one of the contract.
+    Stale { symbol: String, ts: u64 },
+}
+
+impl fmt::Display for OptionChainError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidSymbol(symbol) => write!(f, "invalid option symbol {symbol}"),
+            Self::UnexpectedSymbol(symbol) => write!(f, "unexpected symbol {symbol}"),
+            Self::Stale { symbol, ts } => write!(f, "stale option ticker {symbol}: ts={ts}"),
+        }
+    }
+}
+
+impl std::error::Error for OptionChainError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OptionKind {
+    Call,
+    Put,
+}
+
+/// Expiry date of an option, e.g. `27DEC24`. Ordered by date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OptionExpiry {
+    pub year: u16,
+    /// 1 to 12
+    pub month: u8,
+    pub day: u8,
+}
+
+impl OptionExpiry {
+    /// Delivery timestamp (ms), 08:00 UTC of the expiry date.
+    pub fn delivery_time(&self) -> u64 {
+        days_from_civil(self.year, self.month, self.day) * DAY_MS + DELIVERY_HOUR_MS
+    }
+}
+
+impl FromStr for OptionExpiry {
+    type Err = ();
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let split = s.find(|c: char| !c.is_ascii_digit()).ok_or(())?;
+        let (day, rest) = s.split_at(split);
+        let (month, year) = rest.split_at_checked(3).ok_or(())?;
+        let day: u8 = day.parse().map_err(|_| ())?;
+        let month = MONTHS.iter().position(|m| *m == month).ok_or(())? as u8 + 1;
+        let year: u16 = year.parse().map_err(|_| ())?;
+        if year > 99 {
+            return Err(());
+        }
+        let year = 2000 + year;
+        if !(1..=days_in_month(year, month)).contains(&day) {
+            return Err(());
+        }
+        Ok(Self { year, month, day })
+    }
+}
+
+impl fmt::Display for OptionExpiry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let month = MONTHS[usize::from(self.month - 1)];
+        write!(f, "{}{month}{:02}", self.day, self.year % 100)
+    }
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    let leap = year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+    match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 of a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: u16, month: u8, day: u8) -> u64 {
+    let year = u64::from(year) - u64::from(month <= 2);
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let month = u64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + u64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Parts of an option symbol, e.g. `BTC-27DEC24-100000-C` or `ETH-3JAN25-3500-P-USDT`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OptionSymbol {
+    pub symbol: String,
+    pub base_coin: String,
+    pub expiry: OptionExpiry,
+    pub strike: Number,
+    pub kind: OptionKind,
+    /// Only in the symbols of USDT settled options.
+    pub settle_coin: Option<String>,
+}
+
+impl FromStr for OptionSymbol {
+    type Err = OptionChainError;
+
+    fn from_str(symbol: &str) -> std::result::Result<Self, Self::Err> {
+        let invalid = || OptionChainError::InvalidSymbol(symbol.to_owned());
+        let parts: Vec<&str> = symbol.split('-').collect();
+        let (base_coin, expiry, strike, kind, settle_coin) = match parts[..] {
+            [base_coin, expiry, strike, kind] => (base_coin, expiry, strike, kind, None),
+            [base_coin, expiry, strike, kind, settle_coin] => {
+                (base_coin, expiry, strike, kind, Some(settle_coin))
+            }
+            _ => return Err(invalid()),
+        };
+        let kind = match kind {
+            "C" => OptionKind::Call,
+            "P" => OptionKind::Put,
+            _ => return Err(invalid()),
+        };
+        if base_coin.is_empty() {
+            return Err(invalid());
+        }
+        Ok(Self {
+            symbol: symbol.to_owned(),
+            base_coin: base_coin.to_owned(),
+            expiry: expiry.parse().map_err(|_| invalid())?,
+            strike: strike.parse().map_err(|_| invalid())?,
+            kind,
+            settle_coin: settle_coin.map(str::to_owned),
+        })
+    }
+}
+
+/// Latest ticker of one option contract.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OptionContract {
+    pub symbol: OptionSymbol,
+    pub bid_price: Number,
+    pub bid_size: Number,
+    pub bid_iv: Number,
+    pub ask_price: Number,
+    pub ask_size: Number,
+    pub ask_iv: Number,
+    pub last_price: Number,
+    pub mark_price: Number,
+    /// Implied volatility of the mark price
+    pub mark_iv: Number,
+    pub underlying_price: Number,
+    pub open_interest: Number,
+    pub volume24h: Number,
+    pub delta: Number,
+    pub gamma: Number,
+    pub vega: Number,
+    pub theta: Number,
+    /// Timestamp (ms) of the ticker, 0 when it came from the REST API.
+    pub ts: u64,
+}
+
+impl OptionContract {
+    fn from_ticker(symbol: OptionSymbol, ticker: &OptionTicker) -> Self {
+        Self {
+            symbol,
+            bid_price: ticker.bid1_price,
+            bid_size: ticker.bid1_size,
+            bid_iv: ticker.bid1_iv,
+            ask_price: ticker.ask1_price,
+            ask_size: ticker.ask1_size,
+            ask_iv: ticker.ask1_iv,
+            last_price: ticker.last_price,
+            mark_price: ticker.mark_price,
+            mark_iv: ticker.mark_iv,
+            underlying_price: ticker.underlying_price,
+            open_interest: ticker.open_interest,
+            volume24h: ticker.volume24h,
+            delta: ticker.delta,
+            gamma: ticker.gamma,
+            vega: ticker.vega,
+            theta: ticker.theta,
+            ts: 0,
+        }
+    }
+
+    fn from_message(symbol: OptionSymbol, data: &OptionTickerSnapshotMsg, ts: u64) -> Self {
+        Self {
+            symbol,
+            bid_price: data.bid_price,
+            bid_size: data.bid_size,
+            bid_iv: data.bid_iv,
+            ask_price: data.ask_price,
+            ask_size: data.ask_size,
+            ask_iv: data.ask_iv,
+            last_price: data.last_price,
+            mark_price: data.mark_price,
+            mark_iv: data.mark_price_iv,
+            underlying_price: data.underlying_price,
+            open_interest: data.open_interest,
+            volume24h: data.volume24h,
+            delta: data.delta,
+            gamma: data.gamma,
+            vega: data.vega,
+            theta: data.theta,
+            ts,
+        }
+    }
+}
+
+/// Call and put of one strike.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OptionStrike {
+    pub strike: Number,
+    pub call: Option<OptionContract>,
+    pub put: Option<OptionContract>,
+}
+
+/// Strike used as a map key.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Strike(Number);
+
+impl Eq for Strike {}
+
+impl PartialOrd for Strike {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Strike {
+    fn cmp(&self, other: &Self) -> Ordering {
+        total_cmp(&self.0, &other.0)
+    }
+}
+
+/// Options of one base coin and settle coin grouped by expiry and strike. USDC settled
+/// options by default, see [`OptionChain::with_settle_coin`].
+///
+/// Start from the REST tickers with [`Client::get_option_chain`] or
+/// [`OptionChain::with_tickers`], subscribe to [`OptionChain::topics`] on the option stream
+/// and [`OptionChain::apply`] the ticker messages.
+#[derive(Debug)]
+pub struct OptionChain {
+    base_coin: String,
+    /// `None` for USDC settled options, whose symbols have no settle coin.
+    settle_coin: Option<String>,
+    index_price: Option<Number>,
+    ts: u64,
+    expiries: BTreeMap<OptionExpiry, BTreeMap<Strike, OptionStrike>>,
+}
+
+impl OptionChain {
+    pub fn new(base_coin: &str) -> Self {
+        Self {
+            base_coin: base_coin.to_owned(),
+            settle_coin: None,
+            index_price: None,
+            ts: 0,
+            expiries: BTreeMap::new(),
+        }
+    }
+
+    /// Keeps the options settled in `settle_coin` instead, e.g. `USDT` for
+    /// `BTC-27DEC24-100000-C-USDT`. Call it before adding tickers.
+    pub fn with_settle_coin(mut self, settle_coin: &str) -> Self {
+        self.settle_coin = Some(settle_coin.to_owned());
+        self
+    }
+
+    /// Adds the tickers of `GET /v5/market/tickers?category=option`. Tickers of other base
+    /// coins or settle coins and symbols that cannot be parsed are skipped.
+    pub fn with_tickers(mut self, tickers: &[OptionTicker]) -> Self {
+        for ticker in tickers {
+            match self.parse(&ticker.symbol) {
+                Ok(symbol) => {
+                    self.index_price.get_or_insert(ticker.index_price);
+                    self.insert(OptionContract::from_ticker(symbol, ticker));
+                }
+                Err(e) => tracing::debug!("Option ticker skipped: {e}"),
+            }
+        }
+        self
+    }
+
+    pub fn base_coin(&self) -> &str {
+        &self.base_coin
+    }
+
+    /// `None` for USDC settled options.
+    pub fn settle_coin(&self) -> Option<&str> {
+        self.settle_coin.as_deref()
+    }
+
+    /// Index price of the base coin from the latest ticker.
+    pub fn index_price(&self) -> Option<Number> {
+        self.index_price
+    }
+
+    /// Timestamp (ms) of the last applied message.
+    pub fn ts(&self) -> u64 {
+        self.ts
+    }
+
+    /// Number of contracts.
+    pub fn len(&self) -> usize {
+        self.contracts().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.expiries.is_empty()
+    }
+
+    /// Expiries, nearest first.
+    pub fn expiries(&self) -> impl Iterator<Item = &OptionExpiry> {
+        self.expiries.keys()
+    }
+
+    /// Strikes of `expiry`, lowest first.
+    pub fn strikes(&self, expiry: &OptionExpiry) -> impl Iterator<Item = &OptionStrike> {
+        self.expiries
+            .get(expiry)
+            .into_iter()
+            .flat_map(|s| s.values())
+    }
+
+    pub fn contracts(&self) -> impl Iterator<Item = &OptionContract> {
+        self.expiries
+            .values()
+            .flat_map(|strikes| strikes.values())
+            .flat_map(|strike| strike.call.iter().chain(strike.put.iter()))
+    }
+
+    pub fn contract(&self, symbol: &str) -> Option<&OptionContract> {
+        let symbol: OptionSymbol = symbol.parse().ok()?;
+        let strike = self
+            .expiries
+            .get(&symbol.expiry)?
+            .get(&Strike(symbol.strike))?;
+        match symbol.kind {
+            OptionKind::Call => strike.call.as_ref(),
+            OptionKind::Put => strike.put.as_ref(),
+        }
+        .filter(|contract| contract.symbol.symbol == symbol.symbol)
+    }
+
+    /// Ticker topics of all contracts, for the option stream.
+    pub fn topics(&self) -> Vec<Topic> {
+        self.contracts()
+            .map(|contract| Topic::ticker(&contract.symbol.symbol))
+            .collect()
+    }
+
+    /// Replaces the contract of the message; adds it when it is new, e.g. a freshly listed
+    /// strike.
+    pub fn apply(
+        &mut self,
+        message: &OptionTickerMsg,
+    ) -> std::result::Result<(), OptionChainError> {
+        let OptionTickerMsg::Snapshot { ts, data, .. } = message;
+        let symbol = self.parse(&data.symbol)?;
+        if self
+            .contract(&data.symbol)
+            .is_some_and(|contract| *ts < contract.ts)
+        {
+            return Err(OptionChainError::Stale {
+                symbol: data.symbol.clone(),
+                ts: *ts,
+            });
+        }
+        if *ts >= self.ts {
+            self.index_price = Some(data.index_price);
+            self.ts = *ts;
+        }
+        self.insert(OptionContract::from_message(symbol, data, *ts));
+        Ok(())
+    }
+
+    /// Drops the expiries delivered before `now` (ms).
+    pub fn remove_expired(&mut self, now: u64) {
+        self.expiries
+            .retain(|expiry, _| expiry.delivery_time() > now);
+    }
+
+    fn parse(&self, symbol: &str) -> std::result::Result<OptionSymbol, OptionChainError> {
+        let parsed: OptionSymbol = symbol.parse()?;
+        if parsed.base_coin != self.base_coin || parsed.settle_coin != self.settle_coin {
+            return Err(OptionChainError::UnexpectedSymbol(symbol.to_owned()));
+        }
+        Ok(parsed)
+    }
+
+    fn insert(&mut self, contract: OptionContract) {
+        let strike = contract.symbol.strike;
+        let entry = self
+            .expiries
+            .entry(contract.symbol.expiry)
+            .or_default()
+            .entry(Strike(strike))
+            .or_insert(OptionStrike {
+                strike,
+                call: None,
+                put: None,
+            });
+        match contract.symbol.kind {
+            OptionKind::Call => entry.call = Some(contract),
+            OptionKind::Put => entry.put = Some(contract),
+        }
+    }
+}
+
+impl Client {
+    /// Option chain of the USDC settled options of `base_coin` from the REST tickers.
+    pub async fn get_option_chain(&self, base_coin: &str) -> Result<OptionChain> {
+        let params = GetTickersParams {
+            category: Category::Option,
+            symbol: None,
+            base_coin: Some(base_coin.to_owned()),
+            exp_date: None,
+        };
+        let tickers = match self.get_tickers(params).await? {
+            Ticker::Option { list } => list,
+            _ => Vec::new(),
+        };
+        Ok(OptionChain::new(base_coin).with_tickers(&tickers))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bybit_mock::MockServer;
+
+    use super::*;
+    use crate::{common::deserialize_slice, number::n, url::PATH_MARKET_TICKERS};
+
+    fn ticker(symbol: &str, mark_iv: &str, delta: &str) -> serde_json::Value {
+        serde_json::json!({
+            "symbol": symbol,
+            "bid1Price": "0",
+            "bid1Size": "0",
+            "bid1Iv": "0",
+            "ask1Price": "5",
+            "ask1Size": "1.5",
+            "ask1Iv": "0.6",
+            "lastPrice": "10",
+            "highPrice24h": "25",
+            "lowPrice24h": "5",
+            "markPrice": "7.86",
+            "indexPrice": "16823.73",
+            "markIv": mark_iv,
+            "underlyingPrice": "16815.1",
+            "openInterest": "49.85",
+            "turnover24h": "446802.8473",
+            "volume24h": "26.55",
+            "totalVolume": "86",
+            "totalTurnover": "1437431",
+            "delta": delta,
+            "gamma": "0.00021453",
+            "vega": "0.81351067",
+            "theta": "-19.9115368",
+            "predictedDeliveryPrice": "0",
+            "change24h": "-0.33333334"
+        })
+    }
+
+    #[test]
+    fn test_option_symbol() {
+        let symbol: OptionSymbol = "BTC-6JAN23-17500-C".parse().unwrap();
+        assert_eq!(symbol.base_coin, "BTC");
+        assert_eq!(
+            symbol.expiry,
+            OptionExpiry {
+                year: 2023,
+                month: 1,
+                day: 6
+            }
+        );
+        assert_eq!(symbol.expiry.to_string(), "6JAN23");
+        assert_eq!(symbol.expiry.delivery_time(), 1672992000000);
+        assert_eq!(symbol.strike, n!(17500.0));
+        assert_eq!(symbol.kind, OptionKind::Call);
+        assert_eq!(symbol.settle_coin, None);
+
+        let symbol: OptionSymbol = "ETH-28FEB25-2850.5-P-USDT".parse().unwrap();
+        assert_eq!(symbol.strike, n!(2850.5));
+        assert_eq!(symbol.kind, OptionKind::Put);
+        assert_eq!(symbol.settle_coin.as_deref(), Some("USDT"));
+
+        let symbol: OptionSymbol = "BTC-29FEB28-90000-C".parse().unwrap();
+        assert_eq!(symbol.expiry.to_string(), "29FEB28");
+
+        for invalid in [
+            "BTCUSDT",
+            "BTC-6JAN23-17500-X",
+            "BTC-6JXN23-17500-C",
+            "BTC-6JAN23-a-P",
+            "BTC-31APR25-3000-C",
+            "BTC-29FEB25-3000-C",
+            "BTC-0MAR25-3000-C",
+        ] {
+            assert_eq!(
+                invalid.parse::<OptionSymbol>(),
+                Err(OptionChainError::InvalidSymbol(invalid.to_owned()))
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_option_chain() {
+        let server = MockServer::start().await;
+        server.mock_rest(
+            PATH_MARKET_TICKERS,
+            serde_json::json!({
+                "category": "option",
+                "list": [
+                    ticker("BTC-27DEC24-100000-C", "0.55", "0.4"),
+                    ticker("BTC-6JAN23-18000-P", "0.5", "-0.6"),
+                    ticker("BTC-6JAN23-17500-C", "0.4896", "0.047831"),
+                    ticker("BTC-6JAN23-17500-P", "0.49", "-0.95"),
+                    ticker("BTC-6JAN23-17500-C-USDT", "0.7", "0.1"),
+                ]
+            }),
+        );
+        let mut chain = Client::new(&server.url())
+            .get_option_chain("BTC")
+            .await
+            .unwrap();
+        assert_eq!(
+            server.rest_requests()[0].query.as_deref(),
+            Some("category=option&baseCoin=BTC")
+        );
+
+        assert_eq!(chain.len(), 4);
+        assert_eq!(chain.index_price(), Some(n!(16823.73)));
+        let expiries: Vec<String> = chain.expiries().map(|e| e.to_string()).collect();
+        assert_eq!(expiries, ["6JAN23", "27DEC24"]);
+        let expiry = *chain.expiries().next().unwrap();
+        let strikes: Vec<&OptionStrike> = chain.strikes(&expiry).collect();
+        assert_eq!(strikes[0].strike, n!(17500.0));
+        assert_eq!(strikes[0].call.as_ref().unwrap().mark_iv, n!(0.4896));
+        assert_eq!(strikes[0].put.as_ref().unwrap().delta, n!(-0.95));
+        assert!(strikes[1].call.is_none());
+        assert_eq!(chain.topics()[0], Topic::ticker("BTC-6JAN23-17500-C"));
+
+        let json = r#"{"topic":"tickers.BTC-6JAN23-17500-C","ts":1672917511074,"type":"snapshot","data":{"symbol":"BTC-6JAN23-17500-C","bidPrice":"0","bidSize":"0","bidIv":"0","askPrice":"10","askSize":"5.1","askIv":"0.514","lastPrice":"10","highPrice24h":"25","lowPrice24h":"5","markPrice":"7.86976724","indexPrice":"16830","markPriceIv":"0.51","underlyingPrice":"16815.1","openInterest":"49.85","turnover24h":"446802.8473","volume24h":"26.55","totalVolume":"86","totalTurnover":"1437431","delta":"0.05","gamma":"0.0002","vega":"0.8","theta":"-20","predictedDeliveryPrice":"0","change24h":"-0.33333334"}}"#;
+        let message: OptionTickerMsg = deserialize_slice(json.as_bytes()).unwrap();
+        chain.apply(&message).unwrap();
+        let contract = chain.contract("BTC-6JAN23-17500-C").unwrap();
+        assert_eq!(contract.mark_iv, n!(0.51));
+        assert_eq!(contract.delta, n!(0.05));
+        assert_eq!(contract.theta, n!(-20.0));
+        assert_eq!(contract.ts, 1672917511074);
+        assert_eq!(chain.index_price(), Some(n!(16830.0)));
+        assert_eq!(chain.len(), 4);
+
+        let OptionTickerMsg::Snapshot { topic, data, .. } = message;
+        let stale = OptionTickerMsg::Snapshot {
+            topic,
+            ts: 1672917511000,
+            data,
+        };
+        assert_eq!(
+            chain.apply(&stale),
+            Err(OptionChainError::Stale {
+                symbol: String::from("BTC-6JAN23-17500-C"),
+                ts: 1672917511000
+            })
+        );
+
+        chain.remove_expired(1672992000000);
+        assert_eq!(chain.len(), 1);
+        assert!(chain.contract("BTC-27DEC24-100000-C").is_some());
+    }
+
+    #[test]
+    fn test_option_chain_settle_coin() {
+        let tickers: Vec<OptionTicker> = serde_json::from_value(serde_json::json!([
+            ticker("BTC-6JAN23-17500-C", "0.4896", "0.047831"),
+            ticker("BTC-6JAN23-17500-C-USDT", "0.7", "0.1"),
+        ]))
+        .unwrap();
+
+        let chain = OptionChain::new("BTC").with_tickers(&tickers);
+        assert_eq!(chain.len(), 1);
+        assert_eq!(
+            chain.contract("BTC-6JAN23-17500-C").unwrap().mark_iv,
+            n!(0.4896)
+        );
+        assert!(chain.contract("BTC-6JAN23-17500-C-USDT").is_none());
+
+        let mut chain = OptionChain::new("BTC")
+            .with_settle_coin("USDT")
+            .with_tickers(&tickers);
+        assert_eq!(chain.settle_coin(), Some("USDT"));
+        assert_eq!(chain.len(), 1);
+        assert_eq!(
+            chain.contract("BTC-6JAN23-17500-C-USDT").unwrap().mark_iv,
+            n!(0.7)
+        );
+        let json = r#"{"topic":"tickers.BTC-6JAN23-17500-C","ts":1672917511074,"type":"snapshot","data":{"symbol":"BTC-6JAN23-17500-C","bidPrice":"0","bidSize":"0","bidIv":"0","askPrice":"10","askSize":"5.1","askIv":"0.514","lastPrice":"10","highPrice24h":"25","lowPrice24h":"5","markPrice":"7.86976724","indexPrice":"16830","markPriceIv":"0.51","underlyingPrice":"16815.1","openInterest":"49.85","turnover24h":"446802.8473","volume24h":"26.55","totalVolume":"86","totalTurnover":"1437431","delta":"0.05","gamma":"0.0002","vega":"0.8","theta":"-20","predictedDeliveryPrice":"0","change24h":"-0.33333334"}}"#;
+        let message: OptionTickerMsg = deserialize_slice(json.as_bytes()).unwrap();
+        assert_eq!(
+            chain.apply(&message),
+            Err(OptionChainError::UnexpectedSymbol(String::from(
+                "BTC-6JAN23-17500-C"
+            )))
+        );
+        assert_eq!(
+            chain.contract("BTC-6JAN23-17500-C-USDT").unwrap().mark_iv,
+            n!(0.7)
+        );
+    }
+}
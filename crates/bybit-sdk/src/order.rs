@@ -0,0 +1,931 @@
+use serde_json::Value;
+use std::fmt;
+
+use crate::{
+    number::{floor_to_step, is_multiple, round_to_step, UNLIMITED, ZERO},
+    trade::batch_acks,
+    url::{
+        PATH_ORDER_AMEND, PATH_ORDER_AMEND_BATCH, PATH_ORDER_CANCEL, PATH_ORDER_CANCEL_BATCH,
+        PATH_ORDER_CREATE, PATH_ORDER_CREATE_BATCH,
+    },
+    AllCategoriesInstrumentsInfo, AmendOrderParams, BatchOrderParams, CancelOrderParams, Category,
+    Client, CreateOrderParams, Error, LotSizeFilter, Number, OptionInstrumentsInfo, OrderAck,
+    OrderId, OrderType, PositionIdx, PriceFilter, Response, Result, Side, SpotInstrumentsInfo,
+    SpotLotSizeFilter, SpotPriceFilter, TimeInForce, TradeError, TradeOp, TriggerBy,
+    TriggerDirection,
+};
+
+/// `marketUnit` of spot market orders with qty in quote coin.
+const MARKET_UNIT_QUOTE_COIN: &str = "quoteCoin";
+
+/// Order rejected locally, before it is sent to Bybit.
+#[derive(Debug, PartialEq)]
+pub enum OrderValidationError {
+    /// Limit order without price.
+    MissingPrice,
+    /// Amend or cancel request without `orderId` and `orderLinkId`.
+    MissingOrderId,
+    /// Price of `field` is not a multiple of the tick size.
+    Tick {
+        field: &'static str,
+        price: Number,
+        tick_size: Number,
+    },
+    PriceOutOfRange {
+        price: Number,
+        min: Number,
+        max: Number,
+    },
+    /// Qty is not a multiple of the qty step.
+    Step { qty: Number, qty_step: Number },
+    QtyOutOfRange {
+        qty: Number,
+        min: Number,
+        max: Number,
+    },
+    NotionalOutOfRange {
+        notional: Number,
+        min: Number,
+        max: Number,
+    },
+}
+
+impl fmt::Display for OrderValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::MissingPrice => write!(f, "limit order without price"),
+            Self::MissingOrderId => write!(f, "either orderId or orderLinkId is required"),
+            Self::Tick {
+                field,
+                price,
+                tick_size,
+            } => write!(
+                f,
+                "{field} {price} is not a multiple of tick size {tick_size}"
+            ),
+            Self::PriceOutOfRange { price, min, max } => {
+                write!(f, "price {price} is out of range [{min}, {max}]")
+            }
+            Self::Step { qty, qty_step } => {
+                write!(f, "qty {qty} is not a multiple of qty step {qty_step}")
+            }
+            Self::QtyOutOfRange { qty, min, max } => {
+                write!(f, "qty {qty} is out of range [{min}, {max}]")
+            }
+            Self::NotionalOutOfRange { notional, min, max } => {
+                write!(f, "order value {notional} is out of range [{min}, {max}]")
+            }
+        }
+    }
+}
+
+impl std::error::Error for OrderValidationError {}
+
+/// Price and size constraints of an instrument, from instruments info.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstrumentFilters {
+    pub tick_size: Number,
+    pub min_price: Number,
+    pub max_price: Number,
+    pub qty_step: Number,
+    pub min_qty: Number,
+    pub max_qty: Number,
+    /// Max qty of market orders.
+    pub max_market_qty: Number,
+    /// Max qty of `PostOnly` orders.
+    pub max_post_only_qty: Number,
+    /// Min and max order value (`qty * price`) in quote coin.
+    pub min_notional: Number,
+    pub max_notional: Number,
+    /// Step of spot market orders with qty in quote coin.
+    pub quote_step: Option<Number>,
+}
+
+impl InstrumentFilters {
+    /// Linear and inverse instruments.
+    pub fn new(price_filter: &PriceFilter, lot_size_filter: &LotSizeFilter) -> Self {
+        Self {
+            tick_size: price_filter.tick_size,
+            min_price: price_filter.min_price,
+            max_price: price_filter.max_price,
+            qty_step: lot_size_filter.qty_step,
+            min_qty: lot_size_filter.min_order_qty,
+            max_qty: lot_size_filter.max_order_qty,
+            max_market_qty: lot_size_filter.max_mkt_order_qty,
+            max_post_only_qty: lot_size_filter.post_only_max_order_qty,
+            min_notional: lot_size_filter.min_notional_value,
+            max_notional: UNLIMITED,
+            quote_step: None,
+        }
+    }
+
+    /// Spot instruments have no price range and limit the order value instead.
+    pub fn spot(price_filter: &SpotPriceFilter, lot_size_filter: &SpotLotSizeFilter) -> Self {
+        Self {
+            tick_size: price_filter.tick_size,
+            min_price: ZERO,
+            max_price: UNLIMITED,
+            qty_step: lot_size_filter.base_precision,
+            min_qty: lot_size_filter.min_order_qty,
+            max_qty: lot_size_filter.max_order_qty,
+            max_market_qty: lot_size_filter.max_order_qty,
+            max_post_only_qty: lot_size_filter.max_order_qty,
+            min_notional: lot_size_filter.min_order_amt,
+            max_notional: lot_size_filter.max_order_amt,
+            quote_step: Some(lot_size_filter.quote_precision),
+        }
+    }
+
+    /// `price` rounded to the nearest tick.
+    pub fn round_price(&self, price: Number) -> Number {
+        round_to_step(price, self.tick_size)
+    }
+
+    /// `qty` rounded down to the qty step, so it never exceeds the intended size.
+    pub fn round_qty(&self, qty: Number) -> Number {
+        floor_to_step(qty, self.qty_step)
+    }
+
+    fn check_price(
+        &self,
+        field: &'static str,
+        price: Number,
+    ) -> std::result::Result<(), OrderValidationError> {
+        if !is_multiple(price, self.tick_size) {
+            return Err(OrderValidationError::Tick {
+                field,
+                price,
+                tick_size: self.tick_size,
+            });
+        }
+        Ok(())
+    }
+
+    fn check_limit_price(&self, price: Number) -> std::result::Result<(), OrderValidationError> {
+        self.check_price("price", price)?;
+        if price < self.min_price || price > self.max_price {
+            return Err(OrderValidationError::PriceOutOfRange {
+                price,
+                min: self.min_price,
+                max: self.max_price,
+            });
+        }
+        Ok(())
+    }
+
+    fn check_qty(&self, qty: Number, max: Number) -> std::result::Result<(), OrderValidationError> {
+        if !is_multiple(qty, self.qty_step) {
+            return Err(OrderValidationError::Step {
+                qty,
+                qty_step: self.qty_step,
+            });
+        }
+        if qty < self.min_qty || qty > max {
+            return Err(OrderValidationError::QtyOutOfRange {
+                qty,
+                min: self.min_qty,
+                max,
+            });
+        }
+        Ok(())
+    }
+
+    fn check_notional(&self, notional: Number) -> std::result::Result<(), OrderValidationError> {
+        if notional < self.min_notional || notional > self.max_notional {
+            return Err(OrderValidationError::NotionalOutOfRange {
+                notional,
+                min: self.min_notional,
+                max: self.max_notional,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl From<&AllCategoriesInstrumentsInfo> for InstrumentFilters {
+    fn from(info: &AllCategoriesInstrumentsInfo) -> Self {
+        Self::new(&info.price_filter, &info.lot_size_filter)
+    }
+}
+
+impl From<&OptionInstrumentsInfo> for InstrumentFilters {
+    /// Options have no separate market or post-only limit and no minimum order value.
+    fn from(info: &OptionInstrumentsInfo) -> Self {
+        let lot_size_filter = &info.lot_size_filter;
+        Self {
+            tick_size: info.price_filter.tick_size,
+            min_price: info.price_filter.min_price,
+            max_price: info.price_filter.max_price,
+            qty_step: lot_size_filter.qty_step,
+            min_qty: lot_size_filter.min_order_qty,
+            max_qty: lot_size_filter.max_order_qty,
+            max_market_qty: lot_size_filter.max_order_qty,
+            max_post_only_qty: lot_size_filter.max_order_qty,
+            min_notional: ZERO,
+            max_notional: UNLIMITED,
+            quote_step: None,
+        }
+    }
+}
+
+impl From<&SpotInstrumentsInfo> for InstrumentFilters {
+    fn from(info: &SpotInstrumentsInfo) -> Self {
+        Self::spot(&info.price_filter, &info.lot_size_filter)
+    }
+}
+
+impl CreateOrderParams {
+    pub fn new(
+        category: Category,
+        symbol: &str,
+        side: Side,
+        order_type: OrderType,
+        qty: Number,
+    ) -> Self {
+        Self {
+            category,
+            symbol: symbol.to_owned(),
+            is_leverage: None,
+            side,
+            order_type,
+            qty,
+            market_unit: None,
+            price: None,
+            trigger_direction: None,
+            order_filter: None,
+            trigger_price: None,
+            trigger_by: None,
+            order_iv: None,
+            time_in_force: None,
+            position_idx: None,
+            order_link_id: None,
+            take_profit: None,
+            stop_loss: None,
+            tp_trigger_by: None,
+            sl_trigger_by: None,
+            reduce_only: None,
+            close_on_trigger: None,
+            smp_type: None,
+            mmp: None,
+            tpsl_mode: None,
+            tp_limit_price: None,
+            sl_limit_price: None,
+            tp_order_type: None,
+            sl_order_type: None,
+        }
+    }
+
+    pub fn limit(category: Category, symbol: &str, side: Side, qty: Number, price: Number) -> Self {
+        Self {
+            price: Some(price),
+            ..Self::new(category, symbol, side, OrderType::Limit, qty)
+        }
+    }
+
+    pub fn market(category: Category, symbol: &str, side: Side, qty: Number) -> Self {
+        Self::new(category, symbol, side, OrderType::Market, qty)
+    }
+
+    pub fn with_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    pub fn with_order_link_id(mut self, order_link_id: &str) -> Self {
+        self.order_link_id = Some(order_link_id.to_owned());
+        self
+    }
+
+    pub fn with_position_idx(mut self, position_idx: PositionIdx) -> Self {
+        self.position_idx = Some(position_idx);
+        self
+    }
+
+    pub fn with_reduce_only(mut self, reduce_only: bool) -> Self {
+        self.reduce_only = Some(reduce_only);
+        self
+    }
+
+    /// Spot market orders only: `qty` is in quote coin.
+    pub fn with_qty_in_quote_coin(mut self) -> Self {
+        self.market_unit = Some(String::from(MARKET_UNIT_QUOTE_COIN));
+        self
+    }
+
+    /// Makes the order conditional.
+    pub fn with_trigger(
+        mut self,
+        trigger_price: Number,
+        trigger_direction: TriggerDirection,
+        trigger_by: TriggerBy,
+    ) -> Self {
+        self.trigger_price = Some(trigger_price);
+        self.trigger_direction = Some(trigger_direction);
+        self.trigger_by = Some(trigger_by);
+        self
+    }
+
+    pub fn with_take_profit(mut self, take_profit: Number) -> Self {
+        self.take_profit = Some(take_profit);
+        self
+    }
+
+    pub fn with_stop_loss(mut self, stop_loss: Number) -> Self {
+        self.stop_loss = Some(stop_loss);
+        self
+    }
+
+    /// Checks prices against the tick size and range, qty against the qty step and the limits
+    /// of the order type, and the order value of limit orders.
+    /// The value of market orders is unknown and not checked, nor is the value of inverse
+    /// orders, whose qty is in USD contracts.
+    pub fn validate(
+        &self,
+        filters: &InstrumentFilters,
+    ) -> std::result::Result<(), OrderValidationError> {
+        if self.order_type == OrderType::Limit {
+            let price = self.price.ok_or(OrderValidationError::MissingPrice)?;
+            filters.check_limit_price(price)?;
+        }
+        check_trigger_prices(
+            filters,
+            [
+                ("triggerPrice", self.trigger_price),
+                ("takeProfit", self.take_profit),
+                ("stopLoss", self.stop_loss),
+                ("tpLimitPrice", self.tp_limit_price),
+                ("slLimitPrice", self.sl_limit_price),
+            ],
+        )?;
+
+        let quote_step = filters
+            .quote_step
+            .filter(|_| self.market_unit.as_deref() == Some(MARKET_UNIT_QUOTE_COIN));
+        if let Some(quote_step) = quote_step {
+            if !is_multiple(self.qty, quote_step) {
+                return Err(OrderValidationError::Step {
+                    qty: self.qty,
+                    qty_step: quote_step,
+                });
+            }
+            return filters.check_notional(self.qty);
+        }
+
+        let max_qty = match (&self.order_type, &self.time_in_force) {
+            (OrderType::Market, _) => filters.max_market_qty,
+            (_, Some(TimeInForce::PostOnly)) => filters.max_post_only_qty,
+            _ => filters.max_qty,
+        };
+        filters.check_qty(self.qty, max_qty)?;
+        if let (OrderType::Limit, Some(price)) = (&self.order_type, self.price) {
+            check_order_value(filters, &self.category, self.qty, price)?;
+        }
+        Ok(())
+    }
+}
+
+impl AmendOrderParams {
+    pub fn by_order_id(category: Category, symbol: &str, order_id: &str) -> Self {
+        Self {
+            order_id: Some(order_id.to_owned()),
+            ..Self::new(category, symbol)
+        }
+    }
+
+    pub fn by_order_link_id(category: Category, symbol: &str, order_link_id: &str) -> Self {
+        Self {
+            order_link_id: Some(order_link_id.to_owned()),
+            ..Self::new(category, symbol)
+        }
+    }
+
+    fn new(category: Category, symbol: &str) -> Self {
+        Self {
+            category,
+            symbol: symbol.to_owned(),
+            order_id: None,
+            order_link_id: None,
+            order_iv: None,
+            trigger_price: None,
+            qty: None,
+            price: None,
+            tpsl_mode: None,
+            take_profit: None,
+            stop_loss: None,
+            tp_trigger_by: None,
+            sl_trigger_by: None,
+            trigger_by: None,
+            tp_limit_price: None,
+            sl_limit_price: None,
+        }
+    }
+
+    pub fn with_qty(mut self, qty: Number) -> Self {
+        self.qty = Some(qty);
+        self
+    }
+
+    pub fn with_price(mut self, price: Number) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    pub fn with_trigger_price(mut self, trigger_price: Number) -> Self {
+        self.trigger_price = Some(trigger_price);
+        self
+    }
+
+    /// Zero cancels the take profit.
+    pub fn with_take_profit(mut self, take_profit: Number) -> Self {
+        self.take_profit = Some(take_profit);
+        self
+    }
+
+    /// Zero cancels the stop loss.
+    pub fn with_stop_loss(mut self, stop_loss: Number) -> Self {
+        self.stop_loss = Some(stop_loss);
+        self
+    }
+
+    /// Checks the amended fields like [`CreateOrderParams::validate`] does. The order value is
+    /// only checked when both qty and price are amended.
+    pub fn validate(
+        &self,
+        filters: &InstrumentFilters,
+    ) -> std::result::Result<(), OrderValidationError> {
+        if self.order_id.is_none() && self.order_link_id.is_none() {
+            return Err(OrderValidationError::MissingOrderId);
+        }
+        if let Some(price) = self.price {
+            filters.check_limit_price(price)?;
+        }
+        check_trigger_prices(
+            filters,
+            [
+                ("triggerPrice", self.trigger_price),
+                ("takeProfit", self.take_profit),
+                ("stopLoss", self.stop_loss),
+                ("tpLimitPrice", self.tp_limit_price),
+                ("slLimitPrice", self.sl_limit_price),
+            ],
+        )?;
+        if let Some(qty) = self.qty {
+            filters.check_qty(qty, filters.max_qty)?;
+        }
+        if let (Some(qty), Some(price)) = (self.qty, self.price) {
+            check_order_value(filters, &self.category, qty, price)?;
+        }
+        Ok(())
+    }
+}
+
+impl CancelOrderParams {
+    pub fn by_order_id(category: Category, symbol: &str, order_id: &str) -> Self {
+        Self {
+            category,
+            symbol: symbol.to_owned(),
+            order_id: Some(order_id.to_owned()),
+            order_link_id: None,
+            order_filter: None,
+        }
+    }
+
+    pub fn by_order_link_id(category: Category, symbol: &str, order_link_id: &str) -> Self {
+        Self {
+            category,
+            symbol: symbol.to_owned(),
+            order_id: None,
+            order_link_id: Some(order_link_id.to_owned()),
+            order_filter: None,
+        }
+    }
+
+    pub fn validate(&self) -> std::result::Result<(), OrderValidationError> {
+        if self.order_id.is_none() && self.order_link_id.is_none() {
+            return Err(OrderValidationError::MissingOrderId);
+        }
+        Ok(())
+    }
+}
+
+impl<T> BatchOrderParams<T> {
+    pub fn new(category: Category, request: Vec<T>) -> Self {
+        Self { category, request }
+    }
+}
+
+/// `qty * price` against the order value limits in quote coin. Inverse qty is in USD
+/// contracts, so the value would be `qty / price` in coin, which the filters do not limit.
+fn check_order_value(
+    filters: &InstrumentFilters,
+    category: &Category,
+    qty: Number,
+    price: Number,
+) -> std::result::Result<(), OrderValidationError> {
+    match category {
+        Category::Inverse => Ok(()),
+        _ => filters.check_notional(qty * price),
+    }
+}
+
+fn check_trigger_prices<const N: usize>(
+    filters: &InstrumentFilters,
+    prices: [(&'static str, Option<Number>); N],
+) -> std::result::Result<(), OrderValidationError> {
+    for (field, price) in prices {
+        if let Some(price) = price {
+            filters.check_price(field, price)?;
+        }
+    }
+    Ok(())
+}
+
+impl Client {
+    pub async fn create_order(&self, params: CreateOrderParams) -> Result<OrderId> {
+        self.post_signed(PATH_ORDER_CREATE, &params).await
+    }
+
+    pub async fn amend_order(&self, params: AmendOrderParams) -> Result<OrderId> {
+        self.post_signed(PATH_ORDER_AMEND, &params).await
+    }
+
+    pub async fn cancel_order(&self, params: CancelOrderParams) -> Result<OrderId> {
+        self.post_signed(PATH_ORDER_CANCEL, &params).await
+    }
+
+    /// Results are in the order of the batch.
+    pub async fn create_batch_order(
+        &self,
+        params: BatchOrderParams<CreateOrderParams>,
+    ) -> Result<Vec<std::result::Result<OrderAck, TradeError>>> {
+        self.batch_order(TradeOp::CreateBatch, PATH_ORDER_CREATE_BATCH, &params)
+            .await
+    }
+
+    pub async fn amend_batch_order(
+        &self,
+        params: BatchOrderParams<AmendOrderParams>,
+    ) -> Result<Vec<std::result::Result<OrderAck, TradeError>>> {
+        self.batch_order(TradeOp::AmendBatch, PATH_ORDER_AMEND_BATCH, &params)
+            .await
+    }
+
+    pub async fn cancel_batch_order(
+        &self,
+        params: BatchOrderParams<CancelOrderParams>,
+    ) -> Result<Vec<std::result::Result<OrderAck, TradeError>>> {
+        self.batch_order(TradeOp::CancelBatch, PATH_ORDER_CANCEL_BATCH, &params)
+            .await
+    }
+
+    async fn batch_order<T>(
+        &self,
+        op: TradeOp,
+        path: &str,
+        params: &BatchOrderParams<T>,
+    ) -> Result<Vec<std::result::Result<OrderAck, TradeError>>>
+    where
+        T: serde::Serialize,
+    {
+        let response: Response<Value, Value> = self.post_signed_response(path, params).await?;
+        if response.ret_code != 0 {
+            return Err(Error::api(response.ret_code, response.ret_msg));
+        }
+        batch_acks(op, response.result, response.ret_ext_info)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bybit_mock::MockServer;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{client::tests::signed_client, common::serialize, number::n, RejectReason};
+
+    fn linear_filters() -> InstrumentFilters {
+        InstrumentFilters::new(
+            &PriceFilter {
+                min_price: n!(0.1),
+                max_price: n!(1999999.8),
+                tick_size: n!(0.1),
+            },
+            &LotSizeFilter {
+                min_notional_value: n!(5.0),
+                max_order_qty: n!(1190.0),
+                max_mkt_order_qty: n!(500.0),
+                min_order_qty: n!(0.001),
+                qty_step: n!(0.001),
+                post_only_max_order_qty: n!(1190.0),
+            },
+        )
+    }
+
+    fn spot_filters() -> InstrumentFilters {
+        InstrumentFilters::spot(
+            &SpotPriceFilter {
+                tick_size: n!(0.01),
+            },
+            &SpotLotSizeFilter {
+                base_precision: n!(0.000001),
+                quote_precision: n!(0.00000001),
+                min_order_qty: n!(0.000048),
+                max_order_qty: n!(71.73956243),
+                min_order_amt: n!(1.0),
+                max_order_amt: n!(2000000.0),
+            },
+        )
+    }
+
+    #[test]
+    fn test_serialize_create_order_builder() {
+        let params = CreateOrderParams::limit(
+            Category::Linear,
+            "BTCUSDT",
+            Side::Buy,
+            n!(0.01),
+            n!(28000.5),
+        )
+        .with_time_in_force(TimeInForce::PostOnly)
+        .with_order_link_id("link-1")
+        .with_reduce_only(false);
+        assert_eq!(
+            serialize(&params).unwrap(),
+            r#"{"category":"linear","symbol":"BTCUSDT","side":"Buy","orderType":"Limit","qty":"0.01","price":"28000.5","timeInForce":"PostOnly","orderLinkId":"link-1","reduceOnly":false}"#
+        );
+
+        let params = AmendOrderParams::by_order_link_id(Category::Spot, "ETHUSDT", "link-2")
+            .with_price(n!(1800.25));
+        assert_eq!(
+            serialize(&params).unwrap(),
+            r#"{"category":"spot","symbol":"ETHUSDT","orderLinkId":"link-2","price":"1800.25"}"#
+        );
+    }
+
+    #[test]
+    fn test_validate_create_order() {
+        let filters = linear_filters();
+        let order = |qty, price| {
+            CreateOrderParams::limit(Category::Linear, "BTCUSDT", Side::Buy, qty, price)
+        };
+
+        assert_eq!(order(n!(0.003), n!(28000.3)).validate(&filters), Ok(()));
+        assert_eq!(
+            order(n!(0.003), n!(28000.35)).validate(&filters),
+            Err(OrderValidationError::Tick {
+                field: "price",
+                price: n!(28000.35),
+                tick_size: n!(0.1),
+            })
+        );
+        assert_eq!(
+            order(n!(0.0035), n!(28000.3)).validate(&filters),
+            Err(OrderValidationError::Step {
+                qty: n!(0.0035),
+                qty_step: n!(0.001),
+            })
+        );
+        assert_eq!(
+            order(n!(0.0001), n!(28000.3)).validate(&filters),
+            Err(OrderValidationError::Step {
+                qty: n!(0.0001),
+                qty_step: n!(0.001),
+            })
+        );
+        assert_eq!(
+            order(n!(0.001), n!(2000.0)).validate(&filters),
+            Err(OrderValidationError::NotionalOutOfRange {
+                notional: n!(2.0),
+                min: n!(5.0),
+                max: crate::number::UNLIMITED,
+            })
+        );
+        assert_eq!(
+            order(n!(0.003), n!(28000.3))
+                .with_stop_loss(n!(27000.05))
+                .validate(&filters),
+            Err(OrderValidationError::Tick {
+                field: "stopLoss",
+                price: n!(27000.05),
+                tick_size: n!(0.1),
+            })
+        );
+        assert_eq!(
+            CreateOrderParams::market(Category::Linear, "BTCUSDT", Side::Sell, n!(600.0))
+                .validate(&filters),
+            Err(OrderValidationError::QtyOutOfRange {
+                qty: n!(600.0),
+                min: n!(0.001),
+                max: n!(500.0),
+            })
+        );
+        assert_eq!(
+            CreateOrderParams::new(
+                Category::Linear,
+                "BTCUSDT",
+                Side::Buy,
+                OrderType::Limit,
+                n!(1.0)
+            )
+            .validate(&filters),
+            Err(OrderValidationError::MissingPrice)
+        );
+    }
+
+    #[test]
+    fn test_validate_inverse_order() {
+        // Even with an order value limit, 10 USD contracts are not 10 * 28000 in value.
+        let filters = InstrumentFilters {
+            qty_step: n!(1.0),
+            min_qty: n!(1.0),
+            max_notional: n!(1000.0),
+            ..linear_filters()
+        };
+        let order = CreateOrderParams::limit(
+            Category::Inverse,
+            "BTCUSD",
+            Side::Buy,
+            n!(10.0),
+            n!(28000.0),
+        );
+        assert_eq!(order.validate(&filters), Ok(()));
+
+        let amend = AmendOrderParams::by_order_id(Category::Inverse, "BTCUSD", "id")
+            .with_qty(n!(10.0))
+            .with_price(n!(28000.0));
+        assert_eq!(amend.validate(&filters), Ok(()));
+
+        let order = CreateOrderParams::limit(
+            Category::Linear,
+            "BTCUSDT",
+            Side::Buy,
+            n!(10.0),
+            n!(28000.0),
+        );
+        assert!(matches!(
+            order.validate(&filters),
+            Err(OrderValidationError::NotionalOutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_validate_spot_market_order_in_quote_coin() {
+        let filters = spot_filters();
+        let order = CreateOrderParams::market(Category::Spot, "BTCUSDT", Side::Buy, n!(0.5))
+            .with_qty_in_quote_coin();
+        assert_eq!(
+            order.validate(&filters),
+            Err(OrderValidationError::NotionalOutOfRange {
+                notional: n!(0.5),
+                min: n!(1.0),
+                max: n!(2000000.0),
+            })
+        );
+
+        let order = CreateOrderParams::market(Category::Spot, "BTCUSDT", Side::Buy, n!(10.0))
+            .with_qty_in_quote_coin();
+        assert_eq!(order.validate(&filters), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_amend_and_cancel_order() {
+        let filters = linear_filters();
+        let amend = AmendOrderParams::by_order_id(Category::Linear, "BTCUSDT", "id")
+            .with_qty(n!(0.002))
+            .with_take_profit(n!(0.0));
+        assert_eq!(amend.validate(&filters), Ok(()));
+
+        let amend = AmendOrderParams::new(Category::Linear, "BTCUSDT").with_price(n!(28000.0));
+        assert_eq!(
+            amend.validate(&filters),
+            Err(OrderValidationError::MissingOrderId)
+        );
+
+        let cancel = CancelOrderParams::by_order_link_id(Category::Linear, "BTCUSDT", "link");
+        assert_eq!(cancel.validate(), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn test_create_batch_order() {
+        let server = MockServer::start().await;
+        server.mock_rest_with_ext_info(
+            PATH_ORDER_CREATE_BATCH,
+            json!({
+                "list": [
+                    {
+                        "category": "linear",
+                        "symbol": "BTCUSDT",
+                        "orderId": "b2ae2b3f-5a7e-4c4a-8a3a-0a2f2fb1a9d1",
+                        "orderLinkId": "link-1",
+                        "createAt": "1713434102752"
+                    },
+                    {
+                        "category": "linear",
+                        "symbol": "BTCUSDT",
+                        "orderId": "",
+                        "orderLinkId": "link-2",
+                        "createAt": ""
+                    }
+                ]
+            }),
+            json!({
+                "list": [
+                    { "code": 0, "msg": "OK" },
+                    { "code": 170137, "msg": "Order quantity has too many decimals." }
+                ]
+            }),
+        );
+        let client = signed_client(&server.url());
+
+        let acks = client
+            .create_batch_order(BatchOrderParams::new(
+                Category::Linear,
+                vec![
+                    CreateOrderParams::limit(
+                        Category::Linear,
+                        "BTCUSDT",
+                        Side::Buy,
+                        n!(0.01),
+                        n!(28000.0),
+                    )
+                    .with_order_link_id("link-1"),
+                    CreateOrderParams::limit(
+                        Category::Linear,
+                        "BTCUSDT",
+                        Side::Buy,
+                        n!(0.0105),
+                        n!(28000.0),
+                    )
+                    .with_order_link_id("link-2"),
+                ],
+            ))
+            .await
+            .unwrap();
+        let requests = server.rest_requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(
+            acks[0],
+            Ok(OrderAck {
+                op: TradeOp::CreateBatch,
+                order_id: String::from("b2ae2b3f-5a7e-4c4a-8a3a-0a2f2fb1a9d1"),
+                order_link_id: String::from("link-1"),
+            })
+        );
+        let error = acks[1].as_ref().unwrap_err();
+        assert_eq!(error.ret_code, 170137);
+        assert_eq!(error.reason, RejectReason::EcEcInvalidQty);
+    }
+
+    #[tokio::test]
+    async fn test_create_and_cancel_order_with_mock() {
+        let server = MockServer::start().await;
+        server.mock_rest(
+            PATH_ORDER_CREATE,
+            json!({"orderId": "1321003749386327552", "orderLinkId": "link-1"}),
+        );
+        server.mock_rest_error(
+            PATH_ORDER_CANCEL,
+            110001,
+            "order not exists or too late to cancel",
+        );
+        let client = signed_client(&server.url());
+
+        let order_id = client
+            .create_order(
+                CreateOrderParams::limit(
+                    Category::Linear,
+                    "BTCUSDT",
+                    Side::Buy,
+                    n!(0.01),
+                    n!(28000.0),
+                )
+                .with_order_link_id("link-1"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(order_id.order_id, "1321003749386327552");
+
+        let error = client
+            .cancel_order(CancelOrderParams::by_order_id(
+                Category::Linear,
+                "BTCUSDT",
+                "1321003749386327552",
+            ))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Api {
+                ret_code: 110001,
+                ..
+            }
+        ));
+
+        let requests = server.rest_requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].path, PATH_ORDER_CREATE);
+        assert_eq!(requests[0].api_key.as_deref(), Some("api_key"));
+        assert!(requests[0].body.contains(r#""orderLinkId":"link-1""#));
+    }
+}
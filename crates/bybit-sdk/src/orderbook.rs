@@ -0,0 +1,371 @@
+use std::{cmp::Ordering, collections::BTreeMap, fmt};
+
+use crate::{
+    number::{total_cmp, ZERO},
+    Number, OrderBookDataMsg, OrderBookLevelMsg, OrderBookMsg, OutgoingMessage, Topic,
+};
+
+#[derive(Debug, PartialEq)]
+pub enum OrderBookError {
+    /// A delta arrived before the first snapshot or after the book went out of sync.
+    NotSynced,
+    /// Update ID of a delta does not follow the previous one.
+    Gap {
+        expected: u64,
+        received: u64,
+    },
+    UnexpectedSymbol(String),
+}
+
+impl fmt::Display for OrderBookError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NotSynced => write!(f, "order book is not synced, waiting for snapshot"),
+            Self::Gap { expected, received } => {
+                write!(
+                    f,
+                    "order book gap: expected u={expected}, received u={received}"
+                )
+            }
+            Self::UnexpectedSymbol(symbol) => write!(f, "unexpected symbol {symbol}"),
+        }
+    }
+}
+
+impl std::error::Error for OrderBookError {}
+
+/// Price used as a map key.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Price(Number);
+
+impl Eq for Price {}
+
+impl PartialOrd for Price {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Price {
+    fn cmp(&self, other: &Self) -> Ordering {
+        total_cmp(&self.0, &other.0)
+    }
+}
+
+/// Local order book maintained from the `orderbook.{depth}.{symbol}` topic.
+///
+/// Snapshots reset the book, deltas are applied only when their update ID follows the
+/// previous one. On a gap the book is marked out of sync and a fresh snapshot must be
+/// requested with [`OrderBook::resync_messages`].
+#[derive(Debug)]
+pub struct OrderBook {
+    symbol: String,
+    depth: u64,
+    bids: BTreeMap<Price, Number>,
+    asks: BTreeMap<Price, Number>,
+    update_id: u64,
+    seq: u64,
+    ts: u64,
+    synced: bool,
+}
+
+impl OrderBook {
+    pub fn new(symbol: &str, depth: u64) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            depth,
+            bids: BTreeMap::new(),
+            asks: BTreeMap::new(),
+            update_id: 0,
+            seq: 0,
+            ts: 0,
+            synced: false,
+        }
+    }
+
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    pub fn topic(&self) -> Topic {
+        Topic::orderbook(&self.symbol, self.depth)
+    }
+
+    pub fn is_synced(&self) -> bool {
+        self.synced
+    }
+
+    pub fn update_id(&self) -> u64 {
+        self.update_id
+    }
+
+    /// Cross sequence of the last applied message.
+    pub fn seq(&self) -> u64 {
+        self.seq
+    }
+
+    /// Timestamp (ms) of the last applied message.
+    pub fn ts(&self) -> u64 {
+        self.ts
+    }
+
+    pub fn apply(&mut self, message: &OrderBookMsg) -> Result<(), OrderBookError> {
+        match message {
+            OrderBookMsg::Snapshot { ts, data, .. } => {
+                self.check_symbol(data)?;
+                self.bids.clear();
+                self.asks.clear();
+                apply_levels(&mut self.bids, &data.bids);
+                apply_levels(&mut self.asks, &data.asks);
+                self.commit(data, *ts);
+                self.synced = true;
+                Ok(())
+            }
+            OrderBookMsg::Delta { ts, data, .. } => {
+                self.check_symbol(data)?;
+                if !self.synced {
+                    return Err(OrderBookError::NotSynced);
+                }
+                let expected = self.update_id + 1;
+                if data.update_id != expected {
+                    self.synced = false;
+                    return Err(OrderBookError::Gap {
+                        expected,
+                        received: data.update_id,
+                    });
+                }
+                apply_levels(&mut self.bids, &data.bids);
+                apply_levels(&mut self.asks, &data.asks);
+                self.commit(data, *ts);
+                Ok(())
+            }
+        }
+    }
+
+    /// Messages that make Bybit push a fresh snapshot for this book.
+    pub fn resync_messages(&self) -> [OutgoingMessage; 2] {
+        let topic = self.topic();
+        [
+            OutgoingMessage::Unsubscribe {
+                req_id: Some(format!("resync-unsubscribe-{topic}")),
+                args: vec![topic.clone()],
+            },
+            OutgoingMessage::Subscribe {
+                req_id: Some(format!("resync-subscribe-{topic}")),
+                args: vec![topic],
+            },
+        ]
+    }
+
+    /// Best bid price and size.
+    pub fn best_bid(&self) -> Option<(Number, Number)> {
+        self.bids.iter().next_back().map(|(p, s)| (p.0, *s))
+    }
+
+    /// Best ask price and size.
+    pub fn best_ask(&self) -> Option<(Number, Number)> {
+        self.asks.iter().next().map(|(p, s)| (p.0, *s))
+    }
+
+    pub fn spread(&self) -> Option<Number> {
+        Some(self.best_ask()?.0 - self.best_bid()?.0)
+    }
+
+    /// Up to `levels` bids, best first.
+    pub fn bids(&self, levels: usize) -> Vec<(Number, Number)> {
+        self.bids
+            .iter()
+            .rev()
+            .take(levels)
+            .map(|(p, s)| (p.0, *s))
+            .collect()
+    }
+
+    /// Up to `levels` asks, best first.
+    pub fn asks(&self, levels: usize) -> Vec<(Number, Number)> {
+        self.asks
+            .iter()
+            .take(levels)
+            .map(|(p, s)| (p.0, *s))
+            .collect()
+    }
+
+    /// Sanity check of the local book: it is synced, not crossed, has no empty levels and
+    /// does not exceed the subscribed depth on either side.
+    pub fn is_consistent(&self) -> bool {
+        if !self.synced {
+            return false;
+        }
+        if self.bids.len() as u64 > self.depth || self.asks.len() as u64 > self.depth {
+            return false;
+        }
+        if self
+            .bids
+            .values()
+            .chain(self.asks.values())
+            .any(|s| *s <= ZERO)
+        {
+            return false;
+        }
+        match (self.best_bid(), self.best_ask()) {
+            (Some((bid, _)), Some((ask, _))) => bid < ask,
+            _ => true,
+        }
+    }
+
+    fn check_symbol(&self, data: &OrderBookDataMsg) -> Result<(), OrderBookError> {
+        if data.symbol != self.symbol {
+            return Err(OrderBookError::UnexpectedSymbol(data.symbol.clone()));
+        }
+        Ok(())
+    }
+
+    fn commit(&mut self, data: &OrderBookDataMsg, ts: u64) {
+        self.update_id = data.update_id;
+        self.seq = data.seq;
+        self.ts = ts;
+    }
+}
+
+fn apply_levels(side: &mut BTreeMap<Price, Number>, levels: &[OrderBookLevelMsg]) {
+    for OrderBookLevelMsg(price, size) in levels {
+        if *size == ZERO {
+            side.remove(&Price(*price));
+        } else {
+            side.insert(Price(*price), *size);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::n;
+
+    fn data(
+        update_id: u64,
+        bids: &[(Number, Number)],
+        asks: &[(Number, Number)],
+    ) -> OrderBookDataMsg {
+        OrderBookDataMsg {
+            symbol: String::from("BTCUSDT"),
+            bids: bids.iter().map(|l| OrderBookLevelMsg(l.0, l.1)).collect(),
+            asks: asks.iter().map(|l| OrderBookLevelMsg(l.0, l.1)).collect(),
+            update_id,
+            seq: update_id * 10,
+        }
+    }
+
+    fn snapshot(data: OrderBookDataMsg) -> OrderBookMsg {
+        OrderBookMsg::Snapshot {
+            topic: Topic::orderbook("BTCUSDT", 50),
+            ts: 1,
+            data,
+            cts: None,
+        }
+    }
+
+    fn delta(data: OrderBookDataMsg) -> OrderBookMsg {
+        OrderBookMsg::Delta {
+            topic: Topic::orderbook("BTCUSDT", 50),
+            ts: 2,
+            data,
+            cts: None,
+        }
+    }
+
+    #[test]
+    fn test_apply_snapshot_and_delta() {
+        let mut book = OrderBook::new("BTCUSDT", 50);
+        book.apply(&snapshot(data(
+            100,
+            &[(n!(100.0), n!(1.0)), (n!(99.5), n!(2.0))],
+            &[(n!(100.5), n!(3.0)), (n!(101.0), n!(4.0))],
+        )))
+        .unwrap();
+        assert_eq!(book.best_bid(), Some((n!(100.0), n!(1.0))));
+        assert_eq!(book.best_ask(), Some((n!(100.5), n!(3.0))));
+
+        book.apply(&delta(data(
+            101,
+            &[(n!(100.0), n!(0.0)), (n!(99.8), n!(5.0))],
+            &[(n!(100.2), n!(1.5))],
+        )))
+        .unwrap();
+        assert_eq!(
+            book.bids(10),
+            vec![(n!(99.8), n!(5.0)), (n!(99.5), n!(2.0))]
+        );
+        assert_eq!(
+            book.asks(2),
+            vec![(n!(100.2), n!(1.5)), (n!(100.5), n!(3.0))]
+        );
+        assert_eq!(book.update_id(), 101);
+        assert_eq!(book.seq(), 1010);
+        assert!(book.is_consistent());
+    }
+
+    #[test]
+    fn test_delta_before_snapshot() {
+        let mut book = OrderBook::new("BTCUSDT", 50);
+        let result = book.apply(&delta(data(1, &[(n!(100.0), n!(1.0))], &[])));
+        assert_eq!(result, Err(OrderBookError::NotSynced));
+    }
+
+    #[test]
+    fn test_gap_marks_book_out_of_sync() {
+        let mut book = OrderBook::new("BTCUSDT", 50);
+        book.apply(&snapshot(data(
+            100,
+            &[(n!(100.0), n!(1.0))],
+            &[(n!(101.0), n!(1.0))],
+        )))
+        .unwrap();
+        let result = book.apply(&delta(data(102, &[(n!(100.0), n!(2.0))], &[])));
+        assert_eq!(
+            result,
+            Err(OrderBookError::Gap {
+                expected: 101,
+                received: 102,
+            })
+        );
+        assert!(!book.is_synced());
+        assert!(!book.is_consistent());
+
+        // A fresh snapshot brings the book back.
+        book.apply(&snapshot(data(
+            1,
+            &[(n!(100.0), n!(3.0))],
+            &[(n!(101.0), n!(1.0))],
+        )))
+        .unwrap();
+        assert!(book.is_synced());
+        assert_eq!(book.best_bid(), Some((n!(100.0), n!(3.0))));
+    }
+
+    #[test]
+    fn test_crossed_book_is_not_consistent() {
+        let mut book = OrderBook::new("BTCUSDT", 50);
+        book.apply(&snapshot(data(
+            1,
+            &[(n!(101.0), n!(1.0))],
+            &[(n!(100.0), n!(1.0))],
+        )))
+        .unwrap();
+        assert!(!book.is_consistent());
+    }
+
+    #[test]
+    fn test_resync_messages() {
+        let book = OrderBook::new("BTCUSDT", 50);
+        let [unsubscribe, subscribe] = book.resync_messages();
+        assert!(matches!(
+            unsubscribe,
+            OutgoingMessage::Unsubscribe { args, .. } if args == vec![Topic::orderbook("BTCUSDT", 50)]
+        ));
+        assert!(matches!(
+            subscribe,
+            OutgoingMessage::Subscribe { args, .. } if args == vec![Topic::orderbook("BTCUSDT", 50)]
+        ));
+    }
+}
@@ -1,17 +1,19 @@
 use serde::Serialize;
 
-#[derive(Serialize, Debug)]
+use crate::Topic;
+
+#[derive(Serialize, Debug, PartialEq)]
 #[serde(tag = "op")]
 pub enum OutgoingMessage {
     #[serde(rename = "subscribe")]
     Subscribe {
         req_id: Option<String>,
-        args: Vec<String>,
+        args: Vec<Topic>,
     },
     #[serde(rename = "unsubscribe")]
     Unsubscribe {
         req_id: Option<String>,
-        args: Vec<String>,
+        args: Vec<Topic>,
     },
     #[serde(rename = "auth")]
     Auth {
@@ -34,7 +36,7 @@ mod tests {
     fn test_serialize_outgoing_message_subscribe() {
         let msg = OutgoingMessage::Subscribe {
             req_id: Some(String::from("request_id")),
-            args: vec![String::from("tickers.BTCUSDT")],
+            args: vec![Topic::ticker("BTCUSDT")],
         };
         let expected = r#"{"op":"subscribe","req_id":"request_id","args":["tickers.BTCUSDT"]}"#;
         let serialized = serialize(&msg).unwrap();
@@ -45,7 +47,7 @@ mod tests {
     fn test_serialize_outgoing_message_unsubscribe() {
         let msg = OutgoingMessage::Unsubscribe {
             req_id: Some(String::from("request_id")),
-            args: vec![String::from("tickers.BTCUSDT")],
+            args: vec![Topic::ticker("BTCUSDT")],
         };
         let expected = r#"{"op":"unsubscribe","req_id":"request_id","args":["tickers.BTCUSDT"]}"#;
         let serialized = serialize(&msg).unwrap();
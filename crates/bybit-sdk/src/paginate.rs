@@ -0,0 +1,467 @@
+use futures_util::{stream, Stream};
+use serde::{de::DeserializeOwned, Serialize};
+use std::collections::VecDeque;
+
+use crate::{
+    url::{
+        PATH_ACCOUNT_BORROW_HISTORY, PATH_ACCOUNT_TRANSACTION_LOG, PATH_EXECUTION_LIST,
+        PATH_ORDER_HISTORY, PATH_POSITION_CLOSED_PNL, PATH_POSITION_LIST,
+    },
+    BorrowHistory, BorrowRecord, Client, ClosedPnl, ClosedPnlRecord, Execution, Executions,
+    GetBorrowHistoryParams, GetClosedPnlParams, GetExecutionsParams, GetOpenClosedOrdersParams,
+    GetPositionInfo, GetTransactionLogParams, OpenClosedOrders, Order, Position, PositionInfo,
+    Result, Transaction, TransactionLog,
+};
+
+/// Request parameters of an endpoint paginated with `cursor`.
+pub trait PageParams: Serialize {
+    fn set_cursor(&mut self, cursor: String);
+    fn set_time_window(&mut self, start_time: Option<u64>, end_time: Option<u64>);
+}
+
+/// Response of an endpoint paginated with `nextPageCursor`.
+pub trait Page: DeserializeOwned {
+    type Item;
+
+    /// `None` on the last page.
+    fn next_page_cursor(&self) -> Option<&str>;
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+/// Options of the paginated streams.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Pagination {
+    /// Stop after this many items.
+    pub max_items: Option<usize>,
+    /// Start timestamp (ms), overrides the one of the request parameters.
+    pub start_time: Option<u64>,
+    /// End timestamp (ms), overrides the one of the request parameters.
+    pub end_time: Option<u64>,
+}
+
+impl Pagination {
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Positions have no time filter and ignore the window.
+    pub fn time_window(mut self, start_time: u64, end_time: u64) -> Self {
+        self.start_time = Some(start_time);
+        self.end_time = Some(end_time);
+        self
+    }
+}
+
+/// State of a paginated stream between pages.
+pub(crate) struct PageState<P, T> {
+    pub(crate) params: P,
+    pub(crate) items: VecDeque<T>,
+    pub(crate) remaining: Option<usize>,
+    /// Cursor of the last request, unused by page number pagination.
+    pub(crate) cursor: Option<String>,
+    pub(crate) last_page: bool,
+}
+
+impl<P, T> PageState<P, T> {
+    pub(crate) fn new(params: P, pagination: &Pagination) -> Self {
+        Self {
+            params,
+            items: VecDeque::new(),
+            remaining: pagination.max_items,
+            cursor: None,
+            last_page: false,
+        }
+    }
+
+    /// Takes the next buffered item, counting it against `max_items`.
+    pub(crate) fn next_item(&mut self) -> Option<T> {
+        let item = self.items.pop_front()?;
+        if let Some(remaining) = &mut self.remaining {
+            *remaining -= 1;
+        }
+        Some(item)
+    }
+}
+
+impl Client {
+    /// Positions, following `nextPageCursor` until the last page.
+    pub fn positions(
+        &self,
+        params: GetPositionInfo,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<Position>> + '_ {
+        self.paginate::<_, PositionInfo>(PATH_POSITION_LIST, params, pagination)
+    }
+
+    pub fn order_history(
+        &self,
+        params: GetOpenClosedOrdersParams,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<Order>> + '_ {
+        self.paginate::<_, OpenClosedOrders>(PATH_ORDER_HISTORY, params, pagination)
+    }
+
+    pub fn executions(
+        &self,
+        params: GetExecutionsParams,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<Execution>> + '_ {
+        self.paginate::<_, Executions>(PATH_EXECUTION_LIST, params, pagination)
+    }
+
+    pub fn closed_pnl(
+        &self,
+        params: GetClosedPnlParams,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<ClosedPnlRecord>> + '_ {
+        self.paginate::<_, ClosedPnl>(PATH_POSITION_CLOSED_PNL, params, pagination)
+    }
+
+    pub fn transaction_log(
+        &self,
+        params: GetTransactionLogParams,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<Transaction>> + '_ {
+        self.paginate::<_, TransactionLog>(PATH_ACCOUNT_TRANSACTION_LOG, params, pagination)
+    }
+
+    pub fn borrow_history(
+        &self,
+        params: GetBorrowHistoryParams,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<BorrowRecord>> + '_ {
+        self.paginate::<_, BorrowHistory>(PATH_ACCOUNT_BORROW_HISTORY, params, pagination)
+    }
+
+    /// Items of all pages of a private endpoint. Pages are requested lazily and paced by the
+    /// endpoint rate limit. The stream ends after the first error.
+    pub fn paginate<'a, P, T>(
+        &'a self,
+        path: &'a str,
+        params: P,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<T::Item>> + 'a
+    where
+        P: PageParams + 'a,
+        T: Page + 'a,
+    {
+        self.paginate_endpoint::<P, T>(path, params, pagination, true)
+    }
+
+    /// [`Client::paginate`] for a public endpoint, only paced when the client throttles.
+    pub(crate) fn paginate_public<'a, P, T>(
+        &'a self,
+        path: &'a str,
+        params: P,
+        pagination: Pagination,
+    ) -> impl Stream<Item = Result<T::Item>> + 'a
+    where
+        P: PageParams + 'a,
+        T: Page + 'a,
+    {
+        self.paginate_endpoint::<P, T>(path, params, pagination, false)
+    }
+
+    fn paginate_endpoint<'a, P, T>(
+        &'a self,
+        path: &'a str,
+        mut params: P,
+        pagination: Pagination,
+        signed: bool,
+    ) -> impl Stream<Item = Result<T::Item>> + 'a
+    where
+        P: PageParams + 'a,
+        T: Page + 'a,
+    {
+        if pagination.start_time.is_some() || pagination.end_time.is_some() {
+            params.set_time_window(pagination.start_time, pagination.end_time);
+        }
+        let state = PageState::new(params, &pagination);
+
+        stream::unfold(state, move |mut state| async move {
+            loop {
+                if state.remaining == Some(0) {
+                    return None;
+                }
+                if let Some(item) = state.next_item() {
+                    return Some((Ok(item), state));
+                }
+                if state.last_page {
+                    return None;
+                }
+
+                let page = match signed {
+                    true => self.get_signed_page(path, &state.params).await,
+                    false => self.get(path, &state.params).await,
+                };
+                let page: T = match page {
+                    Ok(page) => page,
+                    Err(e) => {
+                        state.last_page = true;
+                        return Some((Err(e), state));
+                    }
+                };
+                match page.next_page_cursor().map(str::to_owned) {
+                    // Guard against a cursor pointing at the same page again.
+                    Some(cursor) if state.cursor.as_ref() == Some(&cursor) => {
+                        tracing::warn!(path, cursor, "Page cursor repeated, stopping");
+                        state.last_page = true;
+                    }
+                    Some(cursor) => {
+                        state.cursor = Some(cursor.clone());
+                        state.params.set_cursor(cursor);
+                    }
+                    None => state.last_page = true,
+                }
+                let items = page.into_items();
+                // Guard against a cursor that never ends.
+                if items.is_empty() {
+                    state.last_page = true;
+                }
+                tracing::trace!(path, items = items.len(), "Page received");
+                state.items.extend(items);
+            }
+        })
+    }
+}
+
+pub(crate) fn cursor(next_page_cursor: &str) -> Option<&str> {
+    Some(next_page_cursor).filter(|cursor| !cursor.is_empty())
+}
+
+impl PageParams for GetPositionInfo {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor);
+    }
+
+    fn set_time_window(&mut self, _start_time: Option<u64>, _end_time: Option<u64>) {}
+}
+
+impl Page for PositionInfo {
+    type Item = Position;
+
+    fn next_page_cursor(&self) -> Option<&str> {
+        cursor(&self.next_page_cursor)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.list
+    }
+}
+
+impl PageParams for GetOpenClosedOrdersParams {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor);
+    }
+
+    fn set_time_window(&mut self, start_time: Option<u64>, end_time: Option<u64>) {
+        self.start_time = start_time;
+        self.end_time = end_time;
+    }
+}
+
+impl Page for OpenClosedOrders {
+    type Item = Order;
+
+    fn next_page_cursor(&self) -> Option<&str> {
+        cursor(&self.next_page_cursor)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.list
+    }
+}
+
+impl PageParams for GetExecutionsParams {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor);
+    }
+
+    fn set_time_window(&mut self, start_time: Option<u64>, end_time: Option<u64>) {
+        self.start_time = start_time;
+        self.end_time = end_time;
+    }
+}
+
+impl Page for Executions {
+    type Item = Execution;
+
+    fn next_page_cursor(&self) -> Option<&str> {
+        cursor(&self.next_page_cursor)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.list
+    }
+}
+
+impl PageParams for GetClosedPnlParams {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor);
+    }
+
+    fn set_time_window(&mut self, start_time: Option<u64>, end_time: Option<u64>) {
+        self.start_time = start_time;
+        self.end_time = end_time;
+    }
+}
+
+impl Page for ClosedPnl {
+    type Item = ClosedPnlRecord;
+
+    fn next_page_cursor(&self) -> Option<&str> {
+        cursor(&self.next_page_cursor)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.list
+    }
+}
+
+impl PageParams for GetTransactionLogParams {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor);
+    }
+
+    fn set_time_window(&mut self, start_time: Option<u64>, end_time: Option<u64>) {
+        self.start_time = start_time;
+        self.end_time = end_time;
+    }
+}
+
+impl Page for TransactionLog {
+    type Item = Transaction;
+
+    fn next_page_cursor(&self) -> Option<&str> {
+        cursor(&self.next_page_cursor)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.list
+    }
+}
+
+impl PageParams for GetBorrowHistoryParams {
+    fn set_cursor(&mut self, cursor: String) {
+        self.cursor = Some(cursor);
+    }
+
+    fn set_time_window(&mut self, start_time: Option<u64>, end_time: Option<u64>) {
+        self.start_time = start_time;
+        self.end_time = end_time;
+    }
+}
+
+impl Page for BorrowHistory {
+    type Item = BorrowRecord;
+
+    fn next_page_cursor(&self) -> Option<&str> {
+        cursor(&self.next_page_cursor)
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bybit_mock::MockServer;
+    use futures_util::{StreamExt, TryStreamExt};
+    use serde_json::Value;
+
+    use super::*;
+    use crate::{client::tests::signed_client, Category};
+
+    const PAGE_1: &str = r#"{"nextPageCursor":"page2","category":"linear","list":[{"symbol":"ETHPERP","orderType":"Market","leverage":"3","updatedTime":"1672214887236","side":"Sell","orderId":"5a373bfe-188d-4913-9c81-d57ab5be8068","closedPnl":"-47.4065323","avgEntryPrice":"1194.97516667","qty":"3","cumEntryValue":"3584.9255","createdTime":"1672214887231","orderPrice":"1122.95","closedSize":"3","avgExitPrice":"1180.59833333","execType":"Trade","fillCount":"4","cumExitValue":"3541.795"},{"symbol":"ETHPERP","orderType":"Limit","leverage":"3","updatedTime":"1672214887236","side":"Buy","orderId":"67b2f37b-0fdf-4a95-ba5c-33ef4a7d6b42","closedPnl":"1.5","avgEntryPrice":"1180","qty":"1","cumEntryValue":"1180","createdTime":"1672214887231","orderPrice":"1181.5","closedSize":"1","avgExitPrice":"1181.5","execType":"Trade","fillCount":"1","cumExitValue":"1181.5"}]}"#;
+    const PAGE_2: &str = r#"{"nextPageCursor":"","category":"linear","list":[{"symbol":"ETHPERP","orderType":"Market","leverage":"3","updatedTime":"1672214887236","side":"Sell","orderId":"9f6a2c5e-6a3c-4c9c-9b0d-2d2a1c6b8e11","closedPnl":"-2","avgEntryPrice":"1182","qty":"1","cumEntryValue":"1182","createdTime":"1672214887231","orderPrice":"1180","closedSize":"1","avgExitPrice":"1180","execType":"Trade","fillCount":"1","cumExitValue":"1180"}]}"#;
+
+    fn mock_pages(server: &MockServer) {
+        let pages = [PAGE_1, PAGE_2]
+            .iter()
+            .map(|page| serde_json::from_str::<Value>(page).unwrap())
+            .collect();
+        server.mock_rest_pages(PATH_POSITION_CLOSED_PNL, pages);
+    }
+
+    fn queries(server: &MockServer) -> Vec<Option<String>> {
+        server
+            .rest_requests()
+            .into_iter()
+            .map(|request| request.query)
+            .collect()
+    }
+
+    fn params() -> GetClosedPnlParams {
+        GetClosedPnlParams {
+            category: Category::Linear,
+            symbol: Some(String::from("ETHPERP")),
+            start_time: None,
+            end_time: None,
+            limit: Some(2),
+            cursor: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_paginate_follows_cursor() {
+        let server = MockServer::start().await;
+        mock_pages(&server);
+        let client = signed_client(&server.url());
+
+        let records: Vec<ClosedPnlRecord> = client
+            .closed_pnl(
+                params(),
+                Pagination::default().time_window(1672214880000, 1672214890000),
+            )
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[2].order_id, "9f6a2c5e-6a3c-4c9c-9b0d-2d2a1c6b8e11");
+
+        assert_eq!(
+            queries(&server),
+            [
+                Some(String::from("category=linear&symbol=ETHPERP&startTime=1672214880000&endTime=1672214890000&limit=2")),
+                Some(String::from("category=linear&symbol=ETHPERP&startTime=1672214880000&endTime=1672214890000&limit=2&cursor=page2")),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_paginate_max_items() {
+        let server = MockServer::start().await;
+        mock_pages(&server);
+        let client = signed_client(&server.url());
+
+        let records: Vec<_> = client
+            .closed_pnl(params(), Pagination::default().max_items(2))
+            .collect()
+            .await;
+        assert_eq!(records.len(), 2);
+        // The second page is never requested.
+        assert_eq!(queries(&server).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_ends_after_error() {
+        let server = MockServer::start().await;
+        server.mock_rest_error(PATH_POSITION_CLOSED_PNL, 10001, "params error");
+        let client = signed_client(&server.url());
+
+        let records: Vec<_> = client
+            .closed_pnl(params(), Pagination::default())
+            .collect()
+            .await;
+        assert_eq!(records.len(), 1);
+        assert!(matches!(
+            records[0],
+            Err(crate::Error::Api {
+                ret_code: 10001,
+                ..
+            })
+        ));
+    }
+}
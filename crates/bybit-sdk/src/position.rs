@@ -0,0 +1,174 @@
+use crate::{
+    url::{
+        PATH_EXECUTION_LIST, PATH_POSITION_CLOSED_PNL, PATH_POSITION_SET_AUTO_ADD_MARGIN,
+        PATH_POSITION_SET_LEVERAGE, PATH_POSITION_SET_RISK_LIMIT, PATH_POSITION_SWITCH_ISOLATED,
+        PATH_POSITION_SWITCH_MODE, PATH_POSITION_TRADING_STOP,
+    },
+    Client, ClosedPnl, Executions, GetClosedPnlParams, GetExecutionsParams, Result,
+    SetAutoAddMarginParams, SetLeverageParams, SetRiskLimit, SetRiskLimitParams,
+    SwitchIsolatedParams, SwitchModeParams, TradingStopParams,
+};
+
+impl Client {
+    /// Bybit rejects a leverage equal to the current one with retCode 110043.
+    pub async fn set_leverage(&self, params: SetLeverageParams) -> Result<()> {
+        self.post_empty(PATH_POSITION_SET_LEVERAGE, &params).await
+    }
+
+    pub async fn set_risk_limit(&self, params: SetRiskLimitParams) -> Result<SetRiskLimit> {
+        self.post_signed(PATH_POSITION_SET_RISK_LIMIT, &params)
+            .await
+    }
+
+    /// Sets, changes or cancels (with 0) the take profit, stop loss and trailing stop.
+    pub async fn set_trading_stop(&self, params: TradingStopParams) -> Result<()> {
+        self.post_empty(PATH_POSITION_TRADING_STOP, &params).await
+    }
+
+    /// Switches a symbol between cross and isolated margin. Classic accounts only.
+    pub async fn switch_isolated(&self, params: SwitchIsolatedParams) -> Result<()> {
+        self.post_empty(PATH_POSITION_SWITCH_ISOLATED, &params)
+            .await
+    }
+
+    /// Switches between one-way and hedge mode. Fails while the symbol has a position or
+    /// open orders.
+    pub async fn switch_position_mode(&self, params: SwitchModeParams) -> Result<()> {
+        self.post_empty(PATH_POSITION_SWITCH_MODE, &params).await
+    }
+
+    pub async fn set_auto_add_margin(&self, params: SetAutoAddMarginParams) -> Result<()> {
+        self.post_empty(PATH_POSITION_SET_AUTO_ADD_MARGIN, &params)
+            .await
+    }
+
+    /// A single page; [`Client::executions`] streams all of them.
+    pub async fn get_executions(&self, params: GetExecutionsParams) -> Result<Executions> {
+        self.get_signed(PATH_EXECUTION_LIST, &params).await
+    }
+
+    /// A single page; [`Client::closed_pnl`] streams all of them.
+    pub async fn get_closed_pnl(&self, params: GetClosedPnlParams) -> Result<ClosedPnl> {
+        self.get_signed(PATH_POSITION_CLOSED_PNL, &params).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bybit_mock::MockServer;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        client::tests::signed_client, number::n, AutoAddMargin, Category, PositionIdx,
+        PositionMode, TpslMode, TradeMode, TriggerBy,
+    };
+
+    #[tokio::test]
+    async fn test_set_trading_stop() {
+        let server = MockServer::start().await;
+        server.mock_rest(PATH_POSITION_TRADING_STOP, json!({}));
+        signed_client(&server.url())
+            .set_trading_stop(TradingStopParams {
+                take_profit: Some(n!(0.5)),
+                stop_loss: Some(n!(0.2)),
+                tp_trigger_by: Some(TriggerBy::MarkPrice),
+                trailing_stop: Some(n!(0.0)),
+                ..TradingStopParams::new(Category::Linear, "XRPUSDT", PositionIdx::OneWay)
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            server.rest_requests()[0].body,
+            r#"{"category":"linear","symbol":"XRPUSDT","tpslMode":"Full","positionIdx":0,"takeProfit":"0.5","stopLoss":"0.2","trailingStop":"0","tpTriggerBy":"MarkPrice"}"#
+        );
+
+        let params = TradingStopParams {
+            tpsl_mode: TpslMode::Partial,
+            ..TradingStopParams::new(Category::Linear, "XRPUSDT", PositionIdx::Buy)
+        };
+        assert_eq!(
+            serde_json::to_string(&params).unwrap(),
+            r#"{"category":"linear","symbol":"XRPUSDT","tpslMode":"Partial","positionIdx":1}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_switch_position_mode() {
+        let server = MockServer::start().await;
+        server.mock_rest(PATH_POSITION_SWITCH_MODE, json!({}));
+        server.mock_rest(PATH_POSITION_SWITCH_ISOLATED, json!({}));
+        server.mock_rest(PATH_POSITION_SET_AUTO_ADD_MARGIN, json!({}));
+        let client = signed_client(&server.url());
+        client
+            .switch_position_mode(SwitchModeParams {
+                category: Category::Linear,
+                symbol: None,
+                coin: Some(String::from("USDT")),
+                mode: PositionMode::BothSides,
+            })
+            .await
+            .unwrap();
+        client
+            .switch_isolated(SwitchIsolatedParams {
+                category: Category::Linear,
+                symbol: String::from("ETHUSDT"),
+                trade_mode: TradeMode::IsolatedMargin,
+                buy_leverage: n!(10.0),
+                sell_leverage: n!(10.0),
+            })
+            .await
+            .unwrap();
+        client
+            .set_auto_add_margin(SetAutoAddMarginParams {
+                category: Category::Linear,
+                symbol: String::from("ETHUSDT"),
+                auto_add_margin: AutoAddMargin::True,
+                position_idx: Some(PositionIdx::Sell),
+            })
+            .await
+            .unwrap();
+
+        let requests = server.rest_requests();
+        assert_eq!(
+            requests[0].body,
+            r#"{"category":"linear","coin":"USDT","mode":3}"#
+        );
+        assert_eq!(
+            requests[1].body,
+            r#"{"category":"linear","symbol":"ETHUSDT","tradeMode":1,"buyLeverage":"10","sellLeverage":"10"}"#
+        );
+        assert_eq!(
+            requests[2].body,
+            r#"{"category":"linear","symbol":"ETHUSDT","autoAddMargin":1,"positionIdx":2}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn test_set_risk_limit() {
+        let server = MockServer::start().await;
+        server.mock_rest(
+            PATH_POSITION_SET_RISK_LIMIT,
+            json!({
+                "riskId": 4,
+                "riskLimitValue": "8000000",
+                "category": "linear"
+            }),
+        );
+        let result = signed_client(&server.url())
+            .set_risk_limit(SetRiskLimitParams {
+                category: Category::Linear,
+                symbol: String::from("BTCUSDT"),
+                risk_id: 4,
+                position_idx: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(result.risk_id, 4);
+        assert_eq!(result.risk_limit_value, n!(8000000.0));
+        assert_eq!(
+            server.rest_requests()[0].body,
+            r#"{"category":"linear","symbol":"BTCUSDT","riskId":4}"#
+        );
+    }
+}
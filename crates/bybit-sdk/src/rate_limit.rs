@@ -0,0 +1,289 @@
+use reqwest::header::HeaderMap;
+use std::{collections::HashMap, str::FromStr, sync::Mutex, time::Duration};
+use tokio::time::sleep;
+
+use crate::{
+    common::timestamp,
+    url::{HEADER_X_BAPI_LIMIT, HEADER_X_BAPI_LIMIT_RESET_TIMESTAMP, HEADER_X_BAPI_LIMIT_STATUS},
+};
+
+/// Bybit counts requests per second.
+const WINDOW_MS: u64 = 1000;
+
+/// Rate limit state of an endpoint reported by the `X-Bapi-Limit*` headers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    /// Current limit for the endpoint.
+    pub limit: u32,
+    /// Remaining requests for the endpoint.
+    pub remaining: u32,
+    /// UTC timestamp (ms) when the limit resets.
+    pub reset_timestamp: u64,
+}
+
+impl RateLimit {
+    /// `None` unless all three headers are present and valid.
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        Some(Self {
+            limit: header(headers, HEADER_X_BAPI_LIMIT)?,
+            remaining: header(headers, HEADER_X_BAPI_LIMIT_STATUS)?,
+            reset_timestamp: header(headers, HEADER_X_BAPI_LIMIT_RESET_TIMESTAMP)?,
+        })
+    }
+}
+
+fn header<T: FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+    headers.get(name)?.to_str().ok()?.parse().ok()
+}
+
+/// Last known [`RateLimit`] per endpoint group.
+///
+/// An endpoint is its own group unless it was added to a shared one with
+/// [`RateLimiter::group`], e.g. for endpoints sharing one limit.
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    groups: HashMap<String, String>,
+    state: Mutex<HashMap<String, GroupState>>,
+}
+
+#[derive(Debug, Default)]
+struct GroupState {
+    rate_limit: Option<RateLimit>,
+    /// Requests acquired and still waiting for their response.
+    in_flight: u32,
+}
+
+/// Request slot reserved by [`RateLimiter::acquire`], in flight until dropped.
+#[must_use]
+#[derive(Debug)]
+pub struct Reservation<'a> {
+    limiter: &'a RateLimiter,
+    path: String,
+}
+
+impl Drop for Reservation<'_> {
+    fn drop(&mut self) {
+        let mut state = self.limiter.state.lock().unwrap();
+        if let Some(group) = state.get_mut(self.limiter.key(&self.path)) {
+            group.in_flight = group.in_flight.saturating_sub(1);
+        }
+    }
+}
+
+impl RateLimiter {
+    pub fn group(&mut self, group: &str, paths: &[&str]) {
+        for path in paths {
+            self.groups.insert((*path).to_owned(), group.to_owned());
+        }
+    }
+
+    pub fn get(&self, path: &str) -> Option<RateLimit> {
+        self.state
+            .lock()
+            .unwrap()
+            .get(self.key(path))
+            .and_then(|group| group.rate_limit)
+    }
+
+    /// Applies the headers of a response. The server has not yet counted the other requests
+    /// in flight, so their slots stay reserved: within the same window the lower of the
+    /// server and local `remaining` is kept.
+    pub fn update(&self, path: &str, rate_limit: RateLimit) {
+        let mut state = self.state.lock().unwrap();
+        let group = state.entry(self.key(path).to_owned()).or_default();
+        // The response belongs to one of the requests in flight.
+        let others = group.in_flight.saturating_sub(1);
+        let mut remaining = rate_limit.remaining.saturating_sub(others);
+        if let Some(local) = group.rate_limit {
+            if local.reset_timestamp >= rate_limit.reset_timestamp {
+                remaining = remaining.min(local.remaining);
+            }
+        }
+        group.rate_limit = Some(RateLimit {
+            remaining,
+            ..rate_limit
+        });
+    }
+
+    /// Waits until the group of `path` has requests left, then reserves one until the
+    /// returned [`Reservation`] is dropped. Unknown groups are not delayed. Once a window
+    /// resets, a new one of `limit` requests starts, so waiters beyond the limit keep waiting
+    /// instead of all firing at once.
+    pub async fn acquire(&self, path: &str) -> Reservation<'_> {
+        loop {
+            let wait = {
+                let mut state = self.state.lock().unwrap();
+                let group = state.entry(self.key(path).to_owned()).or_default();
+                let wait = match &mut group.rate_limit {
+                    Some(rate_limit) => {
+                        let now = timestamp();
+                        if rate_limit.reset_timestamp <= now {
+                            rate_limit.remaining = rate_limit.limit;
+                            rate_limit.reset_timestamp = now + WINDOW_MS;
+                        }
+                        if rate_limit.remaining > 0 {
+                            rate_limit.remaining -= 1;
+                            None
+                        } else {
+                            Some(Duration::from_millis(rate_limit.reset_timestamp - now))
+                        }
+                    }
+                    None => None,
+                };
+                if wait.is_none() {
+                    group.in_flight += 1;
+                }
+                wait
+            };
+
+            match wait {
+                Some(delay) => {
+                    tracing::debug!(path, ?delay, "Rate limit exhausted, waiting");
+                    sleep(delay).await;
+                }
+                None => {
+                    return Reservation {
+                        limiter: self,
+                        path: path.to_owned(),
+                    }
+                }
+            }
+        }
+    }
+
+    fn key<'a>(&'a self, path: &'a str) -> &'a str {
+        self.groups.get(path).map(String::as_str).unwrap_or(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_rate_limit_from_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(HEADER_X_BAPI_LIMIT, HeaderValue::from_static("10"));
+        headers.insert(HEADER_X_BAPI_LIMIT_STATUS, HeaderValue::from_static("9"));
+        assert_eq!(RateLimit::from_headers(&headers), None);
+
+        headers.insert(
+            HEADER_X_BAPI_LIMIT_RESET_TIMESTAMP,
+            HeaderValue::from_static("1711001595208"),
+        );
+        assert_eq!(
+            RateLimit::from_headers(&headers),
+            Some(RateLimit {
+                limit: 10,
+                remaining: 9,
+                reset_timestamp: 1711001595208,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_acquire_waits_for_reset() {
+        let mut limiter = RateLimiter::default();
+        limiter.group("order", &["/v5/order/create", "/v5/order/cancel"]);
+        limiter.update(
+            "/v5/order/create",
+            RateLimit {
+                limit: 10,
+                remaining: 1,
+                reset_timestamp: timestamp() + 100,
+            },
+        );
+
+        let start = std::time::Instant::now();
+        let _ = limiter.acquire("/v5/order/cancel").await;
+        assert!(start.elapsed() < Duration::from_millis(50));
+        assert_eq!(limiter.get("/v5/order/create").unwrap().remaining, 0);
+
+        let _ = limiter.acquire("/v5/order/create").await;
+        assert!(start.elapsed() >= Duration::from_millis(90));
+
+        // The new window has the full limit again.
+        let rate_limit = limiter.get("/v5/order/create").unwrap();
+        assert_eq!(rate_limit.remaining, 9);
+        assert!(rate_limit.reset_timestamp > timestamp());
+
+        // Other endpoints are not affected.
+        let _ = limiter.acquire("/v5/position/list").await;
+        assert_eq!(limiter.get("/v5/position/list"), None);
+    }
+
+    #[tokio::test]
+    async fn test_acquire_releases_limit_per_window() {
+        let limiter = Arc::new(RateLimiter::default());
+        limiter.update(
+            "/v5/order/create",
+            RateLimit {
+                limit: 2,
+                remaining: 0,
+                reset_timestamp: timestamp() + 50,
+            },
+        );
+
+        let acquired = Arc::new(AtomicUsize::new(0));
+        for _ in 0..3 {
+            let limiter = limiter.clone();
+            let acquired = acquired.clone();
+            tokio::spawn(async move {
+                let _reservation = limiter.acquire("/v5/order/create").await;
+                acquired.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+
+        sleep(Duration::from_millis(300)).await;
+        assert_eq!(acquired.load(Ordering::SeqCst), 2);
+        assert_eq!(limiter.get("/v5/order/create").unwrap().remaining, 0);
+    }
+
+    #[tokio::test]
+    async fn test_update_keeps_in_flight_slots() {
+        let limiter = RateLimiter::default();
+        let reset_timestamp = timestamp() + 1000;
+        limiter.update(
+            "/v5/order/create",
+            RateLimit {
+                limit: 10,
+                remaining: 5,
+                reset_timestamp,
+            },
+        );
+
+        let first = limiter.acquire("/v5/order/create").await;
+        let _second = limiter.acquire("/v5/order/create").await;
+        let _third = limiter.acquire("/v5/order/create").await;
+        assert_eq!(limiter.get("/v5/order/create").unwrap().remaining, 2);
+
+        // The server only counted the first request, the other two are still in flight.
+        limiter.update(
+            "/v5/order/create",
+            RateLimit {
+                limit: 10,
+                remaining: 4,
+                reset_timestamp,
+            },
+        );
+        drop(first);
+        assert_eq!(limiter.get("/v5/order/create").unwrap().remaining, 2);
+
+        // A new window reported by the server replaces the local count.
+        limiter.update(
+            "/v5/order/create",
+            RateLimit {
+                limit: 10,
+                remaining: 9,
+                reset_timestamp: reset_timestamp + 1000,
+            },
+        );
+        assert_eq!(limiter.get("/v5/order/create").unwrap().remaining, 8);
+    }
+}
@@ -0,0 +1,354 @@
+use serde::{Deserialize, Serialize};
+use std::{path::Path, time::Duration};
+use tokio::{
+    fs::File,
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader, BufWriter},
+    sync::{
+        mpsc::{channel, unbounded_channel, Receiver, Sender, UnboundedSender},
+        oneshot,
+    },
+    time::{sleep_until, Instant},
+};
+use tracing::Instrument;
+
+use crate::{
+    common::{deserialize_slice, serialize, timestamp},
+    stream::connect_stream,
+    IncomingMessage, OutgoingMessage, Result,
+};
+
+/// One line of a JSONL recording.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum RecordEntry {
+    /// First line of a recording.
+    Connection { url: String, ts: u64 },
+    /// Raw text frame received from Bybit.
+    In { ts: u64, frame: String },
+    /// Frame sent to Bybit.
+    Out { ts: u64, frame: String },
+}
+
+/// Request to the background task of a [`Recorder`].
+enum RecorderCommand {
+    Record(RecordEntry),
+    /// Flush the entries queued before, then reply.
+    Flush(oneshot::Sender<()>),
+}
+
+/// Appends frames to a recording from a background task, so the stream never waits on I/O.
+#[derive(Clone)]
+pub(crate) struct Recorder {
+    tx: UnboundedSender<RecorderCommand>,
+}
+
+impl Recorder {
+    pub(crate) async fn create(path: &Path, url: &str) -> Result<Self> {
+        let file = File::create(path).await?;
+        let (tx, mut rx) = unbounded_channel::<RecorderCommand>();
+        let span = tracing::info_span!("bybit.recorder", path = %path.display());
+
+        tokio::spawn(
+            async move {
+                let mut writer = BufWriter::new(file);
+                while let Some(command) = rx.recv().await {
+                    let flushed = match command {
+                        RecorderCommand::Record(entry) => {
+                            if let Err(e) = write_entry(&mut writer, &entry).await {
+                                tracing::error!("Write recording failed: {e}");
+                                break;
+                            }
+                            None
+                        }
+                        RecorderCommand::Flush(reply) => Some(reply),
+                    };
+                    // Flush once the burst is written, so a crash loses little.
+                    if rx.is_empty() || flushed.is_some() {
+                        if let Err(e) = writer.flush().await {
+                            tracing::error!("Flush recording failed: {e}");
+                            break;
+                        }
+                    }
+                    if let Some(reply) = flushed {
+                        let _ = reply.send(());
+                    }
+                }
+            }
+            .instrument(span),
+        );
+
+        let recorder = Self { tx };
+        recorder.record(RecordEntry::Connection {
+            url: url.to_owned(),
+            ts: timestamp(),
+        });
+        Ok(recorder)
+    }
+
+    pub(crate) fn incoming(&self, frame: &str) {
+        self.record(RecordEntry::In {
+            ts: timestamp(),
+            frame: frame.to_owned(),
+        });
+    }
+
+    pub(crate) fn outgoing(&self, message: &OutgoingMessage) {
+        match serialize(message) {
+            Ok(frame) => self.record(RecordEntry::Out {
+                ts: timestamp(),
+                frame,
+            }),
+            Err(e) => tracing::warn!("Serialize OutgoingMessage for recording failed: {e}"),
+        }
+    }
+
+    /// Waits until the frames recorded so far are written to the file.
+    pub(crate) async fn flush(&self) {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        if self.tx.send(RecorderCommand::Flush(reply_tx)).is_err() || reply_rx.await.is_err() {
+            tracing::debug!("Recording stopped, nothing to flush");
+        }
+    }
+
+    fn record(&self, entry: RecordEntry) {
+        if self.tx.send(RecorderCommand::Record(entry)).is_err() {
+            tracing::debug!("Recording stopped, frame dropped");
+        }
+    }
+}
+
+async fn write_entry(writer: &mut BufWriter<File>, entry: &RecordEntry) -> std::io::Result<()> {
+    let mut line = serialize(entry)?;
+    line.push('\n');
+    writer.write_all(line.as_bytes()).await
+}
+
+/// [`stream_async`](crate::stream_async) that also writes every frame to a JSONL recording
+/// at `path`, for [`replay_stream_async`].
+pub async fn recorded_stream_async(
+    url: &str,
+    ping_interval: u64,
+    path: impl AsRef<Path>,
+) -> Result<(Sender<OutgoingMessage>, Receiver<IncomingMessage>)> {
+    let recorder = Recorder::create(path.as_ref(), url).await?;
+    connect_stream(url, ping_interval, Some(recorder)).await
+}
+
+/// Pace of a replay.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReplaySpeed {
+    /// Frames keep their recorded spacing.
+    Original,
+    /// Recorded spacing divided by the factor.
+    Accelerated(f64),
+    /// Frames are sent as fast as they are received.
+    Unlimited,
+}
+
+/// Replays the received frames of a recording with the shape of
+/// [`stream_async`](crate::stream_async). Outgoing messages are accepted and dropped.
+/// The receiver is closed at the end of the recording.
+pub async fn replay_stream_async(
+    path: impl AsRef<Path>,
+    speed: ReplaySpeed,
+) -> Result<(Sender<OutgoingMessage>, Receiver<IncomingMessage>)> {
+    let path = path.as_ref();
+    let file = File::open(path).await?;
+    let (incoming_tx, incoming_rx) = channel::<IncomingMessage>(1);
+    let (outgoing_tx, mut outgoing_rx) = channel::<OutgoingMessage>(1);
+    let span = tracing::info_span!("bybit.replay", path = %path.display());
+
+    tokio::spawn(
+        async move {
+            let mut lines = BufReader::new(file).lines();
+            let start = Instant::now();
+            let mut first_ts = None;
+            loop {
+                let line = match lines.next_line().await {
+                    Ok(Some(line)) => line,
+                    Ok(None) => break,
+                    Err(e) => {
+                        tracing::error!("Read recording failed: {e}");
+                        break;
+                    }
+                };
+                let (ts, frame) = match deserialize_slice(line.as_bytes()) {
+                    Ok(RecordEntry::In { ts, frame }) => (ts, frame),
+                    Ok(RecordEntry::Connection { url, ts }) => {
+                        tracing::debug!(url, ts, "Replaying recording");
+                        continue;
+                    }
+                    Ok(RecordEntry::Out { .. }) => continue,
+                    Err(e) => {
+                        tracing::warn!(payload = %line, "Deserialize RecordEntry failed: {e}");
+                        continue;
+                    }
+                };
+
+                let elapsed = ts.saturating_sub(*first_ts.get_or_insert(ts));
+                if let Some(delay) = replay_delay(elapsed, speed) {
+                    sleep_until(start + delay).await;
+                }
+                let message = IncomingMessage::decode(&frame);
+                if let Err(e) = incoming_tx.send(message).await {
+                    tracing::debug!("Send IncomingMessage failed: {e}");
+                    break;
+                }
+            }
+        }
+        .instrument(span.clone()),
+    );
+
+    tokio::spawn(
+        async move {
+            while let Some(message) = outgoing_rx.recv().await {
+                tracing::trace!(?message, "Replay drops OutgoingMessage");
+            }
+        }
+        .instrument(span),
+    );
+
+    Ok((outgoing_tx, incoming_rx))
+}
+
+/// Time since the start of the replay at which a frame recorded `elapsed` ms after the
+/// first one is sent.
+fn replay_delay(elapsed: u64, speed: ReplaySpeed) -> Option<Duration> {
+    let elapsed = Duration::from_millis(elapsed);
+    match speed {
+        ReplaySpeed::Original => Some(elapsed),
+        ReplaySpeed::Accelerated(factor) if factor > 0.0 => Some(elapsed.div_f64(factor)),
+        ReplaySpeed::Accelerated(_) | ReplaySpeed::Unlimited => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::{SinkExt, StreamExt};
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::{accept_async, tungstenite::protocol::Message};
+
+    use super::*;
+    use crate::{CommandMsg, Error, Topic};
+
+    const SUBSCRIBE_REPLY: &str = r#"{"success":true,"ret_msg":"","conn_id":"cejreaspqfh3sjdnldmg-p","req_id":"req-0001","op":"subscribe"}"#;
+    const TRADE: &str = r#"{"topic":"publicTrade.BTCUSDT","type":"snapshot","ts":1672304486868,"data":[{"T":1672304486865,"s":"BTCUSDT","S":"Buy","v":"0.001","p":"16578.50","L":"PlusTick","i":"20f43950-d8dd-5b31-9112-a178eb6023af","BT":false}]}"#;
+
+    #[test]
+    fn test_replay_delay() {
+        assert_eq!(
+            replay_delay(1000, ReplaySpeed::Original),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(
+            replay_delay(1000, ReplaySpeed::Accelerated(4.0)),
+            Some(Duration::from_millis(250))
+        );
+        assert_eq!(replay_delay(1000, ReplaySpeed::Unlimited), None);
+    }
+
+    #[tokio::test]
+    async fn test_record_and_replay() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = accept_async(socket).await.unwrap();
+            ws.next().await.unwrap().unwrap();
+            for frame in [SUBSCRIBE_REPLY, TRADE] {
+                ws.send(Message::text(frame)).await.unwrap();
+            }
+            ws.close(None).await.unwrap();
+        });
+
+        let path = std::env::temp_dir().join(format!("bybit-sdk-record-{}.jsonl", timestamp()));
+        let (tx, mut rx) = recorded_stream_async(&url, 20, &path).await.unwrap();
+        tx.send(OutgoingMessage::Subscribe {
+            req_id: Some(String::from("req-0001")),
+            args: vec![Topic::trade("BTCUSDT")],
+        })
+        .await
+        .unwrap();
+        let mut live = vec![];
+        while let Some(message) = rx.recv().await {
+            live.push(message);
+        }
+        assert_eq!(live.len(), 3);
+        // The close is reported live, but not recorded.
+        assert!(matches!(
+            live.pop(),
+            Some(IncomingMessage::Error(Error::Closed(None)))
+        ));
+
+        let recording = tokio::fs::read_to_string(&path).await.unwrap();
+        let entries: Vec<RecordEntry> = recording
+            .lines()
+            .map(|line| deserialize_slice(line.as_bytes()).unwrap())
+            .collect();
+        assert!(matches!(&entries[0], RecordEntry::Connection { url: u, .. } if *u == url));
+        assert!(matches!(
+            &entries[1],
+            RecordEntry::Out { frame, .. } if frame == r#"{"op":"subscribe","req_id":"req-0001","args":["publicTrade.BTCUSDT"]}"#
+        ));
+        assert!(matches!(&entries[2], RecordEntry::In { frame, .. } if frame == SUBSCRIBE_REPLY));
+        assert!(matches!(&entries[3], RecordEntry::In { frame, .. } if frame == TRADE));
+
+        let (_tx, mut rx) = replay_stream_async(&path, ReplaySpeed::Unlimited)
+            .await
+            .unwrap();
+        let mut replayed = vec![];
+        while let Some(message) = rx.recv().await {
+            replayed.push(message);
+        }
+        assert_eq!(replayed, live);
+        assert!(matches!(
+            replayed[0],
+            IncomingMessage::Command(CommandMsg::Subscribe { .. })
+        ));
+
+        tokio::fs::remove_file(&path).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_replay_missing_file() {
+        let path = std::env::temp_dir().join("bybit-sdk-replay-missing.jsonl");
+        let result = replay_stream_async(&path, ReplaySpeed::Unlimited).await;
+        assert!(matches!(result, Err(Error::Io(_))));
+    }
+
+    #[tokio::test]
+    async fn test_replay_at_original_speed() {
+        let path = std::env::temp_dir().join(format!("bybit-sdk-replay-{}.jsonl", timestamp()));
+        let entries = [
+            RecordEntry::Connection {
+                url: String::from("wss://stream.bybit.com/v5/public/linear"),
+                ts: 1000,
+            },
+            RecordEntry::In {
+                ts: 1000,
+                frame: String::from(TRADE),
+            },
+            RecordEntry::In {
+                ts: 1100,
+                frame: String::from(TRADE),
+            },
+        ];
+        let recording: String = entries
+            .iter()
+            .map(|entry| serialize(entry).unwrap() + "\n")
+            .collect();
+        tokio::fs::write(&path, recording).await.unwrap();
+
+        let (_tx, mut rx) = replay_stream_async(&path, ReplaySpeed::Original)
+            .await
+            .unwrap();
+        let start = std::time::Instant::now();
+        rx.recv().await.unwrap();
+        assert!(start.elapsed() < Duration::from_millis(50));
+        rx.recv().await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(100));
+        assert!(rx.recv().await.is_none());
+
+        tokio::fs::remove_file(&path).await.unwrap();
+    }
+}
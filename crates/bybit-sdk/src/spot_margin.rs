@@ -0,0 +1,65 @@
+use crate::{
+    url::{
+        PATH_SPOT_MARGIN_TRADE_SET_LEVERAGE, PATH_SPOT_MARGIN_TRADE_SET_PLEDGE_TOKEN,
+        PATH_SPOT_MARGIN_TRADE_SWITCH_MODE,
+    },
+    Client, Result, SetPledgeTokenParams, SetSpotLeverageParams, SwitchSpotMarginMode,
+    SwitchSpotMarginModeParams,
+};
+
+impl Client {
+    /// Turns spot margin trading of a unified account on or off.
+    pub async fn switch_spot_margin_mode(
+        &self,
+        params: SwitchSpotMarginModeParams,
+    ) -> Result<SwitchSpotMarginMode> {
+        self.post_signed(PATH_SPOT_MARGIN_TRADE_SWITCH_MODE, &params)
+            .await
+    }
+
+    pub async fn set_spot_leverage(&self, params: SetSpotLeverageParams) -> Result<()> {
+        self.post_empty(PATH_SPOT_MARGIN_TRADE_SET_LEVERAGE, &params)
+            .await
+    }
+
+    pub async fn set_pledge_token(&self, params: SetPledgeTokenParams) -> Result<()> {
+        self.post_empty(PATH_SPOT_MARGIN_TRADE_SET_PLEDGE_TOKEN, &params)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bybit_mock::MockServer;
+    use serde_json::json;
+
+    use super::*;
+    use crate::{client::tests::signed_client, number::n, SpotMarginMode};
+
+    #[tokio::test]
+    async fn test_spot_margin() {
+        let server = MockServer::start().await;
+        server.mock_rest(
+            PATH_SPOT_MARGIN_TRADE_SWITCH_MODE,
+            json!({ "spotMarginMode": "1" }),
+        );
+        server.mock_rest(PATH_SPOT_MARGIN_TRADE_SET_LEVERAGE, json!({}));
+        let client = signed_client(&server.url());
+
+        let result = client
+            .switch_spot_margin_mode(SwitchSpotMarginModeParams {
+                spot_margin_mode: SpotMarginMode::On,
+            })
+            .await
+            .unwrap();
+        assert_eq!(result.spot_margin_mode, SpotMarginMode::On);
+        client
+            .set_spot_leverage(SetSpotLeverageParams { leverage: n!(4.0) })
+            .await
+            .unwrap();
+
+        let requests = server.rest_requests();
+        assert_eq!(requests[0].body, r#"{"spotMarginMode":"1"}"#);
+        assert_eq!(requests[1].body, r#"{"leverage":"4"}"#);
+    }
+}
@@ -1,106 +1,1038 @@
-use futures_util::{SinkExt, StreamExt};
-use std::time::Duration;
+use futures_util::{stream::SplitStream, SinkExt, StreamExt};
+use std::{collections::VecDeque, sync::Arc, time::Duration};
 use tokio::{
     self,
+    net::TcpStream,
     sync::mpsc::{channel, Receiver, Sender},
-    time::sleep,
+    time::{interval_at, sleep, sleep_until, timeout, Instant},
 };
 use tokio_tungstenite::{
     connect_async,
     tungstenite::{protocol::Message, Utf8Bytes},
+    MaybeTlsStream, WebSocketStream,
 };
 
+use tracing::Instrument;
+
 use crate::{
     common::{deserialize_slice, serialize},
-    IncomingMessage, OutgoingMessage,
+    record::Recorder,
+    CommandMsg, Credentials, EndpointKind, EndpointSet, Error, IncomingMessage, OutgoingMessage,
+    Result, Topic,
 };
 
 pub async fn stream_async(
     url: &str,
     ping_interval: u64,
-) -> anyhow::Result<(Sender<OutgoingMessage>, Receiver<IncomingMessage>)> {
+) -> Result<(Sender<OutgoingMessage>, Receiver<IncomingMessage>)> {
+    connect_stream(url, ping_interval, None).await
+}
+
+/// [`stream_async`] with an optional recorder of the raw frames.
+pub(crate) async fn connect_stream(
+    url: &str,
+    ping_interval: u64,
+    recorder: Option<Recorder>,
+) -> Result<(Sender<OutgoingMessage>, Receiver<IncomingMessage>)> {
     let (incoming_tx, incoming_rx) = channel::<IncomingMessage>(1);
     let (outgoing_tx, mut outgoing_rx) = channel::<OutgoingMessage>(1);
 
     let (stream, _) = connect_async(url).await?;
     let (mut sender, mut receiver) = stream.split();
+    let span = tracing::info_span!("bybit.stream", url);
 
+    let outgoing_recorder = recorder.clone();
     let handshake = outgoing_tx.clone();
-    tokio::spawn(async move {
-        let mut count = 0_u64;
-        loop {
-            sleep(Duration::from_secs(ping_interval)).await;
-            count += 1;
-            let id = format!("ping-{count}");
-            let message = OutgoingMessage::Ping { req_id: Some(id) };
-            if let Err(e) = handshake.send(message).await {
-                println!("Send ping error: {e}");
-                break;
-            };
+    tokio::spawn(
+        async move {
+            let mut count = 0_u64;
+            loop {
+                sleep(Duration::from_secs(ping_interval)).await;
+                count += 1;
+                let id = format!("ping-{count}");
+                let message = OutgoingMessage::Ping { req_id: Some(id) };
+                if let Err(e) = handshake.send(message).await {
+                    tracing::debug!("Send ping failed: {e}");
+                    break;
+                };
+            }
         }
-    });
+        .instrument(span.clone()),
+    );
 
-    tokio::spawn(async move {
-        while let Some(result) = receiver.next().await {
-            match result {
-                Ok(message) => match message {
-                    Message::Text(slice) => {
-                        match deserialize_slice(slice.as_ref()) {
-                            Ok(message) => {
-                                if let Err(e) = incoming_tx.send(message).await {
-                                    println!("[bybit.stream.incoming] Send IncomingMessage failed with: {e}!");
-                                }
-                            }
-                            Err(e) => {
-                                println!("[bybit.stream.incoming] Deserialize IncomingMessage failed with: {e}!");
-                                println!("[bybit.stream.incoming] DEBUG: IncomingMessage: {slice}");
-                            }
-                        };
-                    }
-                    Message::Binary(d) => println!(
-                        "[bybit.stream.binary] Binary got {} bytes: {:?}",
-                        d.len(),
-                        d
-                    ),
-                    Message::Close(close_frame) => {
-                        match close_frame {
-                            Some(close_frame) => println!(
-                                "[bybit.stream.close] Close got close with code {} and reason `{}`",
-                                close_frame.code, close_frame.reason
-                            ),
-                            None => {
-                                println!("[bybit.stream.close] Close somehow got close message without CloseFrame")
-                            }
+    tokio::spawn(
+        async move {
+            while let Some(result) = receiver.next().await {
+                if let (Some(recorder), Ok(Message::Text(slice))) = (&recorder, &result) {
+                    recorder.incoming(slice);
+                }
+                match result {
+                    Ok(Message::Text(slice)) => {
+                        let message = IncomingMessage::decode(slice.as_str());
+                        if let Err(e) = incoming_tx.send(message).await {
+                            tracing::debug!("Send IncomingMessage failed: {e}");
+                            break;
                         }
-
+                    }
+                    Ok(Message::Binary(d)) => tracing::debug!("Binary got {} bytes", d.len()),
+                    Ok(Message::Close(close_frame)) => {
+                        let error = Error::Closed(close_frame);
+                        tracing::info!("{error}");
+                        let _ = incoming_tx.send(IncomingMessage::Error(error)).await;
                         break;
                     }
-                    Message::Pong(v) => println!("[bybit.stream.pong] Pong got pong with {v:?}."),
-                    Message::Ping(v) => println!("[bybit.stream.ping] Ping got ping with {v:?}."),
-                    Message::Frame(_) => {
+                    Ok(Message::Pong(v)) => tracing::trace!("Pong got {v:?}"),
+                    Ok(Message::Ping(v)) => tracing::trace!("Ping got {v:?}"),
+                    Ok(Message::Frame(_)) => {
                         unreachable!("Frame This is never supposed to happen.")
                     }
-                },
-                Err(e) => println!("[bybit.stream] Receive message failed with: {e}!"),
+                    Err(e) => {
+                        tracing::error!("Receive message failed: {e}");
+                        let _ = incoming_tx.send(IncomingMessage::Error(e.into())).await;
+                        break;
+                    }
+                }
+            }
+            // The recording is complete once the receiver closes.
+            if let Some(recorder) = &recorder {
+                recorder.flush().await;
             }
         }
-    });
+        .instrument(span.clone()),
+    );
 
-    tokio::spawn(async move {
-        while let Some(message) = outgoing_rx.recv().await {
-            let message = match serialize(&message) {
-                Ok(serialized) => Message::Text(Utf8Bytes::from(&serialized)),
-                Err(e) => {
-                    println!("[bybit.stream.outgoing] Serialize OutgoingMessage failed with {e}!");
-                    continue;
+    tokio::spawn(
+        async move {
+            while let Some(message) = outgoing_rx.recv().await {
+                if let Some(recorder) = &outgoing_recorder {
+                    recorder.outgoing(&message);
                 }
-            };
+                if let Err(e) = send(&mut sender, &message).await {
+                    tracing::error!("Send OutgoingMessage failed: {e}");
+                };
+            }
+        }
+        .instrument(span),
+    );
+
+    Ok((outgoing_tx, incoming_rx))
+}
+
+/// Sends a signed `auth` request and waits for its result.
+/// Messages received before the reply are dropped, so call it before subscribing.
+pub async fn authenticate(
+    tx: &Sender<OutgoingMessage>,
+    rx: &mut Receiver<IncomingMessage>,
+    credentials: &Credentials,
+    wait: Duration,
+) -> Result<()> {
+    tx.send(credentials.auth_message(Some(String::from("auth"))))
+        .await
+        .map_err(|_| Error::Closed(None))?;
+
+    let reply = async {
+        while let Some(message) = rx.recv().await {
+            if let IncomingMessage::Command(CommandMsg::Auth {
+                success, ret_msg, ..
+            }) = message
+            {
+                return Some((success, ret_msg));
+            }
+        }
+        None
+    };
+
+    match timeout(wait, reply).await {
+        Ok(Some((true, _))) => Ok(()),
+        Ok(Some((false, ret_msg))) => Err(Error::Auth(ret_msg.unwrap_or_default())),
+        Ok(None) => Err(Error::Closed(None)),
+        Err(_) => Err(Error::Timeout(String::from("auth"))),
+    }
+}
+
+/// Connects to a private stream (`PATH_PRIVATE`) and authenticates it.
+pub async fn private_stream_async(
+    url: &str,
+    ping_interval: u64,
+    credentials: &Credentials,
+) -> Result<(Sender<OutgoingMessage>, Receiver<IncomingMessage>)> {
+    let (tx, mut rx) = stream_async(url, ping_interval).await?;
+    authenticate(&tx, &mut rx, credentials, AUTH_TIMEOUT).await?;
+    Ok((tx, rx))
+}
+
+const AUTH_TIMEOUT: Duration = Duration::from_secs(10);
+/// Maximum number of topics per `subscribe` request accepted by Bybit spot streams.
+const MAX_ARGS_PER_SUBSCRIBE: usize = 10;
+const CHANNEL_CAPACITY: usize = 64;
+
+pub(crate) type WebSocket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// `subscribe` requests waiting for their ack, with the topics each of them added.
+type PendingSubscribes = VecDeque<(Option<String>, Vec<Topic>)>;
+
+pub struct StreamConfig {
+    pub ping_interval: Duration,
+    /// How long to wait for a pong after a ping before the connection is considered dead.
+    pub pong_timeout: Duration,
+    pub backoff_initial: Duration,
+    pub backoff_max: Duration,
+    /// Used to authenticate private streams (`PATH_PRIVATE`) after every (re)connect.
+    pub credentials: Option<Credentials>,
+}
+
+impl Default for StreamConfig {
+    fn default() -> Self {
+        Self {
+            ping_interval: Duration::from_secs(20),
+            pong_timeout: Duration::from_secs(10),
+            backoff_initial: Duration::from_secs(1),
+            backoff_max: Duration::from_secs(60),
+            credentials: None,
+        }
+    }
+}
 
-            if let Err(e) = sender.send(message).await {
-                println!("[bybit.stream.outgoing] Send OutgoingMessage failed with {e}!");
+#[derive(Debug)]
+pub enum StreamEvent {
+    Message(IncomingMessage),
+    /// Non-fatal problem: the stream keeps running.
+    Error(Error),
+    Connected,
+    Disconnected,
+    Reconnecting {
+        attempt: u32,
+        delay: Duration,
+    },
+    Resubscribed {
+        topics: Vec<Topic>,
+    },
+}
+
+enum SessionEnd {
+    Disconnected,
+    Shutdown,
+}
+
+/// Like [`stream_async`], but survives disconnects: the connection is re-established with
+/// exponential backoff, re-authenticated and every active subscription is replayed.
+/// The stream stops when the returned `Sender` is dropped.
+pub async fn managed_stream_async(
+    url: &str,
+    config: StreamConfig,
+) -> Result<(Sender<OutgoingMessage>, Receiver<StreamEvent>)> {
+    let endpoints = Arc::new(EndpointSet::new(EndpointKind::Stream, &[url]));
+    managed_stream_failover(endpoints, "", config).await
+}
+
+/// Like [`managed_stream_async`], but connects to `path` of the current endpoint of the set.
+/// An endpoint that cannot be connected to is reported, so the next attempt uses another one.
+pub async fn managed_stream_failover(
+    endpoints: Arc<EndpointSet>,
+    path: &str,
+    config: StreamConfig,
+) -> Result<(Sender<OutgoingMessage>, Receiver<StreamEvent>)> {
+    let (event_tx, event_rx) = channel::<StreamEvent>(CHANNEL_CAPACITY);
+    let (outgoing_tx, outgoing_rx) = channel::<OutgoingMessage>(CHANNEL_CAPACITY);
+
+    let mut attempts = endpoints.len();
+    let stream = loop {
+        attempts -= 1;
+        match connect_endpoint(&endpoints, path).await {
+            Ok(stream) => break stream,
+            Err(e) if attempts == 0 => return Err(e),
+            Err(e) => tracing::warn!(path, "Connect failed, failing over: {e}"),
+        }
+    };
+
+    let span = tracing::info_span!("bybit.stream.managed", path);
+    tokio::spawn(
+        supervise(
+            endpoints,
+            path.to_owned(),
+            config,
+            stream,
+            outgoing_rx,
+            event_tx,
+        )
+        .instrument(span),
+    );
+
+    Ok((outgoing_tx, event_rx))
+}
+
+/// Connects to `path` of the current endpoint and reports the outcome to the set.
+async fn connect_endpoint(endpoints: &EndpointSet, path: &str) -> Result<WebSocket> {
+    let base_url = endpoints.current();
+    match connect_async(format!("{base_url}{path}")).await {
+        Ok((stream, _)) => {
+            endpoints.report_success(&base_url, None);
+            Ok(stream)
+        }
+        Err(e) => {
+            endpoints.report_failure(&base_url);
+            Err(e.into())
+        }
+    }
+}
+
+async fn supervise(
+    endpoints: Arc<EndpointSet>,
+    path: String,
+    config: StreamConfig,
+    stream: WebSocket,
+    mut outgoing_rx: Receiver<OutgoingMessage>,
+    event_tx: Sender<StreamEvent>,
+) {
+    let mut stream = Some(stream);
+    let mut topics = vec![];
+    let mut attempt = 0;
+
+    loop {
+        let connection = match stream.take() {
+            Some(stream) => stream,
+            None => {
+                attempt += 1;
+                let delay = backoff_delay(attempt, config.backoff_initial, config.backoff_max);
+                if event_tx
+                    .send(StreamEvent::Reconnecting { attempt, delay })
+                    .await
+                    .is_err()
+                {
+                    return;
+                }
+                sleep(delay).await;
+
+                match connect_endpoint(&endpoints, &path).await {
+                    Ok(stream) => stream,
+                    Err(e) => {
+                        tracing::warn!(attempt, "Reconnect failed: {e}");
+                        if event_tx.send(StreamEvent::Error(e)).await.is_err() {
+                            return;
+                        }
+                        continue;
+                    }
+                }
+            }
+        };
+        attempt = 0;
+
+        tracing::info!("Connected");
+        if event_tx.send(StreamEvent::Connected).await.is_err() {
+            return;
+        }
+
+        let end = run_session(
+            connection,
+            &config,
+            &mut topics,
+            &mut outgoing_rx,
+            &event_tx,
+        )
+        .await;
+
+        match end {
+            SessionEnd::Disconnected => {
+                tracing::info!("Disconnected");
+                if event_tx.send(StreamEvent::Disconnected).await.is_err() {
+                    return;
+                }
+            }
+            SessionEnd::Shutdown => return,
+        }
+    }
+}
+
+async fn run_session(
+    stream: WebSocket,
+    config: &StreamConfig,
+    topics: &mut Vec<Topic>,
+    outgoing_rx: &mut Receiver<OutgoingMessage>,
+    event_tx: &Sender<StreamEvent>,
+) -> SessionEnd {
+    let (mut sender, mut receiver) = stream.split();
+    let mut pending = PendingSubscribes::new();
+
+    if let Some(credentials) = &config.credentials {
+        if send(
+            &mut sender,
+            &credentials.auth_message(Some(String::from("auth"))),
+        )
+        .await
+        .is_err()
+        {
+            return SessionEnd::Disconnected;
+        }
+        if let Err(e) = wait_auth(&mut receiver).await {
+            tracing::warn!("Authenticate failed: {e}");
+            return end_session(event_tx, e).await;
+        }
+    }
+
+    if !topics.is_empty() {
+        for (i, args) in topics.chunks(MAX_ARGS_PER_SUBSCRIBE).enumerate() {
+            let req_id = format!("resubscribe-{i}");
+            let message = OutgoingMessage::Subscribe {
+                req_id: Some(req_id.clone()),
+                args: args.to_vec(),
             };
+            if send(&mut sender, &message).await.is_err() {
+                return SessionEnd::Disconnected;
+            }
+            pending.push_back((Some(req_id), args.to_vec()));
+        }
+        let event = StreamEvent::Resubscribed {
+            topics: topics.clone(),
+        };
+        if event_tx.send(event).await.is_err() {
+            return SessionEnd::Shutdown;
         }
-    });
+    }
 
-    Ok((outgoing_tx, incoming_rx))
+    let mut ping = interval_at(Instant::now() + config.ping_interval, config.ping_interval);
+    // Armed when a ping is sent, disarmed by the pong.
+    let mut pong_deadline: Option<Instant> = None;
+    let mut count = 0_u64;
+
+    loop {
+        tokio::select! {
+            message = outgoing_rx.recv() => match message {
+                Some(message) => {
+                    if send(&mut sender, &message).await.is_err() {
+                        return SessionEnd::Disconnected;
+                    }
+                    let added = track_topics(topics, &message);
+                    if let OutgoingMessage::Subscribe { req_id, .. } = message {
+                        pending.push_back((req_id, added));
+                    }
+                }
+                None => {
+                    let _ = sender.close().await;
+                    return SessionEnd::Shutdown;
+                }
+            },
+            result = receiver.next() => match result {
+                Some(Ok(Message::Text(slice))) => {
+                    let event = match IncomingMessage::decode(slice.as_str()) {
+                        IncomingMessage::Error(error) => StreamEvent::Error(error),
+                        message => {
+                            if is_pong(&message) {
+                                pong_deadline = None;
+                            }
+                            acknowledge(topics, &mut pending, &message);
+                            StreamEvent::Message(message)
+                        }
+                    };
+                    if event_tx.send(event).await.is_err() {
+                        return SessionEnd::Shutdown;
+                    }
+                }
+                Some(Ok(Message::Pong(_))) => pong_deadline = None,
+                Some(Ok(Message::Close(close_frame))) => {
+                    let error = Error::Closed(close_frame);
+                    tracing::info!("{error}");
+                    return end_session(event_tx, error).await;
+                }
+                Some(Ok(_)) => {}
+                Some(Err(e)) => {
+                    tracing::warn!("Receive message failed: {e}");
+                    return end_session(event_tx, e.into()).await;
+                }
+                None => return SessionEnd::Disconnected,
+            },
+            _ = ping.tick() => {
+                count += 1;
+                let message = OutgoingMessage::Ping { req_id: Some(format!("ping-{count}")) };
+                if send(&mut sender, &message).await.is_err() {
+                    return SessionEnd::Disconnected;
+                }
+                pong_deadline.get_or_insert(Instant::now() + config.pong_timeout);
+            }
+            _ = sleep_until(pong_deadline.unwrap_or_else(Instant::now)),
+                if pong_deadline.is_some() =>
+            {
+                tracing::warn!("Pong is missing, reconnecting");
+                return end_session(event_tx, Error::Timeout(String::from("pong"))).await;
+            }
+        }
+    }
+}
+
+/// Waits for the reply to the `auth` request, so private topics are only resubscribed on an
+/// authenticated connection. Messages received before the reply are dropped.
+async fn wait_auth(receiver: &mut SplitStream<WebSocket>) -> Result<()> {
+    let reply = async {
+        while let Some(result) = receiver.next().await {
+            match result? {
+                Message::Text(slice) => {
+                    if let Ok(IncomingMessage::Command(CommandMsg::Auth {
+                        success, ret_msg, ..
+                    })) = deserialize_slice(slice.as_ref())
+                    {
+                        return match success {
+                            true => Ok(()),
+                            false => Err(Error::Auth(ret_msg.unwrap_or_default())),
+                        };
+                    }
+                }
+                Message::Close(close_frame) => return Err(Error::Closed(close_frame)),
+                _ => {}
+            }
+        }
+        Err(Error::Closed(None))
+    };
+    timeout(AUTH_TIMEOUT, reply)
+        .await
+        .map_err(|_| Error::Timeout(String::from("auth")))?
+}
+
+/// Reports why the session ended before it is restarted.
+async fn end_session(event_tx: &Sender<StreamEvent>, error: Error) -> SessionEnd {
+    match event_tx.send(StreamEvent::Error(error)).await {
+        Ok(()) => SessionEnd::Disconnected,
+        Err(_) => SessionEnd::Shutdown,
+    }
+}
+
+async fn send<S>(sender: &mut S, message: &OutgoingMessage) -> Result<()>
+where
+    S: SinkExt<Message> + Unpin,
+    Error: From<S::Error>,
+{
+    let serialized = serialize(message).map_err(Error::Serialize)?;
+    sender
+        .send(Message::Text(Utf8Bytes::from(&serialized)))
+        .await?;
+    Ok(())
+}
+
+/// Spot answers a ping with `op: "ping"`, other categories with `op: "pong"`.
+fn is_pong(message: &IncomingMessage) -> bool {
+    matches!(
+        message,
+        IncomingMessage::Command(CommandMsg::Pong { .. } | CommandMsg::Ping { .. })
+    )
+}
+
+/// Records the topics of a sent request. Returns the topics a `subscribe` added.
+fn track_topics(topics: &mut Vec<Topic>, message: &OutgoingMessage) -> Vec<Topic> {
+    let mut added = vec![];
+    match message {
+        OutgoingMessage::Subscribe { args, .. } => {
+            for topic in args {
+                if !topics.contains(topic) {
+                    topics.push(topic.clone());
+                    added.push(topic.clone());
+                }
+            }
+        }
+        OutgoingMessage::Unsubscribe { args, .. } => topics.retain(|topic| !args.contains(topic)),
+        _ => {}
+    }
+    added
+}
+
+/// Matches a `subscribe` ack to its request by `req_id`, or to the oldest one without.
+/// The topics of a rejected request are dropped, so they are not replayed on reconnect.
+fn acknowledge(
+    topics: &mut Vec<Topic>,
+    pending: &mut PendingSubscribes,
+    message: &IncomingMessage,
+) {
+    let IncomingMessage::Command(CommandMsg::Subscribe {
+        req_id,
+        ret_msg,
+        success,
+        ..
+    }) = message
+    else {
+        return;
+    };
+    let index = match req_id {
+        Some(req_id) => pending
+            .iter()
+            .position(|(id, _)| id.as_ref() == Some(req_id)),
+        None => pending.iter().position(|(id, _)| id.is_none()),
+    };
+    let Some((_, added)) = index.and_then(|index| pending.remove(index)) else {
+        return;
+    };
+    if *success == Some(false) {
+        tracing::warn!(
+            ?added,
+            "Subscribe rejected: {}",
+            ret_msg.as_deref().unwrap_or_default()
+        );
+        topics.retain(|topic| !added.contains(topic));
+    }
+}
+
+fn backoff_delay(attempt: u32, initial: Duration, max: Duration) -> Duration {
+    let factor = 2_u32.saturating_pow(attempt.saturating_sub(1));
+    initial.saturating_mul(factor).min(max)
+}
+
+#[cfg(test)]
+mod tests {
+    use bybit_mock::{AuthMode, MockServer, PATH_PRIVATE, PATH_PUBLIC_LINEAR};
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::accept_async;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_authenticate() {
+        let credentials = Credentials::new("api_key", "secret");
+        for (success, ok) in [(true, true), (false, false)] {
+            let (tx, mut outgoing_rx) = channel::<OutgoingMessage>(1);
+            let (incoming_tx, mut rx) = channel::<IncomingMessage>(1);
+            tokio::spawn(async move {
+                let message = outgoing_rx.recv().await.unwrap();
+                assert!(matches!(message, OutgoingMessage::Auth { .. }));
+                let reply = IncomingMessage::Command(CommandMsg::Auth {
+                    req_id: None,
+                    ret_msg: Some(String::from("Params Error")),
+                    conn_id: String::from("cejreaspqfh3sjdnldmg-p"),
+                    success,
+                });
+                incoming_tx.send(reply).await.unwrap();
+            });
+
+            let result = authenticate(&tx, &mut rx, &credentials, Duration::from_secs(1)).await;
+            match ok {
+                true => assert!(result.is_ok()),
+                false => assert!(
+                    matches!(result, Err(Error::Auth(message)) if message == "Params Error")
+                ),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_timeout() {
+        let credentials = Credentials::new("api_key", "secret");
+        let (tx, _outgoing_rx) = channel::<OutgoingMessage>(1);
+        let (_incoming_tx, mut rx) = channel::<IncomingMessage>(1);
+        let result = authenticate(&tx, &mut rx, &credentials, Duration::from_millis(10)).await;
+        assert!(matches!(result, Err(Error::Timeout(_))));
+    }
+
+    #[test]
+    fn test_backoff_delay() {
+        let initial = Duration::from_secs(1);
+        let max = Duration::from_secs(60);
+        assert_eq!(backoff_delay(1, initial, max), Duration::from_secs(1));
+        assert_eq!(backoff_delay(2, initial, max), Duration::from_secs(2));
+        assert_eq!(backoff_delay(5, initial, max), Duration::from_secs(16));
+        assert_eq!(backoff_delay(7, initial, max), max);
+        assert_eq!(backoff_delay(100, initial, max), max);
+    }
+
+    #[test]
+    fn test_track_topics() {
+        let mut topics = vec![];
+        let added = track_topics(
+            &mut topics,
+            &OutgoingMessage::Subscribe {
+                req_id: None,
+                args: vec![Topic::ticker("BTCUSDT"), Topic::ticker("ETHUSDT")],
+            },
+        );
+        assert_eq!(added, topics);
+        let added = track_topics(
+            &mut topics,
+            &OutgoingMessage::Subscribe {
+                req_id: None,
+                args: vec![Topic::ticker("BTCUSDT")],
+            },
+        );
+        assert!(added.is_empty());
+        track_topics(
+            &mut topics,
+            &OutgoingMessage::Unsubscribe {
+                req_id: None,
+                args: vec![Topic::ticker("ETHUSDT")],
+            },
+        );
+        assert_eq!(topics, vec![Topic::ticker("BTCUSDT")]);
+    }
+
+    #[test]
+    fn test_acknowledge() {
+        let ack = |req_id: &str, success| {
+            IncomingMessage::Command(CommandMsg::Subscribe {
+                req_id: Some(req_id.to_owned()),
+                ret_msg: None,
+                conn_id: String::from("cejreaspqfh3sjdnldmg-p"),
+                success: Some(success),
+            })
+        };
+        let mut topics = vec![Topic::ticker("BTCUSDT"), Topic::ticker("ETHUSDT")];
+        let mut pending = PendingSubscribes::from([
+            (Some(String::from("req-1")), vec![Topic::ticker("BTCUSDT")]),
+            (Some(String::from("req-2")), vec![Topic::ticker("ETHUSDT")]),
+        ]);
+
+        acknowledge(&mut topics, &mut pending, &ack("req-2", false));
+        assert_eq!(topics, vec![Topic::ticker("BTCUSDT")]);
+        acknowledge(&mut topics, &mut pending, &ack("req-1", true));
+        assert_eq!(topics, vec![Topic::ticker("BTCUSDT")]);
+        assert!(pending.is_empty());
+
+        // Acks of unknown requests are ignored.
+        acknowledge(&mut topics, &mut pending, &ack("req-1", false));
+        assert_eq!(topics, vec![Topic::ticker("BTCUSDT")]);
+    }
+
+    #[tokio::test]
+    async fn test_managed_stream_resubscribes_after_close() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+
+        let server = tokio::spawn(async move {
+            let mut received = vec![];
+            for _ in 0..2 {
+                let (socket, _) = listener.accept().await.unwrap();
+                let mut ws = accept_async(socket).await.unwrap();
+                if let Some(Ok(Message::Text(text))) = ws.next().await {
+                    received.push(text.to_string());
+                }
+                ws.close(None).await.unwrap();
+            }
+            received
+        });
+
+        let config = StreamConfig {
+            backoff_initial: Duration::from_millis(10),
+            ..Default::default()
+        };
+        let (tx, mut rx) = managed_stream_async(&url, config).await.unwrap();
+        tx.send(OutgoingMessage::Subscribe {
+            req_id: Some(String::from("req-0001")),
+            args: vec![Topic::ticker("BTCUSDT")],
+        })
+        .await
+        .unwrap();
+
+        assert!(matches!(rx.recv().await, Some(StreamEvent::Connected)));
+        assert!(matches!(
+            rx.recv().await,
+            Some(StreamEvent::Error(Error::Closed(_)))
+        ));
+        assert!(matches!(rx.recv().await, Some(StreamEvent::Disconnected)));
+        assert!(matches!(
+            rx.recv().await,
+            Some(StreamEvent::Reconnecting { attempt: 1, delay }) if delay == Duration::from_millis(10)
+        ));
+        assert!(matches!(rx.recv().await, Some(StreamEvent::Connected)));
+        assert!(matches!(
+            rx.recv().await,
+            Some(StreamEvent::Resubscribed { topics }) if topics == vec![Topic::ticker("BTCUSDT")]
+        ));
+
+        let received = server.await.unwrap();
+        assert_eq!(
+            received,
+            vec![
+                String::from(
+                    r#"{"op":"subscribe","req_id":"req-0001","args":["tickers.BTCUSDT"]}"#
+                ),
+                String::from(
+                    r#"{"op":"subscribe","req_id":"resubscribe-0","args":["tickers.BTCUSDT"]}"#
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_subscribe_and_ping_with_mock() {
+        let server = MockServer::start().await;
+        let ticker = r#"{"topic":"tickers.BTCUSDT","type":"snapshot","data":{"symbol":"BTCUSDT","tickDirection":"PlusTick","price24hPcnt":"0.017103","lastPrice":"17216.00","prevPrice24h":"16926.50","highPrice24h":"17281.50","lowPrice24h":"16915.00","prevPrice1h":"17238.00","markPrice":"17217.33","indexPrice":"17227.36","openInterest":"68744.761","openInterestValue":"1183601235.91","turnover24h":"1570383121.943499","volume24h":"91705.276","nextFundingTime":"1673280000000","fundingRate":"-0.000212","bid1Price":"17215.50","bid1Size":"84.489","ask1Price":"17216.00","ask1Size":"83.020"},"cs":24987956059,"ts":1673272861686}"#;
+        server.on_subscribe("tickers.BTCUSDT", vec![ticker.to_owned()]);
+
+        let (tx, mut rx) = stream_async(&server.ws_url(PATH_PUBLIC_LINEAR), 1)
+            .await
+            .unwrap();
+        tx.send(OutgoingMessage::Subscribe {
+            req_id: Some(String::from("req-0001")),
+            args: vec![Topic::ticker("BTCUSDT")],
+        })
+        .await
+        .unwrap();
+
+        assert!(matches!(
+            rx.recv().await,
+            Some(IncomingMessage::Command(CommandMsg::Subscribe { success: Some(true), req_id, .. }))
+                if req_id.as_deref() == Some("req-0001")
+        ));
+        assert!(matches!(rx.recv().await, Some(IncomingMessage::Ticker(_))));
+        assert!(matches!(
+            rx.recv().await,
+            Some(IncomingMessage::Command(CommandMsg::Ping { req_id, .. }))
+                if req_id.as_deref() == Some("ping-1")
+        ));
+
+        server.disconnect_all();
+        assert!(matches!(
+            rx.recv().await,
+            Some(IncomingMessage::Error(Error::Closed(Some(frame)))) if frame.reason == "mock disconnect"
+        ));
+        assert!(rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_stream_passes_on_undecodable_frame() {
+        let server = MockServer::start().await;
+        let frame = r#"{"topic":"tickers.BTCUSDT","type":"snapshot","data":{}}"#;
+        server.on_subscribe("tickers.BTCUSDT", vec![frame.to_owned()]);
+
+        let (tx, mut rx) = stream_async(&server.ws_url(PATH_PUBLIC_LINEAR), 20)
+            .await
+            .unwrap();
+        tx.send(OutgoingMessage::Subscribe {
+            req_id: None,
+            args: vec![Topic::ticker("BTCUSDT")],
+        })
+        .await
+        .unwrap();
+
+        assert!(matches!(
+            rx.recv().await,
+            Some(IncomingMessage::Command(CommandMsg::Subscribe { .. }))
+        ));
+        let Some(IncomingMessage::Error(Error::Deserialize { payload, .. })) = rx.recv().await
+        else {
+            panic!("expected an undecodable message");
+        };
+        assert_eq!(payload, frame);
+    }
+
+    #[tokio::test]
+    async fn test_private_stream_auth_with_mock() {
+        let server = MockServer::start().await;
+        server.set_auth(AuthMode::Verify {
+            api_key: String::from("api_key"),
+            api_secret: String::from("secret"),
+        });
+        let url = server.ws_url(PATH_PRIVATE);
+
+        let credentials = Credentials::new("api_key", "secret");
+        let (tx, mut rx) = private_stream_async(&url, 20, &credentials).await.unwrap();
+        tx.send(OutgoingMessage::Ping {
+            req_id: Some(String::from("ping")),
+        })
+        .await
+        .unwrap();
+        assert!(matches!(
+            rx.recv().await,
+            Some(IncomingMessage::Command(CommandMsg::Pong {
+                success: true,
+                ..
+            }))
+        ));
+
+        let credentials = Credentials::new("api_key", "wrong");
+        let result = private_stream_async(&url, 20, &credentials).await;
+        assert!(matches!(result, Err(Error::Auth(_))));
+    }
+
+    #[tokio::test]
+    async fn test_managed_stream_waits_for_auth() {
+        let server = MockServer::start().await;
+        server.set_auth(AuthMode::Reject);
+        let config = StreamConfig {
+            backoff_initial: Duration::from_millis(10),
+            credentials: Some(Credentials::new("api_key", "secret")),
+            ..Default::default()
+        };
+        let (tx, mut rx) = managed_stream_async(&server.ws_url(PATH_PRIVATE), config)
+            .await
+            .unwrap();
+        tx.send(OutgoingMessage::Subscribe {
+            req_id: None,
+            args: vec![Topic::Order { category: None }],
+        })
+        .await
+        .unwrap();
+
+        assert!(matches!(rx.recv().await, Some(StreamEvent::Connected)));
+        assert!(matches!(
+            rx.recv().await,
+            Some(StreamEvent::Error(Error::Auth(_)))
+        ));
+        assert!(matches!(rx.recv().await, Some(StreamEvent::Disconnected)));
+        assert!(matches!(
+            rx.recv().await,
+            Some(StreamEvent::Reconnecting { attempt: 1, .. })
+        ));
+        assert!(server
+            .received()
+            .iter()
+            .all(|frame| frame.contains(r#""op":"auth""#)));
+
+        server.set_auth(AuthMode::Accept);
+        loop {
+            match rx.recv().await {
+                Some(StreamEvent::Message(IncomingMessage::Command(CommandMsg::Subscribe {
+                    ..
+                }))) => break,
+                Some(StreamEvent::Resubscribed { .. }) => panic!("nothing to resubscribe yet"),
+                Some(_) => continue,
+                None => panic!("stream ended"),
+            }
+        }
+        let received = server.received();
+        assert!(received.last().unwrap().contains(r#""op":"subscribe""#));
+        assert!(received[received.len() - 2].contains(r#""op":"auth""#));
+    }
+
+    #[tokio::test]
+    async fn test_managed_stream_failover() {
+        let server = MockServer::start().await;
+        let closed = crate::endpoint::tests::closed_url("ws").await;
+        let endpoints = Arc::new(EndpointSet::new(
+            EndpointKind::Stream,
+            &[&closed, &server.ws_url("")],
+        ));
+        let (_tx, mut rx) =
+            managed_stream_failover(endpoints.clone(), PATH_PUBLIC_LINEAR, Default::default())
+                .await
+                .unwrap();
+
+        assert!(matches!(rx.recv().await, Some(StreamEvent::Connected)));
+        assert_eq!(server.connections(), 1);
+        assert_eq!(endpoints.status()[0].failures, 1);
+        assert_eq!(endpoints.current(), server.ws_url(""));
+
+        let endpoints = Arc::new(EndpointSet::new(EndpointKind::Stream, &[&closed]));
+        let result =
+            managed_stream_failover(endpoints, PATH_PUBLIC_LINEAR, Default::default()).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_managed_stream_reconnects_after_mock_disconnect() {
+        let server = MockServer::start().await;
+        let config = StreamConfig {
+            backoff_initial: Duration::from_millis(10),
+            ..Default::default()
+        };
+        let (tx, mut rx) = managed_stream_async(&server.ws_url(PATH_PUBLIC_LINEAR), config)
+            .await
+            .unwrap();
+        tx.send(OutgoingMessage::Subscribe {
+            req_id: Some(String::from("req-0001")),
+            args: vec![Topic::ticker("BTCUSDT")],
+        })
+        .await
+        .unwrap();
+
+        assert!(matches!(rx.recv().await, Some(StreamEvent::Connected)));
+        assert!(matches!(
+            rx.recv().await,
+            Some(StreamEvent::Message(IncomingMessage::Command(
+                CommandMsg::Subscribe { .. }
+            )))
+        ));
+
+        server.disconnect_all();
+        loop {
+            match rx.recv().await {
+                Some(StreamEvent::Resubscribed { topics }) => {
+                    assert_eq!(topics, vec![Topic::ticker("BTCUSDT")]);
+                    break;
+                }
+                Some(_) => continue,
+                None => panic!("stream ended"),
+            }
+        }
+        assert_eq!(server.connections(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_managed_stream_does_not_replay_rejected_subscribe() {
+        let server = MockServer::start().await;
+        server.set_auth(AuthMode::Verify {
+            api_key: String::from("api_key"),
+            api_secret: String::from("secret"),
+        });
+        let config = StreamConfig {
+            backoff_initial: Duration::from_millis(10),
+            ..Default::default()
+        };
+        let (tx, mut rx) = managed_stream_async(&server.ws_url(PATH_PRIVATE), config)
+            .await
+            .unwrap();
+        tx.send(OutgoingMessage::Subscribe {
+            req_id: Some(String::from("req-0001")),
+            args: vec![Topic::Order { category: None }],
+        })
+        .await
+        .unwrap();
+
+        assert!(matches!(rx.recv().await, Some(StreamEvent::Connected)));
+        assert!(matches!(
+            rx.recv().await,
+            Some(StreamEvent::Message(IncomingMessage::Command(
+                CommandMsg::Subscribe {
+                    success: Some(false),
+                    ..
+                }
+            )))
+        ));
+
+        server.disconnect_all();
+        loop {
+            match rx.recv().await {
+                Some(StreamEvent::Connected) => break,
+                Some(StreamEvent::Resubscribed { topics }) => panic!("resubscribed {topics:?}"),
+                Some(_) => continue,
+                None => panic!("stream ended"),
+            }
+        }
+        tx.send(OutgoingMessage::Ping {
+            req_id: Some(String::from("ping")),
+        })
+        .await
+        .unwrap();
+        assert!(matches!(
+            rx.recv().await,
+            Some(StreamEvent::Message(IncomingMessage::Command(
+                CommandMsg::Pong { .. }
+            )))
+        ));
+        let subscribes = server
+            .received()
+            .iter()
+            .filter(|frame| frame.contains(r#""op":"subscribe""#))
+            .count();
+        assert_eq!(subscribes, 1);
+    }
+
+    #[tokio::test]
+    async fn test_managed_stream_reconnects_on_missing_pong() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = accept_async(socket).await.unwrap();
+            // Pings are read but never answered.
+            while let Some(Ok(_)) = ws.next().await {}
+        });
+
+        let config = StreamConfig {
+            ping_interval: Duration::from_millis(500),
+            pong_timeout: Duration::from_millis(50),
+            ..Default::default()
+        };
+        let start = Instant::now();
+        let (_tx, mut rx) = managed_stream_async(&url, config).await.unwrap();
+        assert!(matches!(rx.recv().await, Some(StreamEvent::Connected)));
+        assert!(matches!(
+            rx.recv().await,
+            Some(StreamEvent::Error(Error::Timeout(message))) if message == "pong"
+        ));
+        // Noticed `pong_timeout` after the first ping, not on the next ping tick.
+        assert!(start.elapsed() < Duration::from_millis(900));
+    }
 }
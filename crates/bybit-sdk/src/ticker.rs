@@ -0,0 +1,446 @@
+use std::fmt;
+
+use crate::{TickerDeltaMsg, TickerMsg, TickerSnapshotMsg, Topic};
+
+#[derive(Debug, PartialEq)]
+pub enum TickerBookError {
+    /// A delta arrived before the first snapshot.
+    NotSynced,
+    /// Message is older than the last applied one.
+    Stale {
+        cs: Option<u64>,
+        ts: u64,
+    },
+    UnexpectedSymbol(String),
+}
+
+impl fmt::Display for TickerBookError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NotSynced => write!(f, "ticker is not synced, waiting for snapshot"),
+            Self::Stale { cs, ts } => write!(f, "stale ticker message: cs={cs:?}, ts={ts}"),
+            Self::UnexpectedSymbol(symbol) => write!(f, "unexpected symbol {symbol}"),
+        }
+    }
+}
+
+impl std::error::Error for TickerBookError {}
+
+/// Field of [`TickerSnapshotMsg`], reported when an update changes it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TickerField {
+    TickDirection,
+    LastPrice,
+    PreOpenPrice,
+    PreQty,
+    CurPreListingPhase,
+    PrevPrice24h,
+    Price24hPcnt,
+    HighPrice24h,
+    LowPrice24h,
+    PrevPrice1h,
+    MarkPrice,
+    IndexPrice,
+    OpenInterest,
+    OpenInterestValue,
+    Turnover24h,
+    Volume24h,
+    FundingRate,
+    NextFundingTime,
+    Bid1Price,
+    Bid1Size,
+    Ask1Price,
+    Ask1Size,
+    DeliveryTime,
+    BasisRate,
+    DeliveryFeeRate,
+    PredictedDeliveryPrice,
+}
+
+/// Current ticker of one symbol, built from the `tickers.{symbol}` snapshot and deltas.
+#[derive(Debug)]
+pub struct TickerBook {
+    symbol: String,
+    ticker: Option<TickerSnapshotMsg>,
+    cs: Option<u64>,
+    ts: u64,
+}
+
+impl TickerBook {
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            ticker: None,
+            cs: None,
+            ts: 0,
+        }
+    }
+
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    pub fn topic(&self) -> Topic {
+        Topic::ticker(&self.symbol)
+    }
+
+    pub fn is_synced(&self) -> bool {
+        self.ticker.is_some()
+    }
+
+    /// Complete ticker with all deltas applied, `None` before the first snapshot.
+    pub fn ticker(&self) -> Option<&TickerSnapshotMsg> {
+        self.ticker.as_ref()
+    }
+
+    /// Cross sequence of the last applied message. Spot tickers have none.
+    pub fn cs(&self) -> Option<u64> {
+        self.cs
+    }
+
+    /// Timestamp (ms) of the last applied message.
+    pub fn ts(&self) -> u64 {
+        self.ts
+    }
+
+    /// Applies a snapshot or delta and returns the fields whose value changed.
+    /// Every field of the first snapshot counts as changed. A snapshot always replaces the
+    /// state, also when Bybit restarted the cross sequence lower.
+    pub fn apply(&mut self, message: &TickerMsg) -> Result<Vec<TickerField>, TickerBookError> {
+        match message {
+            TickerMsg::Snapshot { cs, ts, data, .. } => {
+                self.check_symbol(&data.symbol)?;
+                let changed = match &self.ticker {
+                    Some(ticker) => changed_fields(ticker, data),
+                    None => ALL_FIELDS.to_vec(),
+                };
+                self.ticker = Some(data.clone());
+                self.cs = *cs;
+                self.ts = *ts;
+                Ok(changed)
+            }
+            TickerMsg::Delta { cs, ts, data, .. } => {
+                self.check_symbol(&data.symbol)?;
+                let stale_cs = matches!((self.cs, *cs), (Some(last), Some(cs)) if cs < last);
+                if stale_cs || *ts < self.ts {
+                    return Err(TickerBookError::Stale { cs: *cs, ts: *ts });
+                }
+                let Some(ticker) = &mut self.ticker else {
+                    return Err(TickerBookError::NotSynced);
+                };
+                let previous = ticker.clone();
+                merge(ticker, data);
+                let changed = changed_fields(&previous, ticker);
+                self.cs = cs.or(self.cs);
+                self.ts = *ts;
+                Ok(changed)
+            }
+        }
+    }
+
+    fn check_symbol(&self, symbol: &str) -> Result<(), TickerBookError> {
+        if symbol != self.symbol {
+            return Err(TickerBookError::UnexpectedSymbol(symbol.to_owned()));
+        }
+        Ok(())
+    }
+}
+
+const ALL_FIELDS: [TickerField; 26] = [
+    TickerField::TickDirection,
+    TickerField::LastPrice,
+    TickerField::PreOpenPrice,
+    TickerField::PreQty,
+    TickerField::CurPreListingPhase,
+    TickerField::PrevPrice24h,
+    TickerField::Price24hPcnt,
+    TickerField::HighPrice24h,
+    TickerField::LowPrice24h,
+    TickerField::PrevPrice1h,
+    TickerField::MarkPrice,
+    TickerField::IndexPrice,
+    TickerField::OpenInterest,
+    TickerField::OpenInterestValue,
+    TickerField::Turnover24h,
+    TickerField::Volume24h,
+    TickerField::FundingRate,
+    TickerField::NextFundingTime,
+    TickerField::Bid1Price,
+    TickerField::Bid1Size,
+    TickerField::Ask1Price,
+    TickerField::Ask1Size,
+    TickerField::DeliveryTime,
+    TickerField::BasisRate,
+    TickerField::DeliveryFeeRate,
+    TickerField::PredictedDeliveryPrice,
+];
+
+/// Deltas only carry the fields that changed.
+fn merge(ticker: &mut TickerSnapshotMsg, delta: &TickerDeltaMsg) {
+    fn set<T: Clone>(field: &mut T, value: &Option<T>) {
+        if let Some(value) = value {
+            *field = value.clone();
+        }
+    }
+
+    fn set_option<T: Clone>(field: &mut Option<T>, value: &Option<T>) {
+        if value.is_some() {
+            field.clone_from(value);
+        }
+    }
+
+    set(&mut ticker.tick_direction, &delta.tick_direction);
+    set(&mut ticker.last_price, &delta.last_price);
+    set_option(&mut ticker.pre_open_price, &delta.pre_open_price);
+    set_option(&mut ticker.pre_qty, &delta.pre_qty);
+    set_option(
+        &mut ticker.cur_pre_listing_phase,
+        &delta.cur_pre_listing_phase,
+    );
+    set(&mut ticker.prev_price24h, &delta.prev_price24h);
+    set(&mut ticker.price24h_pcnt, &delta.price24h_pcnt);
+    set(&mut ticker.high_price24h, &delta.high_price24h);
+    set(&mut ticker.low_price24h, &delta.low_price24h);
+    set(&mut ticker.prev_price1h, &delta.prev_price1h);
+    set(&mut ticker.mark_price, &delta.mark_price);
+    set(&mut ticker.index_price, &delta.index_price);
+    set(&mut ticker.open_interest, &delta.open_interest);
+    set(&mut ticker.open_interest_value, &delta.open_interest_value);
+    set(&mut ticker.turnover24h, &delta.turnover24h);
+    set(&mut ticker.volume24h, &delta.volume24h);
+    set(&mut ticker.funding_rate, &delta.funding_rate);
+    set(&mut ticker.next_funding_time, &delta.next_funding_time);
+    set(&mut ticker.bid1_price, &delta.bid1_price);
+    set(&mut ticker.bid1_size, &delta.bid1_size);
+    set(&mut ticker.ask1_price, &delta.ask1_price);
+    set(&mut ticker.ask1_size, &delta.ask1_size);
+    set_option(&mut ticker.delivery_time, &delta.delivery_time);
+    set_option(&mut ticker.basis_rate, &delta.basis_rate);
+    set_option(&mut ticker.delivery_fee_rate, &delta.delivery_fee_rate);
+    set_option(
+        &mut ticker.predicted_delivery_price,
+        &delta.predicted_delivery_price,
+    );
+}
+
+fn changed_fields(old: &TickerSnapshotMsg, new: &TickerSnapshotMsg) -> Vec<TickerField> {
+    let fields = [
+        (
+            TickerField::TickDirection,
+            old.tick_direction != new.tick_direction,
+        ),
+        (TickerField::LastPrice, old.last_price != new.last_price),
+        (
+            TickerField::PreOpenPrice,
+            old.pre_open_price != new.pre_open_price,
+        ),
+        (TickerField::PreQty, old.pre_qty != new.pre_qty),
+        (
+            TickerField::CurPreListingPhase,
+            old.cur_pre_listing_phase != new.cur_pre_listing_phase,
+        ),
+        (
+            TickerField::PrevPrice24h,
+            old.prev_price24h != new.prev_price24h,
+        ),
+        (
+            TickerField::Price24hPcnt,
+            old.price24h_pcnt != new.price24h_pcnt,
+        ),
+        (
+            TickerField::HighPrice24h,
+            old.high_price24h != new.high_price24h,
+        ),
+        (
+            TickerField::LowPrice24h,
+            old.low_price24h != new.low_price24h,
+        ),
+        (
+            TickerField::PrevPrice1h,
+            old.prev_price1h != new.prev_price1h,
+        ),
+        (TickerField::MarkPrice, old.mark_price != new.mark_price),
+        (TickerField::IndexPrice, old.index_price != new.index_price),
+        (
+            TickerField::OpenInterest,
+            old.open_interest != new.open_interest,
+        ),
+        (
+            TickerField::OpenInterestValue,
+            old.open_interest_value != new.open_interest_value,
+        ),
+        (TickerField::Turnover24h, old.turnover24h != new.turnover24h),
+        (TickerField::Volume24h, old.volume24h != new.volume24h),
+        (
+            TickerField::FundingRate,
+            old.funding_rate != new.funding_rate,
+        ),
+        (
+            TickerField::NextFundingTime,
+            old.next_funding_time != new.next_funding_time,
+        ),
+        (TickerField::Bid1Price, old.bid1_price != new.bid1_price),
+        (TickerField::Bid1Size, old.bid1_size != new.bid1_size),
+        (TickerField::Ask1Price, old.ask1_price != new.ask1_price),
+        (TickerField::Ask1Size, old.ask1_size != new.ask1_size),
+        (
+            TickerField::DeliveryTime,
+            old.delivery_time != new.delivery_time,
+        ),
+        (TickerField::BasisRate, old.basis_rate != new.basis_rate),
+        (
+            TickerField::DeliveryFeeRate,
+            old.delivery_fee_rate != new.delivery_fee_rate,
+        ),
+        (
+            TickerField::PredictedDeliveryPrice,
+            old.predicted_delivery_price != new.predicted_delivery_price,
+        ),
+    ];
+    fields
+        .into_iter()
+        .filter(|(_, changed)| *changed)
+        .map(|(field, _)| field)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{common::deserialize_slice, number::n};
+
+    const SNAPSHOT: &str = r#"{
+        "topic": "tickers.BTCUSDT",
+        "type": "snapshot",
+        "data": {
+            "symbol": "BTCUSDT",
+            "tickDirection": "ZeroPlusTick",
+            "price24hPcnt": "-0.044555",
+            "lastPrice": "84594.40",
+            "prevPrice24h": "88539.30",
+            "highPrice24h": "89389.90",
+            "lowPrice24h": "82055.60",
+            "prevPrice1h": "84307.20",
+            "markPrice": "84594.00",
+            "indexPrice": "84650.47",
+            "openInterest": "52903.75",
+            "openInterestValue": "4475339827.50",
+            "turnover24h": "17166562011.6514",
+            "volume24h": "200176.9910",
+            "nextFundingTime": "1740643200000",
+            "fundingRate": "-0.00016974",
+            "bid1Price": "84594.30",
+            "bid1Size": "6.777",
+            "ask1Price": "84594.40",
+            "ask1Size": "0.660"
+        },
+        "cs": 337149693308,
+        "ts": 1740627604223
+    }"#;
+
+    fn delta(cs: u64, ts: u64, data: &str) -> TickerMsg {
+        let json = format!(
+            r#"{{"topic":"tickers.BTCUSDT","type":"delta","data":{data},"cs":{cs},"ts":{ts}}}"#
+        );
+        deserialize_slice(json.as_bytes()).unwrap()
+    }
+
+    fn synced_book() -> TickerBook {
+        let mut book = TickerBook::new("BTCUSDT");
+        let snapshot: TickerMsg = deserialize_slice(SNAPSHOT.as_bytes()).unwrap();
+        let changed = book.apply(&snapshot).unwrap();
+        assert_eq!(changed, ALL_FIELDS);
+        book
+    }
+
+    #[test]
+    fn test_apply_delta() {
+        let mut book = synced_book();
+        let changed = book
+            .apply(&delta(
+                337149693309,
+                1740627604323,
+                r#"{"symbol":"BTCUSDT","tickDirection":"PlusTick","lastPrice":"84600.10","bid1Price":"84594.30","ask1Price":"84600.10","ask1Size":"1.2"}"#,
+            ))
+            .unwrap();
+        assert_eq!(
+            changed,
+            vec![
+                TickerField::TickDirection,
+                TickerField::LastPrice,
+                TickerField::Ask1Price,
+                TickerField::Ask1Size,
+            ]
+        );
+
+        let ticker = book.ticker().unwrap();
+        assert_eq!(ticker.last_price, n!(84600.1));
+        assert_eq!(ticker.ask1_size, n!(1.2));
+        assert_eq!(ticker.bid1_size, n!(6.777));
+        assert_eq!(ticker.mark_price, n!(84594.0));
+        assert_eq!(book.cs(), Some(337149693309));
+        assert_eq!(book.ts(), 1740627604323);
+    }
+
+    #[test]
+    fn test_reject_stale_delta() {
+        let mut book = synced_book();
+        let result = book.apply(&delta(
+            337149693307,
+            1740627604323,
+            r#"{"symbol":"BTCUSDT","lastPrice":"1"}"#,
+        ));
+        assert_eq!(
+            result,
+            Err(TickerBookError::Stale {
+                cs: Some(337149693307),
+                ts: 1740627604323,
+            })
+        );
+
+        let result = book.apply(&delta(
+            337149693308,
+            1740627604000,
+            r#"{"symbol":"BTCUSDT","lastPrice":"1"}"#,
+        ));
+        assert!(matches!(result, Err(TickerBookError::Stale { .. })));
+        assert_eq!(book.ticker().unwrap().last_price, n!(84594.4));
+    }
+
+    #[test]
+    fn test_snapshot_after_sequence_reset() {
+        let mut book = synced_book();
+        let json = SNAPSHOT
+            .replace("337149693308", "5")
+            .replace("1740627604223", "1740627600000")
+            .replace("\"84594.40\"", "\"84000.00\"");
+        let snapshot: TickerMsg = deserialize_slice(json.as_bytes()).unwrap();
+        let changed = book.apply(&snapshot).unwrap();
+        assert!(changed.contains(&TickerField::LastPrice));
+        assert_eq!(book.cs(), Some(5));
+        assert_eq!(book.ts(), 1740627600000);
+
+        book.apply(&delta(
+            6,
+            1740627600100,
+            r#"{"symbol":"BTCUSDT","lastPrice":"84001"}"#,
+        ))
+        .unwrap();
+        assert_eq!(book.ticker().unwrap().last_price, n!(84001.0));
+        assert_eq!(book.cs(), Some(6));
+    }
+
+    #[test]
+    fn test_delta_before_snapshot() {
+        let mut book = TickerBook::new("BTCUSDT");
+        let result = book.apply(&delta(1, 1, r#"{"symbol":"BTCUSDT","lastPrice":"1"}"#));
+        assert_eq!(result, Err(TickerBookError::NotSynced));
+
+        let mut book = TickerBook::new("ETHUSDT");
+        let result = book.apply(&delta(1, 1, r#"{"symbol":"BTCUSDT","lastPrice":"1"}"#));
+        assert_eq!(
+            result,
+            Err(TickerBookError::UnexpectedSymbol(String::from("BTCUSDT")))
+        );
+    }
+}
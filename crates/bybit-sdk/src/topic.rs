@@ -1,17 +1,268 @@
-use crate::Interval;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
 
-pub fn topic_ticker(symbol: &str) -> String {
-    format!("tickers.{symbol}")
+use crate::{Category, Interval};
+
+/// Stream topic, e.g. `tickers.BTCUSDT` or `order.linear`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Topic {
+    Ticker { symbol: String },
+    Trade { symbol: String },
+    OrderBook { depth: u64, symbol: String },
+    KLine { interval: Interval, symbol: String },
+    AllLiquidation { symbol: String },
+    // Private topics. Without category the topic covers all categories.
+    Order { category: Option<Category> },
+    Position { category: Option<Category> },
+    Execution { category: Option<Category> },
+    Wallet,
+    Greeks,
+}
+
+impl Topic {
+    pub fn ticker(symbol: &str) -> Self {
+        Self::Ticker {
+            symbol: symbol.to_owned(),
+        }
+    }
+
+    pub fn trade(symbol: &str) -> Self {
+        Self::Trade {
+            symbol: symbol.to_owned(),
+        }
+    }
+
+    pub fn orderbook(symbol: &str, depth: u64) -> Self {
+        Self::OrderBook {
+            depth,
+            symbol: symbol.to_owned(),
+        }
+    }
+
+    pub fn kline(symbol: &str, interval: Interval) -> Self {
+        Self::KLine {
+            interval,
+            symbol: symbol.to_owned(),
+        }
+    }
+
+    pub fn all_liquidation(symbol: &str) -> Self {
+        Self::AllLiquidation {
+            symbol: symbol.to_owned(),
+        }
+    }
+
+    /// Symbol of a public topic.
+    pub fn symbol(&self) -> Option<&str> {
+        match self {
+            Self::Ticker { symbol }
+            | Self::Trade { symbol }
+            | Self::OrderBook { symbol, .. }
+            | Self::KLine { symbol, .. }
+            | Self::AllLiquidation { symbol } => Some(symbol),
+            _ => None,
+        }
+    }
+
+    pub fn is_private(&self) -> bool {
+        self.symbol().is_none()
+    }
+}
+
+impl fmt::Display for Topic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Ticker { symbol } => write!(f, "tickers.{symbol}"),
+            Self::Trade { symbol } => write!(f, "publicTrade.{symbol}"),
+            Self::OrderBook { depth, symbol } => write!(f, "orderbook.{depth}.{symbol}"),
+            Self::KLine { interval, symbol } => write!(f, "kline.{interval}.{symbol}"),
+            Self::AllLiquidation { symbol } => write!(f, "allLiquidation.{symbol}"),
+            Self::Order { category } => write_private(f, "order", category),
+            Self::Position { category } => write_private(f, "position", category),
+            Self::Execution { category } => write_private(f, "execution", category),
+            Self::Wallet => write!(f, "wallet"),
+            Self::Greeks => write!(f, "greeks"),
+        }
+    }
+}
+
+fn write_private(f: &mut fmt::Formatter, name: &str, category: &Option<Category>) -> fmt::Result {
+    match category {
+        Some(category) => write!(f, "{name}.{category}"),
+        None => write!(f, "{name}"),
+    }
 }
 
-pub fn topic_trade(symbol: &str) -> String {
-    format!("publicTrade.{symbol}")
+#[derive(Debug, PartialEq)]
+pub struct ParseTopicError(pub String);
+
+impl fmt::Display for ParseTopicError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid topic `{}`", self.0)
+    }
 }
 
-pub fn topic_kline(symbol: &str, interval: Interval) -> String {
-    format!("kline.{interval}.{symbol}")
+impl std::error::Error for ParseTopicError {}
+
+impl FromStr for Topic {
+    type Err = ParseTopicError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseTopicError(s.to_owned());
+        let (name, rest) = s.split_once('.').unwrap_or((s, ""));
+        let symbol = || {
+            if rest.is_empty() {
+                Err(error())
+            } else {
+                Ok(rest.to_owned())
+            }
+        };
+        let category = || match rest {
+            "" => Ok(None),
+            "spot" => Ok(Some(Category::Spot)),
+            "linear" => Ok(Some(Category::Linear)),
+            "inverse" => Ok(Some(Category::Inverse)),
+            "option" => Ok(Some(Category::Option)),
+            _ => Err(error()),
+        };
+
+        let topic = match name {
+            "tickers" => Self::Ticker { symbol: symbol()? },
+            "publicTrade" => Self::Trade { symbol: symbol()? },
+            "allLiquidation" => Self::AllLiquidation { symbol: symbol()? },
+            "orderbook" => {
+                let (depth, symbol) = rest.split_once('.').ok_or_else(error)?;
+                Self::OrderBook {
+                    depth: depth.parse().map_err(|_| error())?,
+                    symbol: symbol.to_owned(),
+                }
+            }
+            "kline" => {
+                let (interval, symbol) = rest.split_once('.').ok_or_else(error)?;
+                Self::KLine {
+                    interval: parse_interval(interval).ok_or_else(error)?,
+                    symbol: symbol.to_owned(),
+                }
+            }
+            "order" => Self::Order {
+                category: category()?,
+            },
+            "position" => Self::Position {
+                category: category()?,
+            },
+            "execution" => Self::Execution {
+                category: category()?,
+            },
+            "wallet" if rest.is_empty() => Self::Wallet,
+            "greeks" if rest.is_empty() => Self::Greeks,
+            _ => return Err(error()),
+        };
+        Ok(topic)
+    }
 }
 
-pub fn topic_all_liquidation(symbol: &str) -> String {
-    format!("allLiquidation.{symbol}")
+fn parse_interval(s: &str) -> Option<Interval> {
+    let interval = match s {
+        "1" => Interval::Minute1,
+        "3" => Interval::Minute3,
+        "5" => Interval::Minute5,
+        "15" => Interval::Minute15,
+        "30" => Interval::Minute30,
+        "60" => Interval::Minute60,
+        "120" => Interval::Minute120,
+        "240" => Interval::Minute240,
+        "360" => Interval::Minute360,
+        "720" => Interval::Minute720,
+        "D" => Interval::Day,
+        "W" => Interval::Week,
+        "M" => Interval::Month,
+        _ => return None,
+    };
+    Some(interval)
+}
+
+impl Serialize for Topic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Topic {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_topic_round_trip() {
+        let cases = [
+            (Topic::ticker("BTCUSDT"), "tickers.BTCUSDT"),
+            (
+                Topic::trade("BTC-29JUL22-25000-C"),
+                "publicTrade.BTC-29JUL22-25000-C",
+            ),
+            (Topic::orderbook("BTCUSDT", 50), "orderbook.50.BTCUSDT"),
+            (Topic::kline("BTCUSDT", Interval::Day), "kline.D.BTCUSDT"),
+            (Topic::all_liquidation("BTCUSDT"), "allLiquidation.BTCUSDT"),
+            (Topic::Order { category: None }, "order"),
+            (
+                Topic::Execution {
+                    category: Some(Category::Spot),
+                },
+                "execution.spot",
+            ),
+            (
+                Topic::Position {
+                    category: Some(Category::Linear),
+                },
+                "position.linear",
+            ),
+            (Topic::Wallet, "wallet"),
+            (Topic::Greeks, "greeks"),
+        ];
+        for (topic, expected) in cases {
+            assert_eq!(topic.to_string(), expected);
+            assert_eq!(expected.parse::<Topic>(), Ok(topic));
+        }
+    }
+
+    #[test]
+    fn test_parse_invalid_topic() {
+        for topic in [
+            "",
+            "tickers",
+            "tickers.",
+            "orderbook.BTCUSDT",
+            "kline.2.BTCUSDT",
+            "order.futures",
+            "wallet.spot",
+            "unknown.BTCUSDT",
+        ] {
+            assert_eq!(
+                topic.parse::<Topic>(),
+                Err(ParseTopicError(topic.to_owned()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_topic_symbol() {
+        assert_eq!(
+            Topic::kline("BTCUSDT", Interval::Minute1).symbol(),
+            Some("BTCUSDT")
+        );
+        assert_eq!(Topic::Wallet.symbol(), None);
+        assert!(Topic::Greeks.is_private());
+    }
 }
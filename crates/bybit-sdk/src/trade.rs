@@ -0,0 +1,751 @@
+use futures_util::{SinkExt, StreamExt};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{from_value, Value};
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use tokio::{
+    sync::{
+        mpsc::{channel, Receiver, Sender},
+        oneshot,
+    },
+    time::{interval_at, sleep_until, timeout, Instant},
+};
+use tokio_tungstenite::{
+    connect_async,
+    tungstenite::{protocol::Message, Utf8Bytes},
+};
+use tracing::Instrument;
+
+use crate::{
+    common::{deserialize_slice, serialize},
+    stream::WebSocket,
+    AmendOrderParams, BatchOrderIds, BatchOrderParams, BatchRetExtInfo, CancelOrderParams, Clock,
+    CreateOrderParams, Credentials, Error, OrderId, OutgoingMessage, RejectReason, Result,
+    DEFAULT_RECV_WINDOW,
+};
+
+/// Operations of the websocket trade API (`PATH_TRADE`), also used for the results of the
+/// REST batch order endpoints.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+pub enum TradeOp {
+    #[serde(rename = "order.create")]
+    Create,
+    #[serde(rename = "order.amend")]
+    Amend,
+    #[serde(rename = "order.cancel")]
+    Cancel,
+    #[serde(rename = "order.create-batch")]
+    CreateBatch,
+    #[serde(rename = "order.amend-batch")]
+    AmendBatch,
+    #[serde(rename = "order.cancel-batch")]
+    CancelBatch,
+}
+
+impl fmt::Display for TradeOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match self {
+            Self::Create => "order.create",
+            Self::Amend => "order.amend",
+            Self::Cancel => "order.cancel",
+            Self::CreateBatch => "order.create-batch",
+            Self::AmendBatch => "order.amend-batch",
+            Self::CancelBatch => "order.cancel-batch",
+        };
+        write!(f, "{value}")
+    }
+}
+
+/// Returned when Bybit rejects an order request.
+#[derive(Debug, PartialEq)]
+pub struct TradeError {
+    pub op: TradeOp,
+    pub ret_code: i32,
+    pub ret_msg: String,
+    pub reason: RejectReason,
+}
+
+impl TradeError {
+    fn new(op: TradeOp, ret_code: i32, ret_msg: String) -> Self {
+        Self {
+            op,
+            ret_code,
+            ret_msg,
+            reason: RejectReason::from_ret_code(ret_code),
+        }
+    }
+}
+
+impl fmt::Display for TradeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Bybit {} rejected {}: {}",
+            self.op, self.ret_code, self.ret_msg
+        )
+    }
+}
+
+impl std::error::Error for TradeError {}
+
+/// Accepted order request. It says nothing of the order status, which the `order` topic reports.
+#[derive(Debug, PartialEq)]
+pub struct OrderAck {
+    pub op: TradeOp,
+    pub order_id: String,
+    pub order_link_id: String,
+}
+
+/// Any message received on the trade stream: request results, `auth` and `pong` replies.
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeResponse {
+    pub req_id: Option<String>,
+    pub ret_code: i32,
+    pub ret_msg: String,
+    pub op: String,
+    #[serde(default)]
+    pub data: Value,
+    #[serde(default)]
+    pub ret_ext_info: Value,
+    /// Rate limit headers, e.g. `X-Bapi-Limit-Status`.
+    #[serde(default)]
+    pub header: HashMap<String, String>,
+    #[serde(default)]
+    pub conn_id: String,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct TradeRequest<'a, T: ?Sized> {
+    req_id: &'a str,
+    header: TradeHeader<'a>,
+    op: TradeOp,
+    args: [&'a T; 1],
+}
+
+#[derive(Serialize, Debug)]
+struct TradeHeader<'a> {
+    #[serde(rename = "X-BAPI-TIMESTAMP")]
+    timestamp: String,
+    #[serde(rename = "X-BAPI-RECV-WINDOW")]
+    recv_window: String,
+    #[serde(rename = "Referer", skip_serializing_if = "Option::is_none")]
+    referer: Option<&'a str>,
+}
+
+pub struct TradeStreamConfig {
+    pub ping_interval: Duration,
+    /// How long to wait for a pong after a ping before the connection is considered dead.
+    pub pong_timeout: Duration,
+    /// How long to wait for the result of a single request.
+    pub request_timeout: Duration,
+    pub recv_window: u64,
+    /// The header for broker users only.
+    pub referer: Option<String>,
+}
+
+impl Default for TradeStreamConfig {
+    fn default() -> Self {
+        Self {
+            ping_interval: Duration::from_secs(20),
+            pong_timeout: Duration::from_secs(10),
+            request_timeout: Duration::from_secs(5),
+            recv_window: DEFAULT_RECV_WINDOW,
+            referer: None,
+        }
+    }
+}
+
+type Pending = Arc<Mutex<HashMap<String, oneshot::Sender<TradeResponse>>>>;
+
+/// Authenticated connection to the websocket trade API (`PATH_TRADE`).
+///
+/// Requests are correlated with their results by `reqId`. When the connection is lost
+/// every pending request fails and so do all further ones: connect again to continue.
+pub struct TradeStream {
+    outgoing: Sender<Message>,
+    pending: Pending,
+    next_id: AtomicU64,
+    config: TradeStreamConfig,
+    clock: Arc<Clock>,
+}
+
+impl TradeStream {
+    pub async fn connect(
+        url: &str,
+        credentials: &Credentials,
+        config: TradeStreamConfig,
+    ) -> Result<Self> {
+        let (mut ws, _) = connect_async(url).await?;
+
+        let auth = serialize(&credentials.auth_message(Some(String::from("auth"))))
+            .map_err(Error::Serialize)?;
+        ws.send(Message::Text(Utf8Bytes::from(&auth))).await?;
+        match timeout(AUTH_TIMEOUT, wait_auth(&mut ws)).await {
+            Ok(result) => result?,
+            Err(_) => return Err(Error::Timeout(String::from("auth"))),
+        }
+
+        let (outgoing, outgoing_rx) = channel::<Message>(CHANNEL_CAPACITY);
+        let pending = Pending::default();
+        let span = tracing::info_span!("bybit.trade", url);
+        tokio::spawn(
+            run(
+                ws,
+                outgoing_rx,
+                pending.clone(),
+                config.ping_interval,
+                config.pong_timeout,
+            )
+            .instrument(span),
+        );
+
+        Ok(Self {
+            outgoing,
+            pending,
+            next_id: AtomicU64::new(1),
+            config,
+            clock: credentials.clock(),
+        })
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.outgoing.is_closed()
+    }
+
+    pub async fn create_order(&self, params: CreateOrderParams) -> Result<OrderAck> {
+        let response = self.request(TradeOp::Create, &params).await?;
+        order_ack(TradeOp::Create, response)
+    }
+
+    pub async fn amend_order(&self, params: AmendOrderParams) -> Result<OrderAck> {
+        let response = self.request(TradeOp::Amend, &params).await?;
+        order_ack(TradeOp::Amend, response)
+    }
+
+    pub async fn cancel_order(&self, params: CancelOrderParams) -> Result<OrderAck> {
+        let response = self.request(TradeOp::Cancel, &params).await?;
+        order_ack(TradeOp::Cancel, response)
+    }
+
+    /// Results are in the order of `params.request`.
+    pub async fn create_batch_order(
+        &self,
+        params: BatchOrderParams<CreateOrderParams>,
+    ) -> Result<Vec<std::result::Result<OrderAck, TradeError>>> {
+        let response = self.request(TradeOp::CreateBatch, &params).await?;
+        batch_order_acks(TradeOp::CreateBatch, response)
+    }
+
+    /// Results are in the order of `params.request`.
+    pub async fn amend_batch_order(
+        &self,
+        params: BatchOrderParams<AmendOrderParams>,
+    ) -> Result<Vec<std::result::Result<OrderAck, TradeError>>> {
+        let response = self.request(TradeOp::AmendBatch, &params).await?;
+        batch_order_acks(TradeOp::AmendBatch, response)
+    }
+
+    /// Results are in the order of `params.request`.
+    pub async fn cancel_batch_order(
+        &self,
+        params: BatchOrderParams<CancelOrderParams>,
+    ) -> Result<Vec<std::result::Result<OrderAck, TradeError>>> {
+        let response = self.request(TradeOp::CancelBatch, &params).await?;
+        batch_order_acks(TradeOp::CancelBatch, response)
+    }
+
+    /// Sends a request and waits for the response with the same `reqId`.
+    pub async fn request<T>(&self, op: TradeOp, args: &T) -> Result<TradeResponse>
+    where
+        T: Serialize + ?Sized,
+    {
+        let req_id = format!("trade-{}", self.next_id.fetch_add(1, Ordering::Relaxed));
+        let frame = serialize(&TradeRequest {
+            req_id: &req_id,
+            header: TradeHeader {
+                timestamp: self.clock.now().to_string(),
+                recv_window: self.config.recv_window.to_string(),
+                referer: self.config.referer.as_deref(),
+            },
+            op,
+            args: [args],
+        })
+        .map_err(Error::Serialize)?;
+
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.pending
+            .lock()
+            .unwrap()
+            .insert(req_id.clone(), reply_tx);
+
+        if self
+            .outgoing
+            .send(Message::Text(Utf8Bytes::from(&frame)))
+            .await
+            .is_err()
+        {
+            self.pending.lock().unwrap().remove(&req_id);
+            return Err(Error::Closed(None));
+        }
+
+        match timeout(self.config.request_timeout, reply_rx).await {
+            Ok(Ok(response)) => Ok(response),
+            // The connection was lost before the response arrived.
+            Ok(Err(_)) => Err(Error::Closed(None)),
+            Err(_) => {
+                self.pending.lock().unwrap().remove(&req_id);
+                Err(Error::Timeout(format!("{op} {req_id}")))
+            }
+        }
+    }
+}
+
+const AUTH_TIMEOUT: Duration = Duration::from_secs(10);
+const CHANNEL_CAPACITY: usize = 64;
+
+async fn wait_auth(ws: &mut WebSocket) -> Result<()> {
+    while let Some(message) = ws.next().await {
+        let text = match message? {
+            Message::Text(text) => text,
+            Message::Close(close_frame) => return Err(Error::Closed(close_frame)),
+            _ => continue,
+        };
+        let response: TradeResponse =
+            deserialize_slice(text.as_ref()).map_err(|e| Error::deserialize(e, text.as_str()))?;
+        if response.op == "auth" {
+            if response.ret_code != 0 {
+                return Err(Error::Auth(response.ret_msg));
+            }
+            return Ok(());
+        }
+    }
+    Err(Error::Closed(None))
+}
+
+async fn run(
+    mut ws: WebSocket,
+    mut outgoing: Receiver<Message>,
+    pending: Pending,
+    ping_interval: Duration,
+    pong_timeout: Duration,
+) {
+    let mut ping = interval_at(Instant::now() + ping_interval, ping_interval);
+    // Armed when a ping is sent, disarmed by the pong.
+    let mut pong_deadline: Option<Instant> = None;
+    loop {
+        tokio::select! {
+            message = outgoing.recv() => {
+                let Some(message) = message else {
+                    break;
+                };
+                if let Err(e) = ws.send(message).await {
+                    tracing::error!("Send request failed: {e}");
+                    break;
+                }
+            }
+            message = ws.next() => match message {
+                Some(Ok(Message::Text(text))) => {
+                    if dispatch(&pending, text.as_ref()) {
+                        pong_deadline = None;
+                    }
+                }
+                Some(Ok(Message::Pong(_))) => pong_deadline = None,
+                Some(Ok(Message::Close(close_frame))) => {
+                    tracing::info!("{}", Error::Closed(close_frame));
+                    break;
+                }
+                None => {
+                    tracing::info!("{}", Error::Closed(None));
+                    break;
+                }
+                Some(Ok(_)) => {}
+                Some(Err(e)) => {
+                    tracing::error!("Receive message failed: {e}");
+                    break;
+                }
+            },
+            _ = ping.tick() => {
+                let message = serialize(&OutgoingMessage::Ping { req_id: None })
+                    .expect("ping is serializable");
+                if let Err(e) = ws.send(Message::Text(Utf8Bytes::from(&message))).await {
+                    tracing::error!("Send ping failed: {e}");
+                    break;
+                }
+                pong_deadline.get_or_insert(Instant::now() + pong_timeout);
+            }
+            _ = sleep_until(pong_deadline.unwrap_or_else(Instant::now)),
+                if pong_deadline.is_some() =>
+            {
+                tracing::warn!("Pong is missing, closing");
+                break;
+            }
+        }
+    }
+
+    // Dropping the reply senders fails every request still waiting.
+    pending.lock().unwrap().clear();
+    outgoing.close();
+    let _ = ws.close(None).await;
+}
+
+/// Passes a response on to its request. Returns whether it was a pong.
+fn dispatch(pending: &Pending, slice: &[u8]) -> bool {
+    match deserialize_slice::<TradeResponse>(slice) {
+        Ok(response) => {
+            if response.op == "pong" {
+                return true;
+            }
+            let Some(req_id) = &response.req_id else {
+                return false;
+            };
+            if let Some(reply) = pending.lock().unwrap().remove(req_id) {
+                let _ = reply.send(response);
+            }
+        }
+        Err(e) => tracing::warn!(
+            payload = %String::from_utf8_lossy(slice),
+            "Deserialize TradeResponse failed: {e}"
+        ),
+    }
+    false
+}
+
+fn order_ack(op: TradeOp, response: TradeResponse) -> Result<OrderAck> {
+    if response.ret_code != 0 {
+        return Err(TradeError::new(op, response.ret_code, response.ret_msg).into());
+    }
+
+    let OrderId {
+        order_id,
+        order_link_id,
+    } = from_data(response.data)?;
+    Ok(OrderAck {
+        op,
+        order_id,
+        order_link_id,
+    })
+}
+
+fn batch_order_acks(
+    op: TradeOp,
+    response: TradeResponse,
+) -> Result<Vec<std::result::Result<OrderAck, TradeError>>> {
+    if response.ret_code != 0 {
+        return Err(TradeError::new(op, response.ret_code, response.ret_msg).into());
+    }
+    batch_acks(op, response.data, response.ret_ext_info)
+}
+
+/// Pairs the order ids of a batch result with the per request `retExtInfo` codes.
+/// Shared with the REST batch endpoints.
+pub(crate) fn batch_acks(
+    op: TradeOp,
+    data: Value,
+    ret_ext_info: Value,
+) -> Result<Vec<std::result::Result<OrderAck, TradeError>>> {
+    let ids: BatchOrderIds = from_data(data)?;
+    let codes: BatchRetExtInfo = from_data(ret_ext_info)?;
+
+    let acks = ids
+        .list
+        .into_iter()
+        .zip(codes.list)
+        .map(|(id, code)| {
+            if code.code != 0 {
+                return Err(TradeError::new(op, code.code, code.msg));
+            }
+            Ok(OrderAck {
+                op,
+                order_id: id.order_id,
+                order_link_id: id.order_link_id,
+            })
+        })
+        .collect();
+    Ok(acks)
+}
+
+fn from_data<T: DeserializeOwned>(data: Value) -> Result<T> {
+    let payload = data.to_string();
+    from_value(data).map_err(|e| Error::deserialize(e, payload))
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::accept_async;
+
+    use crate::{number::n, Category, OrderType, Side, TimeInForce};
+
+    use super::*;
+
+    fn create_order_params() -> CreateOrderParams {
+        CreateOrderParams {
+            category: Category::Linear,
+            symbol: String::from("ETHUSDT"),
+            is_leverage: None,
+            side: Side::Buy,
+            order_type: OrderType::Limit,
+            qty: n!(0.2),
+            market_unit: None,
+            price: Some(n!(2800.0)),
+            trigger_direction: None,
+            order_filter: None,
+            trigger_price: None,
+            trigger_by: None,
+            order_iv: None,
+            time_in_force: Some(TimeInForce::PostOnly),
+            position_idx: None,
+            order_link_id: None,
+            take_profit: None,
+            stop_loss: None,
+            tp_trigger_by: None,
+            sl_trigger_by: None,
+            reduce_only: None,
+            close_on_trigger: None,
+            smp_type: None,
+            mmp: None,
+            tpsl_mode: None,
+            tp_limit_price: None,
+            sl_limit_price: None,
+            tp_order_type: None,
+            sl_order_type: None,
+        }
+    }
+
+    #[test]
+    fn test_serialize_trade_request() {
+        let params = create_order_params();
+        let request = TradeRequest {
+            req_id: "test-005",
+            header: TradeHeader {
+                timestamp: String::from("1711001595207"),
+                recv_window: String::from("8000"),
+                referer: Some("bot-001"),
+            },
+            op: TradeOp::Create,
+            args: [&params],
+        };
+        let expected = r#"{"reqId":"test-005","header":{"X-BAPI-TIMESTAMP":"1711001595207","X-BAPI-RECV-WINDOW":"8000","Referer":"bot-001"},"op":"order.create","args":[{"category":"linear","symbol":"ETHUSDT","side":"Buy","orderType":"Limit","qty":"0.2","price":"2800","timeInForce":"PostOnly"}]}"#;
+        assert_eq!(serialize(&request).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_order_ack() {
+        let json = r#"{
+            "reqId": "test-005",
+            "retCode": 0,
+            "retMsg": "OK",
+            "op": "order.create",
+            "data": {
+                "orderId": "a4c1718a-fe45-4d32-9ab7-d3d7cb5e8b8e",
+                "orderLinkId": ""
+            },
+            "retExtInfo": {},
+            "header": {
+                "X-Bapi-Limit": "10",
+                "X-Bapi-Limit-Status": "9",
+                "X-Bapi-Limit-Reset-Timestamp": "1711001595208",
+                "Traceid": "7e33e4a7cf8ee6d9b9a9ee5e7e0ac46f",
+                "Timenow": "1711001595209"
+            },
+            "connId": "cpv85t788smd5eps8ncg-2tgc"
+        }"#;
+        let response: TradeResponse = deserialize_slice(json.as_bytes()).unwrap();
+        assert_eq!(response.header["X-Bapi-Limit-Status"], "9");
+        let ack = order_ack(TradeOp::Create, response).unwrap();
+        assert_eq!(
+            ack,
+            OrderAck {
+                op: TradeOp::Create,
+                order_id: String::from("a4c1718a-fe45-4d32-9ab7-d3d7cb5e8b8e"),
+                order_link_id: String::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_order_ack_rejected() {
+        let json = r#"{
+            "reqId": "test-006",
+            "retCode": 110001,
+            "retMsg": "order not exists or too late to cancel",
+            "op": "order.cancel",
+            "data": {},
+            "retExtInfo": {},
+            "header": {},
+            "connId": "cpv85t788smd5eps8ncg-2tgc"
+        }"#;
+        let response: TradeResponse = deserialize_slice(json.as_bytes()).unwrap();
+        let Err(Error::Rejected(error)) = order_ack(TradeOp::Cancel, response) else {
+            panic!("order.cancel must be rejected");
+        };
+        let expected = TradeError {
+            op: TradeOp::Cancel,
+            ret_code: 110001,
+            ret_msg: String::from("order not exists or too late to cancel"),
+            reason: RejectReason::EcOrderNotExist,
+        };
+        assert_eq!(error, expected);
+    }
+
+    #[test]
+    fn test_batch_order_acks() {
+        let json = r#"{
+            "reqId": "test-007",
+            "retCode": 0,
+            "retMsg": "OK",
+            "op": "order.create-batch",
+            "data": {
+                "list": [
+                    {
+                        "category": "option",
+                        "symbol": "ETH-26MAR24-3000-C",
+                        "orderId": "e5c80a6a-6e1a-4e4b-9d2f-a1b5c2e3d4f5",
+                        "orderLinkId": "batch-1",
+                        "createAt": "1711001595208"
+                    },
+                    {
+                        "category": "option",
+                        "symbol": "ETH-26MAR24-3100-C",
+                        "orderId": "",
+                        "orderLinkId": "batch-1",
+                        "createAt": ""
+                    }
+                ]
+            },
+            "retExtInfo": {
+                "list": [
+                    {"code": 0, "msg": "OK"},
+                    {"code": 110072, "msg": "OrderLinkedID is duplicate"}
+                ]
+            },
+            "header": {},
+            "connId": "cpv85t788smd5eps8ncg-2tgc"
+        }"#;
+        let response: TradeResponse = deserialize_slice(json.as_bytes()).unwrap();
+        let acks = batch_order_acks(TradeOp::CreateBatch, response).unwrap();
+        assert_eq!(
+            acks,
+            vec![
+                Ok(OrderAck {
+                    op: TradeOp::CreateBatch,
+                    order_id: String::from("e5c80a6a-6e1a-4e4b-9d2f-a1b5c2e3d4f5"),
+                    order_link_id: String::from("batch-1"),
+                }),
+                Err(TradeError {
+                    op: TradeOp::CreateBatch,
+                    ret_code: 110072,
+                    ret_msg: String::from("OrderLinkedID is duplicate"),
+                    reason: RejectReason::EcDuplicatedClOrdId,
+                }),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_trade_stream() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = accept_async(socket).await.unwrap();
+            while let Some(Ok(Message::Text(text))) = ws.next().await {
+                let request: Value = serde_json::from_str(text.as_ref()).unwrap();
+                let reply = match request["op"].as_str().unwrap() {
+                    "auth" => {
+                        String::from(r#"{"retCode":0,"retMsg":"OK","op":"auth","connId":"c1"}"#)
+                    }
+                    "order.create" => format!(
+                        r#"{{"reqId":"{}","retCode":0,"retMsg":"OK","op":"order.create","data":{{"orderId":"1","orderLinkId":""}},"retExtInfo":{{}},"header":{{}},"connId":"c1"}}"#,
+                        request["reqId"].as_str().unwrap()
+                    ),
+                    // Amendments are never answered.
+                    _ => continue,
+                };
+                ws.send(Message::Text(Utf8Bytes::from(&reply)))
+                    .await
+                    .unwrap();
+            }
+        });
+
+        let config = TradeStreamConfig {
+            request_timeout: Duration::from_millis(100),
+            ..Default::default()
+        };
+        let credentials = Credentials::new("api_key", "secret");
+        let stream = TradeStream::connect(&url, &credentials, config)
+            .await
+            .unwrap();
+
+        let ack = stream.create_order(create_order_params()).await.unwrap();
+        assert_eq!(ack.order_id, "1");
+
+        let result = stream
+            .amend_order(AmendOrderParams {
+                category: Category::Linear,
+                symbol: String::from("ETHUSDT"),
+                order_id: Some(String::from("1")),
+                order_link_id: None,
+                order_iv: None,
+                trigger_price: None,
+                qty: None,
+                price: Some(n!(2900.0)),
+                tpsl_mode: None,
+                take_profit: None,
+                stop_loss: None,
+                tp_trigger_by: None,
+                sl_trigger_by: None,
+                trigger_by: None,
+                tp_limit_price: None,
+                sl_limit_price: None,
+            })
+            .await;
+        assert!(matches!(result, Err(Error::Timeout(_))));
+        assert!(stream.pending.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_trade_stream_closes_on_missing_pong() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = accept_async(socket).await.unwrap();
+            // Only the auth is answered, like a half-open connection afterwards.
+            while let Some(Ok(Message::Text(text))) = ws.next().await {
+                if text.contains(r#""op":"auth""#) {
+                    let reply = r#"{"retCode":0,"retMsg":"OK","op":"auth","connId":"c1"}"#;
+                    ws.send(Message::text(reply)).await.unwrap();
+                }
+            }
+        });
+
+        let config = TradeStreamConfig {
+            ping_interval: Duration::from_millis(50),
+            pong_timeout: Duration::from_millis(50),
+            request_timeout: Duration::from_secs(5),
+            ..Default::default()
+        };
+        let credentials = Credentials::new("api_key", "secret");
+        let stream = TradeStream::connect(&url, &credentials, config)
+            .await
+            .unwrap();
+
+        let start = Instant::now();
+        let result = stream.create_order(create_order_params()).await;
+        assert!(matches!(result, Err(Error::Closed(None))));
+        assert!(start.elapsed() < Duration::from_secs(1));
+        assert!(stream.is_closed());
+    }
+}
@@ -0,0 +1,164 @@
+use serde::{Deserialize, Deserializer};
+use std::{
+    collections::BTreeMap,
+    marker::PhantomData,
+    sync::{Mutex, RwLock},
+};
+
+/// Enum value received from Bybit that this release does not know, e.g. a new order status.
+/// It is kept in the `Unrecognized` variant of the enum.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct UnrecognizedValue {
+    /// Name of the enum, e.g. `OrderStatus`.
+    pub kind: &'static str,
+    pub value: String,
+}
+
+type Hook = Box<dyn Fn(&UnrecognizedValue) + Send + Sync>;
+
+static HOOK: RwLock<Option<Hook>> = RwLock::new(None);
+static COUNTS: Mutex<BTreeMap<UnrecognizedValue, u64>> = Mutex::new(BTreeMap::new());
+
+/// Called for every unrecognized value, e.g. to increment a metric.
+/// Replaces the previous hook.
+pub fn set_unrecognized_hook(hook: impl Fn(&UnrecognizedValue) + Send + Sync + 'static) {
+    *HOOK.write().unwrap_or_else(|e| e.into_inner()) = Some(Box::new(hook));
+}
+
+/// Unrecognized values received so far, with the number of times each was seen.
+pub fn unrecognized_values() -> Vec<(UnrecognizedValue, u64)> {
+    let counts = COUNTS.lock().unwrap_or_else(|e| e.into_inner());
+    counts
+        .iter()
+        .map(|(v, count)| (v.clone(), *count))
+        .collect()
+}
+
+/// Counts the value and calls the hook. Only the first occurrence is logged.
+pub(crate) fn report<T>(value: String) {
+    let value = UnrecognizedValue {
+        kind: kind::<T>(),
+        value,
+    };
+    let count = {
+        let mut counts = COUNTS.lock().unwrap_or_else(|e| e.into_inner());
+        let count = counts.entry(value.clone()).or_default();
+        *count += 1;
+        *count
+    };
+    if count == 1 {
+        tracing::warn!(kind = value.kind, value = value.value, "Unrecognized value");
+    }
+    if let Some(hook) = HOOK.read().unwrap_or_else(|e| e.into_inner()).as_ref() {
+        hook(&value);
+    }
+}
+
+/// `deserialize_with` of the `Unrecognized(String)` variant of `T`.
+pub(crate) fn unrecognized<'de, T, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    report::<T>(value.clone());
+    Ok(value)
+}
+
+/// Integer code of `T` received from Bybit, for `#[serde(from = "Received<T>")]` on enums
+/// with an `Unrecognized(i64)` variant. Only received values are reported, so `From<i64>`
+/// stays free of side effects.
+#[derive(Deserialize)]
+#[serde(transparent)]
+pub(crate) struct Received<T> {
+    value: i64,
+    #[serde(skip)]
+    kind: PhantomData<T>,
+}
+
+impl<T: From<i64> + PartialEq> Received<T> {
+    /// `T::from(value)`, reporting values that map to `unrecognized`.
+    pub(crate) fn decode(self, unrecognized: fn(i64) -> T) -> T {
+        let decoded = T::from(self.value);
+        if decoded == unrecognized(self.value) {
+            report::<T>(self.value.to_string());
+        }
+        decoded
+    }
+}
+
+fn kind<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    use super::*;
+    use crate::{common::serialize, Category, Interval, OrderStatus, PositionIdx, Side};
+
+    #[test]
+    fn test_deserialize_unrecognized() {
+        let status: OrderStatus = serde_json::from_str(r#""PartiallyFilledCanceled""#).unwrap();
+        assert_eq!(status, OrderStatus::PartiallyFilledCanceled);
+
+        let status: OrderStatus = serde_json::from_str(r#""Suspended""#).unwrap();
+        assert_eq!(status, OrderStatus::Unrecognized(String::from("Suspended")));
+        assert_eq!(serialize(&status).unwrap(), r#""Suspended""#);
+
+        let category: Category = serde_json::from_str(r#""futures""#).unwrap();
+        assert_eq!(category, Category::Unrecognized(String::from("futures")));
+        assert_eq!(category.to_string(), "futures");
+
+        let interval: Interval = serde_json::from_str(r#""2""#).unwrap();
+        assert_eq!(interval, Interval::Unrecognized(String::from("2")));
+        assert_eq!(interval.to_string(), "2");
+
+        let position_idx: PositionIdx = serde_json::from_str("7").unwrap();
+        assert_eq!(position_idx, PositionIdx::Unrecognized(7));
+        assert_eq!(serialize(&position_idx).unwrap(), "7");
+        assert_eq!(serialize(&PositionIdx::Buy).unwrap(), "1");
+
+        let values = unrecognized_values();
+        assert!(values.contains(&(
+            UnrecognizedValue {
+                kind: "OrderStatus",
+                value: String::from("Suspended"),
+            },
+            1
+        )));
+        assert!(values
+            .iter()
+            .any(|(v, _)| v.kind == "PositionIdx" && v.value == "7"));
+
+        // Values built in code are not reported.
+        assert_eq!(PositionIdx::from(9), PositionIdx::Unrecognized(9));
+        assert!(!unrecognized_values()
+            .iter()
+            .any(|(v, _)| v.kind == "PositionIdx" && v.value == "9"));
+    }
+
+    #[test]
+    fn test_unrecognized_hook() {
+        static CALLS: AtomicU64 = AtomicU64::new(0);
+        set_unrecognized_hook(|value| {
+            if value.kind == "Side" && value.value == "Hold" {
+                CALLS.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+        for _ in 0..2 {
+            let side: Side = serde_json::from_str(r#""Hold""#).unwrap();
+            assert_eq!(side, Side::Unrecognized(String::from("Hold")));
+        }
+        assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+        assert!(unrecognized_values().contains(&(
+            UnrecognizedValue {
+                kind: "Side",
+                value: String::from("Hold"),
+            },
+            2
+        )));
+    }
+}
@@ -8,9 +8,9 @@ pub const URL_BASE_API_MAINNET_3: &str = "https://api.bybit.nl";
 /// For Hong Kong users.
 pub const URL_BASE_API_MAINNET_4: &str = "https://api.byhkbit.com";
 /// For Turkey users.
-pub const URL_BASE_API_MAINNET_5: &str = "wss://api.bybit-tr.com";
+pub const URL_BASE_API_MAINNET_5: &str = "https://api.bybit-tr.com";
 /// For Kazakhstan users.
-pub const URL_BASE_API_MAINNET_6: &str = "wss://api.bybit.kz";
+pub const URL_BASE_API_MAINNET_6: &str = "https://api.bybit.kz";
 
 pub const URL_BASE_STREAM_MAINNET_1: &str = "wss://stream.bybit.com";
 /// For Turkey users.
@@ -39,9 +39,9 @@ pub const HEADER_X_REFERER: &str = "X-Referer";
 pub const HEADER_REFERER: &str = "Referer";
 /// The header for specify how long an HTTP request is valid (unit in millisecond and default value is 5,000). It is also used to prevent replay attacks..
 pub const HEADER_X_BAPI_RECV_WINDOW: &str = "X-BAPI-RECV-WINDOW";
-/// Your remaining requests for current endpoint.
-pub const HEADER_X_BAPI_LIMIT: &str = "X-Bapi-Limit";
 /// Your current limit for current endpoint.
+pub const HEADER_X_BAPI_LIMIT: &str = "X-Bapi-Limit";
+/// Your remaining requests for current endpoint.
 pub const HEADER_X_BAPI_LIMIT_STATUS: &str = "X-Bapi-Limit-Status";
 /// The timestamp indicating when your request limit resets if you have exceeded your rate_limit. Otherwise, this is just the current timestamp (it may not exactly match timeNow).
 pub const HEADER_X_BAPI_LIMIT_RESET_TIMESTAMP: &str = "X-Bapi-Limit-Reset-Timestamp";
@@ -49,6 +49,7 @@ pub const HEADER_X_BAPI_LIMIT_RESET_TIMESTAMP: &str = "X-Bapi-Limit-Reset-Timest
 pub const HEADER_CDN_REQUEST_ID: &str = "cdn-request-id";
 
 // Candlestick, orderbook, ticker, platform transaction data, underlying financial rules, risk control rules
+pub const PATH_MARKET_TIME: &str = "/v5/market/time";
 pub const PATH_MARKET_KLINE: &str = "/v5/market/kline";
 pub const PATH_MARKET_MARK_PRICE_KLINE: &str = "/v5/market/mark-price-kline";
 pub const PATH_MARKET_INDEX_PRICE_KLINE: &str = "/v5/market/index-price-kline";
@@ -141,6 +142,9 @@ pub const PATH_SPOT_MARGIN_TRADE_SWITCH_MODE: &str = "/v5/spot-margin-trade/swit
 pub const PATH_SPOT_MARGIN_TRADE_SET_LEVERAGE: &str = "/v5/spot-margin-trade/set-leverage";
 pub const PATH_SPOT_MARGIN_TRADE_SET_PLEDGE_TOKEN: &str = "/v5/spot-margin-trade/set-pledge-token";
 
+// Platform announcements
+pub const PATH_ANNOUNCEMENTS_INDEX: &str = "/v5/announcements/index";
+
 // Stream paths.
 pub const PATH_PUBLIC_SPOT: &str = "/v5/public/spot";
 pub const PATH_PUBLIC_LINEAR: &str = "/v5/public/linear";
@@ -1,4 +1,4 @@
-use bybit::v5::{
+use bybit_sdk::{
     self, Category, InverseLinearSpotTrade, KLineRow, LinearInverseTicker, OptionTicker,
     OptionTrade, SpotTicker,
 };
@@ -52,30 +52,33 @@ pub fn from_kline_row(v: &KLineRow) -> Candle {
     }
 }
 
-pub fn from_inverse_linear_spot_trade(v: &InverseLinearSpotTrade) -> Trade {
-    Trade {
+/// `None` for a trade with an unrecognized side.
+pub fn from_inverse_linear_spot_trade(v: &InverseLinearSpotTrade) -> Option<Trade> {
+    Some(Trade {
         symbol: v.symbol.clone(),
         price: v.price,
         size: v.size,
-        side: from_side(&v.side),
+        side: from_side(&v.side)?,
         time: v.time,
-    }
+    })
 }
 
-pub fn from_option_trade(v: &OptionTrade) -> Trade {
-    Trade {
+/// `None` for a trade with an unrecognized side.
+pub fn from_option_trade(v: &OptionTrade) -> Option<Trade> {
+    Some(Trade {
         symbol: v.symbol.clone(),
         price: v.price,
         size: v.size,
-        side: from_side(&v.side),
+        side: from_side(&v.side)?,
         time: v.time,
-    }
+    })
 }
 
-pub fn from_side(v: &v5::Side) -> Side {
+pub fn from_side(v: &bybit_sdk::Side) -> Option<Side> {
     match v {
-        v5::Side::Buy => Side::Buy,
-        v5::Side::Sell => Side::Sell,
+        bybit_sdk::Side::Buy => Some(Side::Buy),
+        bybit_sdk::Side::Sell => Some(Side::Sell),
+        bybit_sdk::Side::Unrecognized(_) => None,
     }
 }
 
@@ -88,20 +91,20 @@ pub fn to_category(m: &Schema) -> Category {
     }
 }
 
-pub fn to_interval(m: &Interval) -> v5::Interval {
+pub fn to_interval(m: &Interval) -> bybit_sdk::Interval {
     match m {
-        Interval::Minute1 => v5::Interval::Minute1,
-        Interval::Minute3 => v5::Interval::Minute3,
-        Interval::Minute5 => v5::Interval::Minute5,
-        Interval::Minute15 => v5::Interval::Minute15,
-        Interval::Minute30 => v5::Interval::Minute30,
-        Interval::Hour1 => v5::Interval::Hour1,
-        Interval::Hour2 => v5::Interval::Hour2,
-        Interval::Hour4 => v5::Interval::Hour4,
-        Interval::Hour6 => v5::Interval::Hour6,
-        Interval::Hour12 => v5::Interval::Hour12,
-        Interval::Day1 => v5::Interval::Day1,
-        Interval::Week1 => v5::Interval::Week1,
-        Interval::Month1 => v5::Interval::Month1,
+        Interval::Minute1 => bybit_sdk::Interval::Minute1,
+        Interval::Minute3 => bybit_sdk::Interval::Minute3,
+        Interval::Minute5 => bybit_sdk::Interval::Minute5,
+        Interval::Minute15 => bybit_sdk::Interval::Minute15,
+        Interval::Minute30 => bybit_sdk::Interval::Minute30,
+        Interval::Hour1 => bybit_sdk::Interval::Minute60,
+        Interval::Hour2 => bybit_sdk::Interval::Minute120,
+        Interval::Hour4 => bybit_sdk::Interval::Minute240,
+        Interval::Hour6 => bybit_sdk::Interval::Minute360,
+        Interval::Hour12 => bybit_sdk::Interval::Minute720,
+        Interval::Day1 => bybit_sdk::Interval::Day,
+        Interval::Week1 => bybit_sdk::Interval::Week,
+        Interval::Month1 => bybit_sdk::Interval::Month,
     }
 }
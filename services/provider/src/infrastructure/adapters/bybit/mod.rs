@@ -1,6 +1,6 @@
 mod map;
 
-use bybit::v5::{self, Client, GetKLinesParams, GetTickersParams, KLine, Ticker};
+use bybit_sdk::{self, Client, GetKLinesParams, GetTickersParams, KLine, Ticker};
 
 use crate::application::{
     Candle, Exchanger, GetCandlesParams, GetTradesParams, Schema, Symbol, Trade,
@@ -76,7 +76,7 @@ impl Exchanger for BybitExchange {
     async fn get_trades(&self, schema: Schema, params: GetTradesParams) -> Vec<Trade> {
         let result = self
             .client
-            .get_public_recent_trading_history(v5::GetTradesParams {
+            .get_public_recent_trading_history(bybit_sdk::GetTradesParams {
                 category: to_category(&schema),
                 symbol: Some(params.symbol),
                 base_coin: None,
@@ -87,16 +87,21 @@ impl Exchanger for BybitExchange {
 
         match result {
             Ok(response) => match response {
-                v5::Trade::Inverse { list } => {
-                    list.iter().map(from_inverse_linear_spot_trade).collect()
-                }
-                v5::Trade::Linear { list } => {
-                    list.iter().map(from_inverse_linear_spot_trade).collect()
-                }
-                v5::Trade::Option { list } => list.iter().map(from_option_trade).collect(),
-                v5::Trade::Spot { list } => {
-                    list.iter().map(from_inverse_linear_spot_trade).collect()
+                bybit_sdk::Trade::Inverse { list } => list
+                    .iter()
+                    .filter_map(from_inverse_linear_spot_trade)
+                    .collect(),
+                bybit_sdk::Trade::Linear { list } => list
+                    .iter()
+                    .filter_map(from_inverse_linear_spot_trade)
+                    .collect(),
+                bybit_sdk::Trade::Option { list } => {
+                    list.iter().filter_map(from_option_trade).collect()
                 }
+                bybit_sdk::Trade::Spot { list } => list
+                    .iter()
+                    .filter_map(from_inverse_linear_spot_trade)
+                    .collect(),
             },
             Err(err) => {
                 tracing::error!("{err:?}");
@@ -105,3 +110,57 @@ impl Exchanger for BybitExchange {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bybit_mock::MockServer;
+    use bybit_sdk::PATH_MARKET_TICKERS;
+    use serde_json::json;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn get_symbols() {
+        let server = MockServer::start().await;
+        server.mock_rest(
+            PATH_MARKET_TICKERS,
+            json!({
+                "category": "spot",
+                "list": [{
+                    "symbol": "BTCUSDT",
+                    "bid1Price": "20517.96",
+                    "bid1Size": "2",
+                    "ask1Price": "20527.77",
+                    "ask1Size": "1.862172",
+                    "lastPrice": "20533.13",
+                    "prevPrice24h": "20393.48",
+                    "price24hPcnt": "0.0068",
+                    "highPrice24h": "21128.12",
+                    "lowPrice24h": "20318.89",
+                    "turnover24h": "243765620.65899866",
+                    "volume24h": "11801.27771",
+                    "usdIndexPrice": "20784.12009279"
+                }]
+            }),
+        );
+        let exchange = BybitExchange::new(Client::new(&server.url()));
+
+        let symbols = exchange.get_symbols(Schema::Spot, None).await;
+        assert_eq!(symbols.len(), 1);
+        assert_eq!(symbols[0].symbol, "BTCUSDT");
+        assert_eq!(symbols[0].last_price, 20533.13);
+        assert_eq!(
+            server.rest_requests()[0].query.as_deref(),
+            Some("category=spot")
+        );
+    }
+
+    #[tokio::test]
+    async fn get_symbols_api_error() {
+        let server = MockServer::start().await;
+        server.mock_rest_error(PATH_MARKET_TICKERS, 10001, "params error");
+        let exchange = BybitExchange::new(Client::new(&server.url()));
+
+        assert!(exchange.get_symbols(Schema::Linear, None).await.is_empty());
+    }
+}
@@ -8,9 +8,9 @@ use axum::{
     routing::{any, get},
     Router,
 };
-use bybit::v5::BASE_URL_API_MAINNET_1;
+use bybit_sdk::EndpointSet;
 use clap::Parser;
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 use tower_http::{
     cors::CorsLayer,
     trace::{DefaultMakeSpan, TraceLayer},
@@ -23,6 +23,8 @@ use presentation::{get_candles, get_symbols, get_trades, websocket_handler, Comm
 
 type App = Application<BinanceExchange, BybitExchange>;
 
+const BYBIT_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     tracing_subscriber::registry()
@@ -44,7 +46,9 @@ async fn main() -> anyhow::Result<()> {
 async fn command_serve(args: Serve) -> anyhow::Result<()> {
     tracing::info!("CLI command: Serve, args: {:?}", args);
 
-    let client_bybit = bybit::v5::Client::new(BASE_URL_API_MAINNET_1);
+    let endpoints = Arc::new(EndpointSet::api(args.bybit_region));
+    endpoints.spawn_health_check(BYBIT_HEALTH_CHECK_INTERVAL);
+    let client_bybit = bybit_sdk::Client::new(&endpoints.current()).with_endpoints(endpoints);
 
     let binance = BinanceExchange::new();
     let bybit = BybitExchange::new(client_bybit);
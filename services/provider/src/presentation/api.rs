@@ -147,7 +147,7 @@ mod tests {
 
     #[test]
     fn serialize_api_exchange() {
-        let cases = vec![
+        let cases = [
             (APIExchange::Binance, "\"binance\""),
             (APIExchange::Bybit, "\"bybit\""),
         ];
@@ -160,7 +160,7 @@ mod tests {
 
     #[test]
     fn deserialize_api_exchange() {
-        let cases = vec![
+        let cases = [
             ("\"binance\"", APIExchange::Binance),
             ("\"bybit\"", APIExchange::Bybit),
         ];
@@ -173,7 +173,7 @@ mod tests {
 
     #[test]
     fn serialize_api_schema() {
-        let cases = vec![
+        let cases = [
             (APISchema::FuturesCoin, "\"futures_coin\""),
             (APISchema::FuturesUSDT, "\"futures_usdt\""),
             (APISchema::Inverse, "\"inverse\""),
@@ -189,7 +189,7 @@ mod tests {
 
     #[test]
     fn deserialize_api_schema() {
-        let cases = vec![
+        let cases = [
             ("\"futures_coin\"", APISchema::FuturesCoin),
             ("\"futures_usdt\"", APISchema::FuturesUSDT),
             ("\"inverse\"", APISchema::Inverse),
@@ -205,7 +205,7 @@ mod tests {
 
     #[test]
     fn serialize_api_side() {
-        let cases = vec![(APISide::Sell, "\"sell\""), (APISide::Buy, "\"buy\"")];
+        let cases = [(APISide::Sell, "\"sell\""), (APISide::Buy, "\"buy\"")];
 
         cases.iter().for_each(|(value, expected)| {
             let serialized = serde_json::to_string(value).unwrap();
@@ -215,7 +215,7 @@ mod tests {
 
     #[test]
     fn deserialize_api_side() {
-        let cases = vec![("\"sell\"", APISide::Sell), ("\"buy\"", APISide::Buy)];
+        let cases = [("\"sell\"", APISide::Sell), ("\"buy\"", APISide::Buy)];
 
         cases.iter().for_each(|(value, expected)| {
             let deserialized: APISide = serde_json::from_str(value).unwrap();
@@ -225,7 +225,7 @@ mod tests {
 
     #[test]
     fn deserialize_api_interval() {
-        let cases = vec![
+        let cases = [
             ("\"1m\"", APIInterval::Minute1),
             ("\"3m\"", APIInterval::Minute3),
             ("\"5m\"", APIInterval::Minute5),
@@ -249,7 +249,7 @@ mod tests {
 
     #[test]
     fn deserialize_api_interval_from_str() {
-        let cases = vec![
+        let cases = [
             ("1m", APIInterval::Minute1),
             ("3m", APIInterval::Minute3),
             ("5m", APIInterval::Minute5),
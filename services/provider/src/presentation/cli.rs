@@ -1,3 +1,4 @@
+use bybit_sdk::Region;
 use clap::{Args, Parser};
 
 #[derive(Parser)]
@@ -15,4 +16,7 @@ pub struct Serve {
     pub rpc_bind: String,
     #[clap(long, env = "PATISSON__PROVIDER__WS_ADDR", default_value_t = String::from("127.0.0.1:3002"))]
     pub ws_bind: String,
+    /// Bybit site: global, nl, hk, tr, kz, testnet or demo.
+    #[clap(long, env = "PATISSON__PROVIDER__BYBIT_REGION", default_value_t = Region::Global)]
+    pub bybit_region: Region,
 }